@@ -0,0 +1,307 @@
+//! Derive macros for CrabQL.
+//!
+//! The generated code refers to the `crabql` crate by its absolute path.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, LitStr, PathArguments, Type};
+
+/// The types of columns, named after the `Cast` target marker of each type.
+#[derive(Clone, Copy)]
+enum Kind {
+    Bool,
+    Num,
+    Text,
+    Date,
+    Time,
+    Timestamp,
+}
+
+impl Kind {
+    fn from_attr(name: &str) -> Option<Self> {
+        match name {
+            "bool" => Some(Kind::Bool),
+            "num" => Some(Kind::Num),
+            "text" => Some(Kind::Text),
+            "date" => Some(Kind::Date),
+            "time" => Some(Kind::Time),
+            "timestamp" => Some(Kind::Timestamp),
+            _ => None,
+        }
+    }
+
+    /// Infers the type of a column from the type of its field.
+    fn infer(ty: &Type) -> Option<Self> {
+        let ty = match ty {
+            Type::Reference(reference) => &*reference.elem,
+            ty => ty,
+        };
+        let Type::Path(path) = ty else {
+            return None;
+        };
+        match path.path.segments.last()?.ident.to_string().as_str() {
+            "bool" => Some(Kind::Bool),
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+            | "u128" | "usize" | "f32" | "f64" => Some(Kind::Num),
+            "String" | "str" => Some(Kind::Text),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Bool => "Bool",
+            Kind::Num => "Num",
+            Kind::Text => "Text",
+            Kind::Date => "Date",
+            Kind::Time => "Time",
+            Kind::Timestamp => "Timestamp",
+        }
+    }
+
+    fn marker(self) -> TokenStream2 {
+        let marker = format_ident!("As{}", self.name());
+        quote!(::crabql::expr::cast::#marker)
+    }
+
+    fn expr_type(self) -> TokenStream2 {
+        let kind = format_ident!("{}", self.name());
+        quote!(::crabql::expr::ExprType::#kind)
+    }
+}
+
+/// Returns the inner type of an `Option`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// The options of a `#[column(...)]` attribute.
+#[derive(Default)]
+struct ColumnAttrs {
+    name: Option<String>,
+    kind: Option<Kind>,
+    default: bool,
+    unique: bool,
+}
+
+impl ColumnAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = ColumnAttrs::default();
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("column")) {
+            attr.parse_nested_meta(|meta| {
+                let ident = meta.path.get_ident().map(ToString::to_string);
+                match ident.as_deref() {
+                    Some("name") => {
+                        attrs.name = Some(meta.value()?.parse::<LitStr>()?.value());
+                    }
+                    Some("default") => attrs.default = true,
+                    Some("unique") => attrs.unique = true,
+                    Some(kind) => {
+                        let kind = Kind::from_attr(kind)
+                            .ok_or_else(|| meta.error("unknown column attribute"))?;
+                        attrs.kind = Some(kind);
+                    }
+                    None => return Err(meta.error("unknown column attribute")),
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+/// The table name of a `#[table(name = "...")]` attribute, if there is one.
+fn table_name(input: &DeriveInput) -> syn::Result<Option<String>> {
+    let mut name = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("table")) {
+        attr.parse_nested_meta(|meta| match meta.path.is_ident("name") {
+            true => {
+                name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            }
+            false => Err(meta.error("unknown table attribute")),
+        })?;
+    }
+    Ok(name)
+}
+
+/// Returns the named fields of a struct.
+fn named_fields(input: &DeriveInput) -> syn::Result<Vec<&Field>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.iter().collect()),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "only structs with named fields are supported",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "only structs with named fields are supported",
+        )),
+    }
+}
+
+/// Derives `crabql::schema::TableDef` for a struct, along with a typed column constant for each
+/// of its fields.
+///
+/// The table is named after the struct in snake case, unless `#[table(name = "...")]` is given.
+/// Each field is a column, `Option` fields are nullable and others are `NOT NULL`. Columns accept
+/// the options `#[column(name = "...", default, unique)]`, and the type of fields that are not
+/// booleans, numbers or strings must be given as one of `#[column(bool | num | text | date |
+/// time | timestamp)]`.
+#[proc_macro_derive(Table, attributes(table, column))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    table(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn table(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let name = table_name(input)?.unwrap_or_else(|| snake_case(&ident.to_string()));
+
+    let mut consts = Vec::new();
+    let mut columns = Vec::new();
+    for field in named_fields(input)? {
+        let attrs = ColumnAttrs::parse(field)?;
+        let field_ident = field.ident.as_ref().expect("fields are named");
+        let field_name = field_ident.to_string();
+        let field_name = field_name.trim_start_matches("r#");
+        let col = attrs.name.unwrap_or_else(|| field_name.to_string());
+
+        let inner = option_inner(&field.ty);
+        let ty = inner.unwrap_or(&field.ty);
+        let kind = attrs.kind.or_else(|| Kind::infer(ty)).ok_or_else(|| {
+            syn::Error::new_spanned(
+                ty,
+                "unknown column type, give it as one of \
+                 `#[column(bool | num | text | date | time | timestamp)]`",
+            )
+        })?;
+
+        let const_ident = format_ident!("{}", field_name.to_uppercase());
+        let marker = kind.marker();
+        let doc = format!("The `{col}` column");
+        consts.push(quote! {
+            #[doc = #doc]
+            pub const #const_ident: ::crabql::expr::CommonExpr<::crabql::expr::any::TypedCol<#marker>> =
+                ::crabql::expr::any::typed_col(#col);
+        });
+
+        let expr_type = kind.expr_type();
+        let mut column = quote!(::crabql::schema::Column::new(#col, #expr_type));
+        if inner.is_none() {
+            column = quote!(#column.not_null());
+        }
+        if attrs.default {
+            column = quote!(#column.with_default());
+        }
+        let mut table = quote!(table.with(#column));
+        if attrs.unique {
+            table = quote!(#table.with_unique([#col]));
+        }
+        columns.push(quote!(let table = #table;));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#consts)*
+        }
+
+        impl #impl_generics ::crabql::schema::TableDef for #ident #ty_generics #where_clause {
+            fn table_name() -> &'static str {
+                #name
+            }
+
+            fn table() -> ::crabql::schema::Table {
+                let table = ::crabql::schema::Table::new(#name);
+                #(#columns)*
+                table
+            }
+        }
+    })
+}
+
+/// Derives `crabql::row::FromRow` for a struct, decoding each field from the column of the same
+/// name.
+///
+/// The column of a field can be renamed with `#[column(name = "...")]`. Fields are decoded
+/// through `crabql::row::FromValue`, whose type is the one expected of the column unless it is
+/// given as one of `#[column(bool | num | text | date | time | timestamp)]`, such as for
+/// timestamps decoded into strings.
+#[proc_macro_derive(FromRow, attributes(column))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_row(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+
+    let mut columns = Vec::new();
+    let mut fields = Vec::new();
+    for field in named_fields(input)? {
+        let attrs = ColumnAttrs::parse(field)?;
+        let field_ident = field.ident.as_ref().expect("fields are named");
+        let field_name = field_ident.to_string();
+        let col = attrs
+            .name
+            .unwrap_or_else(|| field_name.trim_start_matches("r#").to_string());
+
+        let ty = &field.ty;
+        let from_value = quote!(<#ty as ::crabql::row::FromValue>);
+        let kind = match attrs.kind {
+            Some(kind) => kind.expr_type(),
+            None => quote!(#from_value::KIND),
+        };
+        columns.push(quote! {
+            ::crabql::row::RowColumn::new(#col, #kind, #from_value::NULLABLE)
+        });
+        fields.push(quote!(#field_ident: row.get::<#ty>(#col)?));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::crabql::row::FromRow for #ident #ty_generics #where_clause {
+            fn columns() -> ::std::vec::Vec<::crabql::row::RowColumn> {
+                ::std::vec![#(#columns),*]
+            }
+
+            fn from_row(row: &::crabql::row::Row) -> ::std::result::Result<Self, ::std::string::String> {
+                ::std::result::Result::Ok(Self {
+                    #(#fields,)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,226 @@
+use crate::checker::Dependencies;
+use crate::expr::Dialect;
+use crate::reader::SealedReader;
+use crate::writer::SealedWriter;
+
+/// A statement within a [`Batch`].
+pub enum BatchItem {
+    Read(Box<SealedReader>),
+    Write(Box<SealedWriter>),
+}
+
+impl From<SealedReader> for BatchItem {
+    fn from(reader: SealedReader) -> Self {
+        BatchItem::Read(Box::new(reader))
+    }
+}
+
+impl From<SealedWriter> for BatchItem {
+    fn from(writer: SealedWriter) -> Self {
+        BatchItem::Write(Box::new(writer))
+    }
+}
+
+impl BatchItem {
+    pub fn dependencies(&self) -> &Dependencies {
+        match self {
+            BatchItem::Read(reader) => reader.dependencies(),
+            BatchItem::Write(writer) => writer.dependencies(),
+        }
+    }
+
+    /// Returns the SQL statement in the given dialect, or an error if the dialect does not
+    /// support it.
+    pub fn to_sql(&self, dialect: Dialect) -> Result<String, String> {
+        match self {
+            BatchItem::Read(reader) => reader.to_sql(dialect),
+            BatchItem::Write(writer) => writer.to_sql(dialect),
+        }
+    }
+}
+
+/// Statements that are run in order as a single script.
+///
+/// Statements that depend on the schema changes of others, as recorded by their checker, must
+/// come after them within the batch. All statements are expected to be sealed through the same
+/// checker, in the order the schema changes were made.
+#[derive(Default)]
+pub struct Batch {
+    items: Vec<BatchItem>,
+}
+
+impl Batch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a statement after the previous ones.
+    pub fn then(mut self, item: impl Into<BatchItem>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Validates the ordering of the statements, returns a `SealedBatch` that cannot be
+    /// internally modified further.
+    pub fn seal(self) -> Result<SealedBatch, String> {
+        let items: Vec<_> = self.items.iter().map(|item| (item, true)).collect();
+        let errors = check_order(&items);
+        match errors.is_empty() {
+            true => Ok(SealedBatch { items: self.items }),
+            false => Err(errors.join("; ")),
+        }
+    }
+}
+
+/// Checks that the statements are ordered by their schema dependencies.
+/// Statements that are not kept, such as those that are rolled back, don't apply their changes.
+pub(crate) fn check_order(items: &[(&BatchItem, bool)]) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (i, (item, _)) in items.iter().enumerate() {
+        let deps = item.dependencies();
+        let earlier: Vec<_> = items[..i]
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, kept))| *kept)
+            .map(|(j, (e, _))| (j, e.dependencies()))
+            .collect();
+
+        let missing = deps.requires.iter().find(|required| {
+            !earlier
+                .iter()
+                .any(|(_, e)| e.writes.iter().any(|w| w.covers(required)))
+        });
+        if let Some(required) = missing {
+            errors.push(format!(
+                "statement {} depends on a change to `{required}` that is not earlier in the batch",
+                i + 1
+            ));
+        }
+
+        for (j, e) in earlier {
+            // only changes made after the statement was checked can invalidate it
+            if e.version <= deps.version {
+                continue;
+            }
+            let changed = deps
+                .reads
+                .iter()
+                .find(|read| e.writes.iter().any(|w| w.covers(read)));
+            if let Some(read) = changed {
+                errors.push(format!(
+                    "statement {} was checked before statement {} changed `{read}`, hence must come first",
+                    i + 1,
+                    j + 1
+                ));
+            }
+        }
+    }
+    errors
+}
+
+/// A finished batch of statements.
+pub struct SealedBatch {
+    items: Vec<BatchItem>,
+}
+
+impl SealedBatch {
+    pub fn statements(&self) -> &[BatchItem] {
+        &self.items
+    }
+
+    pub fn into_statements(self) -> Vec<BatchItem> {
+        self.items
+    }
+
+    /// Returns the statements as a single script in the given dialect, one statement per line.
+    pub fn to_sql(&self, dialect: Dialect) -> Result<String, String> {
+        let statements = self
+            .items
+            .iter()
+            .map(|item| item.to_sql(dialect).map(|sql| format!("{sql};")))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(statements.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Batch;
+    use crate::checker::CompiledChecker;
+    use crate::expr::any::{col, lit};
+    use crate::expr::{Dialect, ExprType};
+    use crate::reader::Reader;
+    use crate::schema::{Column, CompiledSchema, Table};
+    use crate::writer::Writer;
+
+    #[test]
+    fn dependent_changes() {
+        let schema = CompiledSchema::new();
+        let mut checker = CompiledChecker::new(&schema);
+        let users = Writer::new(&mut checker)
+            .create_table("users")
+            .column(Column::new("name", ExprType::Text).not_null())
+            .seal()
+            .unwrap();
+        let insert = Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("ann"))
+            .seal()
+            .unwrap();
+        let select = Reader::new(&mut checker)
+            .table("users")
+            .select(col("name"))
+            .unwrap();
+
+        let batch = Batch::new()
+            .then(users)
+            .then(insert)
+            .then(select)
+            .seal()
+            .unwrap();
+        assert_eq!(
+            batch.to_sql(Dialect::Postgres).unwrap(),
+            "CREATE TABLE users (name TEXT NOT NULL);\n\
+             INSERT INTO users (name) VALUES ('ann');\n\
+             SELECT name FROM users;"
+        );
+
+        let insert = Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("bob"))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            Batch::new().then(insert).seal().err().unwrap(),
+            "statement 1 depends on a change to `users` that is not earlier in the batch"
+        );
+    }
+
+    #[test]
+    fn stale_statements() {
+        let schema = CompiledSchema::new().with_table(
+            Table::new("users")
+                .with_column("name", ExprType::Text)
+                .with_column("nickname", ExprType::Text),
+        );
+        let mut checker = CompiledChecker::new(&schema);
+        let select = Reader::new(&mut checker)
+            .table("users")
+            .select(col("nickname"))
+            .unwrap();
+        let drop = Writer::new(&mut checker)
+            .alter_table("users")
+            .drop_column("nickname")
+            .seal()
+            .unwrap();
+        let names = Reader::new(&mut checker)
+            .table("users")
+            .select(col("name"))
+            .unwrap();
+
+        assert_eq!(
+            Batch::new().then(drop).then(names).then(select).seal().err().unwrap(),
+            "statement 3 was checked before statement 1 changed `users.nickname`, hence must come first"
+        );
+    }
+}
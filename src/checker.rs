@@ -1,39 +1,288 @@
 /// Standard conditions for a checker
 mod message;
-pub use message::{Condition, Message, Signal};
+pub use message::{Clause, Condition, Message, Signal};
 
-use crate::expr::ExprType;
+/// Errors found by the checkers
+mod error;
+pub use error::CheckError;
+
+/// Schema objects that statements depend on
+mod dependency;
+pub use dependency::{Dependencies, SchemaObject};
+
+use crate::expr::{ExprType, Nullability};
 use crate::protocol::{Client, Server, ServerHandler};
-use crate::schema::{CompiledSchema, DerivedSchema};
+use crate::schema::{Column, CompiledSchema, DerivedSchema, Table};
 
 pub type Context = ExprType;
 
 /// A type that can check `Checkable` types.
-pub trait Checker: ServerHandler + Server<Msg = Message> {}
+pub trait Checker: ServerHandler<Error = Vec<CheckError>> + Server<Msg = Message> {
+    /// Returns the known type of a column within the current table.
+    fn col_type(&self, col: &str) -> Option<ExprType>;
+
+    /// Returns the nullability of a column within the current table.
+    /// Columns that are not known to be `NOT NULL` are nullable.
+    fn col_nullability(&self, col: &str) -> Nullability;
+
+    /// Returns all columns of the current table, if they are known.
+    fn table_cols(&self) -> Option<Vec<Column>>;
+
+    /// Returns the schema objects that the current statement has read and changed so far.
+    fn dependencies(&self) -> Dependencies;
+
+    /// Returns the suspicious, but valid, uses found within the current statement so far.
+    fn warnings(&self) -> Vec<CheckError>;
+}
 
 /// A type that can be checked by a `Checker`.
 pub trait Checkable: Client<Ctx = Context, Msg = Message> {}
 
+/// State shared by the standard checkers.
+#[derive(Debug, Default)]
+struct State {
+    /// The table that columns currently refer to
+    table: Option<String>,
+    /// Tables joined with the current one, their columns are referred to as `table.col`.
+    /// Columns of outer joined tables are `NULL` for rows without a match.
+    joined: Vec<(String, Nullability)>,
+    /// Columns of unknown type that are linked together, one group per open link
+    links: Vec<Vec<String>>,
+    /// The restricting clauses that are currently open
+    clauses: Vec<Clause>,
+    /// The named windows defined so far
+    windows: Vec<String>,
+    /// The schema objects read and changed by the current statement
+    deps: Dependencies,
+    errors: Vec<CheckError>,
+    warnings: Vec<CheckError>,
+}
+
+impl State {
+    /// Splits a column into the table it refers to and its name within that table.
+    fn resolve<'a>(&'a self, col: &'a str) -> Option<(&'a str, &'a str)> {
+        match col.split_once('.') {
+            Some((table, name))
+                if self.table.as_deref() == Some(table)
+                    || self.joined.iter().any(|(t, _)| t == table) =>
+            {
+                Some((table, name))
+            }
+            Some(_) => None,
+            None => Some((self.table.as_deref()?, col)),
+        }
+    }
+
+    /// Returns the table and name of a column, or records an error if its table is not in
+    /// context.
+    fn table_for(&mut self, col: &str) -> Option<(String, String)> {
+        if let Some((table, name)) = self.resolve(col) {
+            return Some((table.to_string(), name.to_string()));
+        }
+        match col.split_once('.') {
+            Some((table, _)) => self
+                .errors
+                .push(CheckError::UnknownTable(table.to_string())),
+            None => self.errors.push(CheckError::NoTable(col.to_string())),
+        }
+        None
+    }
+
+    /// Returns the nullability that the joins add into the columns of a table.
+    fn join_nullability(&self, table: &str) -> Nullability {
+        self.joined
+            .iter()
+            .find(|(t, _)| t == table)
+            .map_or(Nullability::NotNull, |&(_, nullability)| nullability)
+    }
+
+    /// Adds an untyped column into the innermost open link.
+    /// Returns `false` if there is no open link.
+    fn link(&mut self, col: &str) -> bool {
+        match self.links.last_mut() {
+            Some(group) => {
+                group.push(col.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Records the schema objects that a message reads or changes.
+    /// Returns the changed object, which the checker's session must log.
+    fn track(&mut self, msg: &Message, changes: &[SchemaObject]) -> Option<SchemaObject> {
+        let current = |state: &State| state.table.clone().unwrap_or_default();
+        let read = match msg {
+            Message::Cond(Condition::TableExists(table) | Condition::TableJoined(table, _)) => {
+                SchemaObject::Table(table.clone())
+            }
+            Message::Cond(
+                Condition::ColExists(col)
+                | Condition::ColExistsAndType(col, _)
+                | Condition::ColAcceptsType(col, _, _),
+            ) => {
+                let (table, name) = self.resolve(col)?;
+                SchemaObject::Column(table.to_string(), name.to_string())
+            }
+            Message::Cond(Condition::UniqueKey(cols)) => SchemaObject::unique(current(self), cols),
+            Message::Sig(Signal::CreateTable(table)) => {
+                return Some(self.write(SchemaObject::Table(table.name().to_string())))
+            }
+            Message::Sig(Signal::AddColumn(column)) => {
+                let object = SchemaObject::Column(current(self), column.name().to_string());
+                return Some(self.write(object));
+            }
+            Message::Sig(Signal::DropColumn(col)) => {
+                let (table, name) = self.resolve(col)?;
+                let object = SchemaObject::Column(table.to_string(), name.to_string());
+                return Some(self.write(object));
+            }
+            Message::Sig(Signal::AddUnique(cols)) => {
+                return Some(self.write(SchemaObject::unique(current(self), cols)))
+            }
+            _ => return None,
+        };
+
+        if changes.iter().any(|change| change.covers(&read)) && !self.deps.requires.contains(&read)
+        {
+            self.deps.requires.push(read.clone());
+        }
+        if !self.deps.reads.contains(&read) {
+            self.deps.reads.push(read);
+        }
+        None
+    }
+
+    fn write(&mut self, object: SchemaObject) -> SchemaObject {
+        self.deps.writes.push(object.clone());
+        object
+    }
+
+    /// Handles the messages that don't depend on the schema, ignoring the others.
+    fn accept(&mut self, msg: Message) {
+        match msg {
+            Message::Cond(Condition::WindowExists(name)) => {
+                if !self.windows.contains(&name) {
+                    self.errors.push(CheckError::UnknownWindow(name));
+                }
+            }
+            Message::Sig(Signal::TypeMismatch((expected, found))) => {
+                self.errors.push(CheckError::TypeMismatch(expected, found))
+            }
+            Message::Sig(Signal::NullComparison) => self.warnings.push(CheckError::NullComparison),
+            Message::Sig(Signal::StartClause(clause)) => self.clauses.push(clause),
+            Message::Sig(Signal::EndClause) => {
+                self.clauses.pop();
+            }
+            Message::Sig(Signal::DefineWindow(name)) => self.windows.push(name),
+            Message::Sig(Signal::WindowFunction) => {
+                if let Some(&clause) = self.clauses.last() {
+                    self.errors.push(CheckError::MisplacedWindow(clause));
+                }
+            }
+            // the checkers handle the messages that depend on their schema
+            Message::Cond(
+                Condition::TableExists(_)
+                | Condition::TableJoined(..)
+                | Condition::ColExists(_)
+                | Condition::ColExistsAndType(..)
+                | Condition::ColAcceptsType(..)
+                | Condition::ColsSupplied(_)
+                | Condition::UniqueKey(_),
+            )
+            | Message::Sig(
+                Signal::StartLink
+                | Signal::EndLink
+                | Signal::CreateTable(_)
+                | Signal::AddColumn(_)
+                | Signal::DropColumn(_)
+                | Signal::AddUnique(_),
+            ) => {}
+        }
+    }
+
+    fn result(&self) -> Result<(), Vec<CheckError>> {
+        match self.errors.is_empty() {
+            true => Ok(()),
+            false => Err(self.errors.clone()),
+        }
+    }
+}
+
 // === Concrete Impls ===
 
 pub struct CompiledChecker<'s> {
     schema: &'s CompiledSchema,
-    state: (),
-    // another field should go here to track user additions
-    // TODO
+    /// Tables created or altered by the user, these shadow the tables of the schema
+    additions: CompiledSchema,
+    /// The schema objects changed by the user, in order
+    changes: Vec<SchemaObject>,
+    state: State,
+}
+
+impl<'s> CompiledChecker<'s> {
+    pub fn new(schema: &'s CompiledSchema) -> Self {
+        Self {
+            schema,
+            additions: CompiledSchema::new(),
+            changes: Vec::new(),
+            state: State::default(),
+        }
+    }
+
+    /// Returns a table, including the changes made to it by the user.
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.additions
+            .table(name)
+            .or_else(|| self.schema.table(name))
+    }
+
+    /// Returns the schema type of a column, recording an error if it does not exist.
+    fn lookup(&mut self, col: &str) -> Option<ExprType> {
+        let (table, name) = self.state.table_for(col)?;
+        let kind = self.table(&table)?.column(&name).map(|c| c.kind());
+        if kind.is_none() {
+            self.state
+                .errors
+                .push(CheckError::UnknownColumn(table, name));
+        }
+        kind
+    }
+
+    /// Returns a copy of the current table, for it to be altered.
+    fn current_table(&self) -> Option<Table> {
+        self.table(self.state.table.as_deref()?).cloned()
+    }
 }
 
 #[derive(Default)]
 pub struct DerivedChecker {
     schema: DerivedSchema,
-    state: (),
+    /// The schema objects changed by the user, in order
+    changes: Vec<SchemaObject>,
+    state: State,
 }
 
 impl DerivedChecker {
     pub fn new() -> Self {
         Self {
             schema: DerivedSchema::new(),
-            state: (),
+            changes: Vec::new(),
+            state: State::default(),
+        }
+    }
+
+    /// Records the use of a column, and any inconsistencies with its previous uses.
+    fn record(&mut self, col: &str, kind: ExprType) {
+        let Some((table, name)) = self.state.table_for(col) else {
+            return;
+        };
+        if let Err((expected, found)) = self.schema.record(&table, &name, kind) {
+            self.state.errors.push(CheckError::ColTypeMismatch(
+                col.to_string(),
+                expected,
+                found,
+            ));
         }
     }
 }
@@ -42,34 +291,352 @@ impl Server for DerivedChecker {
     type Msg = Message;
 
     fn accept(&mut self, msg: Self::Msg) {
-        todo!()
+        if let Some(change) = self.state.track(&msg, &self.changes) {
+            self.changes.push(change);
+        }
+        match msg {
+            Message::Cond(Condition::TableExists(table)) => self.state.table = Some(table),
+            Message::Cond(Condition::TableJoined(table, nullability)) => {
+                self.state.joined.push((table, nullability))
+            }
+            Message::Sig(Signal::CreateTable(table)) => {
+                for column in table.columns() {
+                    let recorded = self
+                        .schema
+                        .record(table.name(), column.name(), column.kind());
+                    if let Err((expected, found)) = recorded {
+                        self.state.errors.push(CheckError::ColTypeMismatch(
+                            column.name().to_string(),
+                            expected,
+                            found,
+                        ));
+                    }
+                }
+            }
+            Message::Sig(Signal::AddColumn(column)) => self.record(column.name(), column.kind()),
+            Message::Sig(Signal::DropColumn(col)) => {
+                if let Some((table, name)) = self.state.table_for(&col) {
+                    self.schema.remove(&table, &name);
+                }
+            }
+            Message::Sig(Signal::AddUnique(cols)) => {
+                for col in cols {
+                    self.record(&col, ExprType::Any);
+                }
+            }
+            Message::Cond(Condition::ColExists(col)) => self.record(&col, ExprType::Any),
+            // constraints are not derived
+            Message::Cond(Condition::ColsSupplied(_)) => {}
+            Message::Cond(Condition::UniqueKey(cols)) => {
+                for col in cols {
+                    self.record(&col, ExprType::Any);
+                }
+            }
+            Message::Cond(Condition::ColExistsAndType(col, ExprType::Any)) => {
+                self.record(&col, ExprType::Any);
+                self.state.link(&col);
+            }
+            Message::Cond(Condition::ColExistsAndType(col, kind)) => self.record(&col, kind),
+            Message::Cond(Condition::ColAcceptsType(col, kind, _)) => self.record(&col, kind),
+            Message::Sig(Signal::StartLink) => self.state.links.push(Vec::new()),
+            Message::Sig(Signal::EndLink) => {
+                let Some(group) = self.state.links.pop() else {
+                    return;
+                };
+                let folded = group
+                    .iter()
+                    .filter_map(|col| self.col_type(col))
+                    .try_fold(ExprType::Any, ExprType::try_fold);
+                match folded {
+                    Ok(kind) => {
+                        for col in group {
+                            self.record(&col, kind);
+                        }
+                    }
+                    Err((expected, found)) => self
+                        .state
+                        .errors
+                        .push(CheckError::TypeMismatch(expected, found)),
+                }
+            }
+            msg => self.state.accept(msg),
+        }
     }
 }
 impl ServerHandler for DerivedChecker {
-    fn state(&self) -> Result<(), ()> {
-        todo!()
+    type Error = Vec<CheckError>;
+
+    fn state(&self) -> Result<(), Self::Error> {
+        self.state.result()
     }
 
+    /// Resets the state of the current query.
+    /// Any column types that were derived are kept.
     fn reset(&mut self) {
-        todo!()
+        self.state = State::default();
+    }
+}
+impl Checker for DerivedChecker {
+    fn col_type(&self, col: &str) -> Option<ExprType> {
+        let (table, name) = self.state.resolve(col)?;
+        self.schema.column(table, name)
+    }
+
+    /// Constraints are not derived, hence all columns are nullable.
+    fn col_nullability(&self, col: &str) -> Nullability {
+        Nullability::Nullable
+    }
+
+    fn dependencies(&self) -> Dependencies {
+        Dependencies {
+            version: self.changes.len(),
+            ..self.state.deps.clone()
+        }
+    }
+
+    fn warnings(&self) -> Vec<CheckError> {
+        self.state.warnings.clone()
+    }
+
+    /// Derived tables are only known through their use, hence never fully known.
+    fn table_cols(&self) -> Option<Vec<Column>> {
+        None
     }
 }
-impl Checker for DerivedChecker {}
 
 impl<'s> Server for CompiledChecker<'s> {
     type Msg = Message;
 
     fn accept(&mut self, msg: Self::Msg) {
-        todo!()
+        if let Some(change) = self.state.track(&msg, &self.changes) {
+            self.changes.push(change);
+        }
+        match msg {
+            Message::Cond(Condition::TableExists(table)) => {
+                if self.table(&table).is_none() {
+                    self.state
+                        .errors
+                        .push(CheckError::UnknownTable(table.clone()));
+                }
+                self.state.table = Some(table);
+            }
+            Message::Cond(Condition::TableJoined(table, nullability)) => {
+                if self.table(&table).is_none() {
+                    self.state
+                        .errors
+                        .push(CheckError::UnknownTable(table.clone()));
+                }
+                self.state.joined.push((table, nullability));
+            }
+            Message::Cond(Condition::ColExists(col)) => {
+                self.lookup(&col);
+            }
+            Message::Sig(Signal::CreateTable(table)) => match self.table(table.name()) {
+                Some(_) => self
+                    .state
+                    .errors
+                    .push(CheckError::TableExists(table.name().to_string())),
+                None => self.additions.insert(table),
+            },
+            Message::Sig(Signal::AddColumn(column)) => {
+                let Some(table) = self.current_table() else {
+                    return;
+                };
+                match table.column(column.name()) {
+                    Some(_) => self.state.errors.push(CheckError::DuplicateColumn(
+                        table.name().to_string(),
+                        column.name().to_string(),
+                    )),
+                    None => self.additions.insert(table.with(column)),
+                }
+            }
+            Message::Sig(Signal::DropColumn(col)) => {
+                if self.lookup(&col).is_none() {
+                    return;
+                }
+                if let Some(table) = self.current_table() {
+                    self.additions.insert(table.without(&col));
+                }
+            }
+            Message::Sig(Signal::AddUnique(cols)) => {
+                for col in &cols {
+                    self.lookup(col);
+                }
+                if let Some(table) = self.current_table() {
+                    self.additions.insert(table.with_unique(cols));
+                }
+            }
+            Message::Cond(Condition::ColsSupplied(cols)) => {
+                let Some(table) = self.state.table.clone() else {
+                    return;
+                };
+                let Some(schema) = self.table(&table) else {
+                    return;
+                };
+                let missing: Vec<_> = schema
+                    .columns()
+                    .iter()
+                    .filter(|column| {
+                        column.is_required() && !cols.iter().any(|c| c == column.name())
+                    })
+                    .map(|column| {
+                        CheckError::MissingColumn(table.clone(), column.name().to_string())
+                    })
+                    .collect();
+                self.state.errors.extend(missing);
+            }
+            Message::Cond(Condition::UniqueKey(cols)) => {
+                for col in &cols {
+                    self.lookup(col);
+                }
+                let Some(table) = self.state.table.clone() else {
+                    return;
+                };
+                match self.table(&table) {
+                    Some(schema) if !schema.is_unique(&cols) => {
+                        self.state.errors.push(CheckError::NotUnique(table, cols))
+                    }
+                    _ => {}
+                }
+            }
+            Message::Cond(Condition::ColExistsAndType(col, ExprType::Any)) => {
+                self.lookup(&col);
+                self.state.link(&col);
+            }
+            Message::Cond(Condition::ColExistsAndType(col, kind)) => {
+                if let Some(found) = self.lookup(&col) {
+                    if ExprType::try_fold(kind, found).is_err() {
+                        self.state
+                            .errors
+                            .push(CheckError::ColTypeMismatch(col, kind, found));
+                    }
+                }
+            }
+            Message::Cond(Condition::ColAcceptsType(col, kind, nullability)) => {
+                let Some(expected) = self.lookup(&col) else {
+                    return;
+                };
+                if ExprType::try_fold(expected, kind).is_err() {
+                    self.state.errors.push(CheckError::ColTypeMismatch(
+                        col.clone(),
+                        expected,
+                        kind,
+                    ));
+                }
+                if self.col_nullability(&col) == Nullability::NotNull {
+                    match nullability {
+                        Nullability::NotNull => {}
+                        Nullability::Nullable => {
+                            self.state.warnings.push(CheckError::NullableValue(col))
+                        }
+                        Nullability::Null => self.state.errors.push(CheckError::NullValue(col)),
+                    }
+                }
+            }
+            Message::Sig(Signal::StartLink) => self.state.links.push(Vec::new()),
+            Message::Sig(Signal::EndLink) => {
+                let Some(group) = self.state.links.pop() else {
+                    return;
+                };
+                let folded = group
+                    .iter()
+                    .filter_map(|col| self.col_type(col))
+                    .try_fold(ExprType::Any, ExprType::try_fold);
+                if let Err((expected, found)) = folded {
+                    self.state
+                        .errors
+                        .push(CheckError::TypeMismatch(expected, found));
+                }
+            }
+            msg => self.state.accept(msg),
+        }
     }
 }
 impl<'s> ServerHandler for CompiledChecker<'s> {
-    fn state(&self) -> Result<(), ()> {
-        todo!()
+    type Error = Vec<CheckError>;
+
+    fn state(&self) -> Result<(), Self::Error> {
+        self.state.result()
     }
 
     fn reset(&mut self) {
-        todo!()
+        self.state = State::default();
+    }
+}
+impl<'s> Checker for CompiledChecker<'s> {
+    fn col_type(&self, col: &str) -> Option<ExprType> {
+        let (table, name) = self.state.resolve(col)?;
+        Some(self.table(table)?.column(name)?.kind())
+    }
+
+    fn col_nullability(&self, col: &str) -> Nullability {
+        let column = self
+            .state
+            .resolve(col)
+            .and_then(|(table, name)| self.table(table)?.column(name));
+        match (column, self.state.resolve(col)) {
+            (Some(column), Some((table, _))) => column
+                .nullability()
+                .propagate(self.state.join_nullability(table)),
+            _ => Nullability::Nullable,
+        }
+    }
+
+    fn dependencies(&self) -> Dependencies {
+        Dependencies {
+            version: self.changes.len(),
+            ..self.state.deps.clone()
+        }
+    }
+
+    fn warnings(&self) -> Vec<CheckError> {
+        self.state.warnings.clone()
+    }
+
+    fn table_cols(&self) -> Option<Vec<Column>> {
+        let table = self.table(self.state.table.as_deref()?)?;
+        Some(table.columns().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{any::col, num::Numeric, text::Textual};
+    use crate::schema::Table;
+
+    #[test]
+    fn derived_infers_types() {
+        let mut checker = DerivedChecker::new();
+        checker.accept(Condition::TableExists("posts".into()).into());
+        Numeric::gt(col("likes"), 3).send_all(ExprType::Bool, &mut checker);
+        assert!(checker.state().is_ok());
+        assert_eq!(checker.col_type("likes"), Some(ExprType::Num));
+
+        col("likes").len().send_all(ExprType::Num, &mut checker);
+        assert!(checker.state().is_err());
+    }
+
+    #[test]
+    fn compiled_validates() {
+        let schema = CompiledSchema::new().with_table(
+            Table::new("posts")
+                .with_column("likes", ExprType::Num)
+                .with_column("title", ExprType::Text),
+        );
+        let mut checker = CompiledChecker::new(&schema);
+        checker.accept(Condition::TableExists("posts".into()).into());
+        col("title").len().send_all(ExprType::Num, &mut checker);
+        assert!(checker.state().is_ok());
+
+        col("likes").len().send_all(ExprType::Num, &mut checker);
+        col("views").send_all(ExprType::Any, &mut checker);
+        assert_eq!(
+            checker.state(),
+            Err(vec![
+                CheckError::ColTypeMismatch("likes".into(), ExprType::Text, ExprType::Num),
+                CheckError::UnknownColumn("posts".into(), "views".into()),
+            ])
+        );
     }
 }
-impl<'s> Checker for CompiledChecker<'s> {}
@@ -0,0 +1,79 @@
+/// A part of the schema that statements read or change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaObject {
+    Table(String),
+    /// (Table, Column)
+    Column(String, String),
+    /// A unique constraint, its columns are kept sorted
+    /// (Table, Columns)
+    Unique(String, Vec<String>),
+}
+
+impl SchemaObject {
+    /// Creates a unique constraint object, independent of the order of its columns.
+    pub fn unique(table: impl Into<String>, cols: &[String]) -> Self {
+        let mut cols = cols.to_vec();
+        cols.sort();
+        SchemaObject::Unique(table.into(), cols)
+    }
+
+    pub fn table(&self) -> &str {
+        match self {
+            SchemaObject::Table(table)
+            | SchemaObject::Column(table, _)
+            | SchemaObject::Unique(table, _) => table,
+        }
+    }
+
+    /// Whether a change to this object also changes the other one.
+    /// Changing a table changes everything within it.
+    pub fn covers(&self, other: &SchemaObject) -> bool {
+        match self {
+            SchemaObject::Table(table) => other.table() == table,
+            _ => self == other,
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaObject::Table(table) => write!(f, "{table}"),
+            SchemaObject::Column(table, col) => write!(f, "{table}.{col}"),
+            SchemaObject::Unique(table, cols) => write!(f, "{table}({})", cols.join(", ")),
+        }
+    }
+}
+
+/// The schema objects that a statement reads and changes, as recorded by its checker.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dependencies {
+    /// The objects that the statement reads
+    pub reads: Vec<SchemaObject>,
+    /// The objects read in a form that was created by earlier changes of the checker's session
+    pub requires: Vec<SchemaObject>,
+    /// The objects that the statement changes
+    pub writes: Vec<SchemaObject>,
+    /// The number of changes the checker had applied once the statement was sealed,
+    /// including the statement's own
+    pub version: usize,
+}
+
+impl Dependencies {
+    /// Combines the dependencies of two statements that are sealed into one.
+    pub fn merge(mut self, other: Dependencies) -> Self {
+        for (objects, others) in [
+            (&mut self.reads, other.reads),
+            (&mut self.requires, other.requires),
+            (&mut self.writes, other.writes),
+        ] {
+            for object in others {
+                if !objects.contains(&object) {
+                    objects.push(object);
+                }
+            }
+        }
+        self.version = self.version.max(other.version);
+        self
+    }
+}
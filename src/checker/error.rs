@@ -0,0 +1,87 @@
+use super::Clause;
+use crate::expr::ExprType;
+
+/// The errors that the standard [`crate::checker::Checker`]s can find
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckError {
+    /// The table does not exist
+    UnknownTable(String),
+    /// The column does not exist within the table
+    /// (Table, Column)
+    UnknownColumn(String, String),
+    /// A column was accessed without a table in context
+    NoTable(String),
+    /// Mismatch of CommonExpression types
+    /// (Expected, Found)
+    TypeMismatch(ExprType, ExprType),
+    /// A column is used with a type that differs from its known one
+    /// (Column, Expected, Found)
+    ColTypeMismatch(String, ExprType, ExprType),
+    /// A comparison against `NULL`, which always evaluates into `NULL`
+    NullComparison,
+    /// A window function within a clause that is evaluated before windows are
+    MisplacedWindow(Clause),
+    /// A named window that has not been defined
+    UnknownWindow(String),
+    /// A `NOT NULL` column without a default is not given a value
+    /// (Table, Column)
+    MissingColumn(String, String),
+    /// A `NULL` value is written into a `NOT NULL` column
+    NullValue(String),
+    /// A value that may be `NULL` is written into a `NOT NULL` column
+    NullableValue(String),
+    /// The columns don't match any unique constraint of the table
+    /// (Table, Columns)
+    NotUnique(String, Vec<String>),
+    /// A table is created with the name of an existing one
+    TableExists(String),
+    /// A column is added with the name of an existing one
+    /// (Table, Column)
+    DuplicateColumn(String, String),
+}
+
+impl std::fmt::Display for CheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckError::UnknownTable(table) => write!(f, "unknown table `{table}`"),
+            CheckError::UnknownColumn(table, col) => {
+                write!(f, "unknown column `{col}` in table `{table}`")
+            }
+            CheckError::NoTable(col) => write!(f, "column `{col}` accessed without a table"),
+            CheckError::TypeMismatch(expected, found) => {
+                write!(f, "type mismatch: expected {expected:?}, found {found:?}")
+            }
+            CheckError::ColTypeMismatch(col, expected, found) => write!(
+                f,
+                "type mismatch for column `{col}`: expected {expected:?}, found {found:?}"
+            ),
+            CheckError::NullComparison => write!(
+                f,
+                "comparison against NULL is never true, use `is_null` instead"
+            ),
+            CheckError::MisplacedWindow(clause) => {
+                write!(f, "window functions are not allowed in {clause}")
+            }
+            CheckError::UnknownWindow(name) => write!(f, "unknown window `{name}`"),
+            CheckError::MissingColumn(table, col) => write!(
+                f,
+                "column `{col}` in table `{table}` is NOT NULL without a default, but has no value"
+            ),
+            CheckError::NullValue(col) => write!(f, "NULL value for NOT NULL column `{col}`"),
+            CheckError::NullableValue(col) => {
+                write!(f, "value for NOT NULL column `{col}` may be NULL")
+            }
+            CheckError::NotUnique(table, cols) => write!(
+                f,
+                "columns ({}) don't match a unique constraint of table `{table}`",
+                cols.join(", ")
+            ),
+            CheckError::TableExists(table) => write!(f, "table `{table}` already exists"),
+            CheckError::DuplicateColumn(table, col) => {
+                write!(f, "column `{col}` already exists in table `{table}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CheckError {}
@@ -1,12 +1,30 @@
-use crate::expr::ExprType;
+use crate::expr::{ExprType, Nullability};
+use crate::schema::{Column, Table};
 
 /// The conditions that the standard [`crate::checker::Checker`]s verify
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Condition {
+    /// Does a table exist in this context.
+    /// Subsequent column conditions refer to this table.
+    TableExists(String),
+    /// Does a table exist in this context, joined with the current table.
+    /// Its columns are referred to as `table.col`, and have at least the given nullability,
+    /// which is `Nullable` for outer joins.
+    TableJoined(String, Nullability),
     /// Does a column exist in this context
     ColExists(String),
     /// Does a column exist in this context, and does it have this type?
     ColExistsAndType(String, ExprType),
+    /// Does a column exist in this context, and does it accept values of this type and
+    /// nullability?
+    /// Unlike `ColExistsAndType`, the column's type is the expected one.
+    ColAcceptsType(String, ExprType, Nullability),
+    /// Has a named window been defined in this context
+    WindowExists(String),
+    /// Are all of the columns that require a value within this list
+    ColsSupplied(Vec<String>),
+    /// Do the columns match a unique constraint of the current table
+    UniqueKey(Vec<String>),
 }
 
 impl From<Condition> for Message {
@@ -15,7 +33,7 @@ impl From<Condition> for Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Signal {
     /// Signifies the start of a linking process
     StartLink,
@@ -24,6 +42,58 @@ pub enum Signal {
     /// Mismatch of CommonExpression types
     /// (Expected, Found)
     TypeMismatch((ExprType, ExprType)),
+    /// Comparison against an operand that is always `NULL`,
+    /// hence the comparison is never true.
+    /// It is valid SQL, hence only a warning.
+    NullComparison,
+    /// Signifies the start of a clause that restricts its expressions
+    StartClause(Clause),
+    /// Signifies the end of the current clause
+    EndClause,
+    /// Defines a named window within this context
+    DefineWindow(String),
+    /// A window function is used
+    WindowFunction,
+    /// Defines a new table, or view
+    CreateTable(Table),
+    /// Adds a column into the current table
+    AddColumn(Column),
+    /// Drops a column from the current table
+    DropColumn(String),
+    /// Adds a unique constraint over columns of the current table
+    AddUnique(Vec<String>),
+}
+
+/// Clauses of a query that restrict the expressions within them.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Clause {
+    /// `WHERE`
+    Filter,
+    /// `GROUP BY`
+    GroupBy,
+    /// The `ON` condition of a `JOIN`
+    Join,
+}
+
+impl std::fmt::Display for Clause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Clause::Filter => write!(f, "WHERE"),
+            Clause::GroupBy => write!(f, "GROUP BY"),
+            Clause::Join => write!(f, "JOIN ... ON"),
+        }
+    }
+}
+
+impl Signal {
+    /// Returns a `TypeMismatch` message if an expression of type `found` is sent within a
+    /// context that expects another type.
+    pub fn ctx_mismatch(ctx: ExprType, found: ExprType) -> Vec<Message> {
+        match ExprType::try_fold(ctx, found) {
+            Ok(_) => Vec::new(),
+            Err(mismatch) => vec![Signal::TypeMismatch(mismatch).into()],
+        }
+    }
 }
 
 impl From<Signal> for Message {
@@ -32,7 +102,7 @@ impl From<Signal> for Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Message {
     Cond(Condition),
     Sig(Signal),
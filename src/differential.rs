@@ -0,0 +1,592 @@
+//! Differential tests between the interpreter and SQLite.
+//!
+//! Random well-typed expression trees are built out of the `expr` nodes, rendered in the SQLite
+//! dialect and evaluated over a single row by both SQLite and the interpreter. As the interpreter
+//! doesn't go through `display`, any disagreement points at a rendering bug such as a missing
+//! parenthesis or a badly escaped literal, and proptest shrinks it into a minimal tree.
+
+use std::num::NonZeroUsize;
+
+use proptest::prelude::*;
+
+use crate::checker::CompiledChecker;
+use crate::expr::{
+    any::{col, param, Case, CaseBranch, Coalesce, NullIf, NULL},
+    bool::{And, Between, Eq, Geq, Gt, IsDistinct, IsNull, Leq, Like, Lt, Neq, Not, Or},
+    cast::{AsNum, AsText, Cast},
+    num::{Abs, Add, Ceil, Div, Floor, Len, Ln, Mul, Neg, Position, Power, Rem, Round, Sqrt, Sub},
+    text::{escape_like, Concat, Lower, Replace, Substring, Trim, TrimSide, Upper},
+    Dialect, ExprType, Expression,
+};
+use crate::interpreter::{compare, eval, MapRow};
+use crate::multi;
+use crate::row::Value;
+use crate::schema::{CompiledSchema, Table};
+use crate::sqlite::SqliteExecutor;
+use crate::writer::Writer;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Bool,
+    Num,
+    Text,
+}
+
+impl Kind {
+    fn expr_type(self) -> ExprType {
+        match self {
+            Kind::Bool => ExprType::Bool,
+            Kind::Num => ExprType::Num,
+            Kind::Text => ExprType::Text,
+        }
+    }
+}
+
+/// The columns of the test table. Both `n` and `f` hold numbers, the latter only non-integral
+/// ones, as SQLite stores integral floats of `NUMERIC` columns as integers.
+const COLS: [(&str, Kind); 4] = [
+    ("b", Kind::Bool),
+    ("n", Kind::Num),
+    ("f", Kind::Num),
+    ("s", Kind::Text),
+];
+
+#[derive(Debug, Clone, Copy)]
+enum CmpOp {
+    Eq,
+    Neq,
+    Gt,
+    Geq,
+    Lt,
+    Leq,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MathFn {
+    Neg,
+    Abs,
+    Floor,
+    Ceil,
+    Sqrt,
+    Ln,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TextFn {
+    Lower,
+    Upper,
+    Trim(TrimSide),
+}
+
+/// The literal matches built on `LIKE`.
+#[derive(Debug, Clone, Copy)]
+enum Affix {
+    Prefix,
+    Suffix,
+    Substring,
+}
+
+/// An expression tree, whose operands always have the kinds that their node expects.
+#[derive(Debug, Clone)]
+enum Node {
+    Null(Kind),
+    Bool(bool),
+    Num(i64),
+    Float(f64),
+    Str(String),
+    Col(&'static str, Kind),
+    And(Box<Node>, Box<Node>),
+    Or(Box<Node>, Box<Node>),
+    Not(Box<Node>),
+    Cmp(CmpOp, Box<Node>, Box<Node>),
+    Between {
+        x: Box<Node>,
+        lower: Box<Node>,
+        upper: Box<Node>,
+        negated: bool,
+        symmetric: bool,
+    },
+    IsNull(Box<Node>),
+    IsDistinct(Box<Node>, Box<Node>),
+    Arith(ArithOp, Box<Node>, Box<Node>),
+    Math(MathFn, Box<Node>),
+    Round(Box<Node>, Option<Box<Node>>),
+    Power(Box<Node>, Box<Node>),
+    Len(Box<Node>),
+    Position(Box<Node>, Box<Node>),
+    Like(Box<Node>, Box<Node>, bool),
+    Affix(Affix, Box<Node>, String),
+    Concat(Vec<Node>),
+    Text(TextFn, Box<Node>),
+    Substring(Box<Node>, Box<Node>, Option<Box<Node>>),
+    Replace(Box<Node>, Box<Node>, Box<Node>),
+    ToNum(Box<Node>),
+    ToText(Box<Node>),
+    Case(Vec<(Node, Node)>, Option<Box<Node>>),
+    Coalesce(Box<Node>, Box<Node>),
+    NullIf(Box<Node>, Box<Node>),
+}
+
+impl Node {
+    fn kind(&self) -> Kind {
+        match self {
+            Node::Null(kind) | Node::Col(_, kind) => *kind,
+            Node::Bool(_)
+            | Node::And(..)
+            | Node::Or(..)
+            | Node::Not(_)
+            | Node::Cmp(..)
+            | Node::Between { .. }
+            | Node::IsNull(_)
+            | Node::IsDistinct(..)
+            | Node::Like(..)
+            | Node::Affix(..) => Kind::Bool,
+            Node::Num(_)
+            | Node::Float(_)
+            | Node::Arith(..)
+            | Node::Math(..)
+            | Node::Round(..)
+            | Node::Power(..)
+            | Node::Len(_)
+            | Node::Position(..)
+            | Node::ToNum(_) => Kind::Num,
+            Node::Str(_)
+            | Node::Concat(_)
+            | Node::Text(..)
+            | Node::Substring(..)
+            | Node::Replace(..)
+            | Node::ToText(_) => Kind::Text,
+            Node::Case(branches, _) => branches[0].1.kind(),
+            Node::Coalesce(lhs, _) | Node::NullIf(lhs, _) => lhs.kind(),
+        }
+    }
+
+    /// Wraps the node so that it evaluates into the given kind.
+    fn coerce(self, kind: Kind) -> Node {
+        let boxed = |node: Node| Box::new(node);
+        let case = |cond: Node, then: Node, otherwise: Node| {
+            Node::Case(vec![(cond, then)], Some(boxed(otherwise)))
+        };
+        match (self.kind(), kind) {
+            (from, to) if from == to => self,
+            (Kind::Num, Kind::Bool) => Node::Cmp(CmpOp::Gt, boxed(self), boxed(Node::Num(0))),
+            (Kind::Text, Kind::Bool) => {
+                Node::Cmp(CmpOp::Lt, boxed(self), boxed(Node::Str("b".into())))
+            }
+            (Kind::Bool, Kind::Num) => case(self, Node::Num(1), Node::Num(0)),
+            (Kind::Text, Kind::Num) => Node::Len(boxed(self)),
+            (Kind::Bool, Kind::Text) => case(self, Node::Str("t".into()), Node::Str("f".into())),
+            (Kind::Num, Kind::Text) => case(
+                self.coerce(Kind::Bool),
+                Node::Str("pos".into()),
+                Node::Str("neg".into()),
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    fn build(&self) -> Box<dyn Expression> {
+        match self {
+            Node::Null(_) => Box::new(NULL),
+            Node::Bool(b) => Box::new(*b),
+            Node::Num(n) => Box::new(*n),
+            Node::Float(x) => Box::new(*x),
+            Node::Str(s) => Box::new(s.clone()),
+            Node::Col(name, _) => Box::new(col(*name)),
+            Node::And(lhs, rhs) => Box::new(And::new(lhs.build(), rhs.build())),
+            Node::Or(lhs, rhs) => Box::new(Or::new(lhs.build(), rhs.build())),
+            Node::Not(inner) => Box::new(Not::new(inner.build())),
+            Node::Cmp(op, lhs, rhs) => {
+                let (lhs, rhs, kind) = (lhs.build(), rhs.build(), self.operand_type());
+                match op {
+                    CmpOp::Eq => Box::new(Eq::new(lhs, rhs, kind)),
+                    CmpOp::Neq => Box::new(Neq::new(lhs, rhs, kind)),
+                    CmpOp::Gt => Box::new(Gt::new(lhs, rhs, kind)),
+                    CmpOp::Geq => Box::new(Geq::new(lhs, rhs, kind)),
+                    CmpOp::Lt => Box::new(Lt::new(lhs, rhs, kind)),
+                    CmpOp::Leq => Box::new(Leq::new(lhs, rhs, kind)),
+                }
+            }
+            Node::Between {
+                x,
+                lower,
+                upper,
+                negated,
+                symmetric,
+            } => {
+                let mut between =
+                    Between::new(x.build(), lower.build(), upper.build(), self.operand_type());
+                if *negated {
+                    between = between.negated();
+                }
+                if *symmetric {
+                    between = between.symmetric();
+                }
+                Box::new(between)
+            }
+            Node::IsNull(inner) => Box::new(IsNull::new(inner.build())),
+            Node::IsDistinct(lhs, rhs) => Box::new(IsDistinct::new(
+                lhs.build(),
+                rhs.build(),
+                self.operand_type(),
+            )),
+            Node::Arith(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.build(), rhs.build());
+                match op {
+                    ArithOp::Add => Box::new(Add::new(lhs, rhs)),
+                    ArithOp::Sub => Box::new(Sub::new(lhs, rhs)),
+                    ArithOp::Mul => Box::new(Mul::new(lhs, rhs)),
+                    ArithOp::Div => Box::new(Div::new(lhs, rhs)),
+                    ArithOp::Rem => Box::new(Rem::new(lhs, rhs)),
+                }
+            }
+            Node::Math(f, inner) => {
+                let inner = inner.build();
+                match f {
+                    MathFn::Neg => Box::new(Neg::new(inner)),
+                    MathFn::Abs => Box::new(Abs::new(inner)),
+                    MathFn::Floor => Box::new(Floor::new(inner)),
+                    MathFn::Ceil => Box::new(Ceil::new(inner)),
+                    MathFn::Sqrt => Box::new(Sqrt::new(inner)),
+                    MathFn::Ln => Box::new(Ln::new(inner)),
+                }
+            }
+            Node::Round(x, digits) => Box::new(Round::new(
+                x.build(),
+                digits.as_ref().map(|digits| digits.build()),
+            )),
+            Node::Power(base, exponent) => Box::new(Power::new(base.build(), exponent.build())),
+            Node::Len(inner) => Box::new(Len::new(inner.build())),
+            Node::Position(x, needle) => Box::new(Position::new(x.build(), needle.build())),
+            Node::Like(x, pattern, case_insensitive) => {
+                Box::new(Like::new(x.build(), pattern.build(), *case_insensitive))
+            }
+            Node::Affix(affix, x, literal) => {
+                let literal = escape_like(literal);
+                let pattern = match affix {
+                    Affix::Prefix => format!("{literal}%"),
+                    Affix::Suffix => format!("%{literal}"),
+                    Affix::Substring => format!("%{literal}%"),
+                };
+                Box::new(Like::escaped(x.build(), pattern))
+            }
+            Node::Concat(nodes) => Box::new(Concat::new(nodes.iter().map(Node::build).collect())),
+            Node::Text(f, inner) => {
+                let inner = inner.build();
+                match f {
+                    TextFn::Lower => Box::new(Lower::new(inner)),
+                    TextFn::Upper => Box::new(Upper::new(inner)),
+                    TextFn::Trim(side) => Box::new(Trim::new(inner, *side)),
+                }
+            }
+            Node::Substring(x, start, len) => Box::new(Substring::new(
+                x.build(),
+                start.build(),
+                len.as_ref().map(|len| len.build()),
+            )),
+            Node::Replace(x, from, to) => {
+                Box::new(Replace::new(x.build(), from.build(), to.build()))
+            }
+            Node::ToNum(inner) => {
+                Box::new(Cast::<AsNum>::new(inner.build(), inner.kind().expr_type()))
+            }
+            Node::ToText(inner) => {
+                Box::new(Cast::<AsText>::new(inner.build(), inner.kind().expr_type()))
+            }
+            Node::Case(branches, otherwise) => {
+                let mut arms: Vec<_> = branches
+                    .iter()
+                    .map(|(cond, then)| CaseBranch::new(Some(cond.build()), then.build()))
+                    .collect();
+                if let Some(otherwise) = otherwise {
+                    arms.push(CaseBranch::new(None, otherwise.build()));
+                }
+                Box::new(Case::new(arms))
+            }
+            Node::Coalesce(lhs, rhs) => Box::new(Coalesce::new(vec![lhs.build(), rhs.build()])),
+            Node::NullIf(lhs, rhs) => Box::new(NullIf::new(lhs.build(), rhs.build())),
+        }
+    }
+
+    /// The type of the operands of a comparison.
+    fn operand_type(&self) -> ExprType {
+        match self {
+            Node::Cmp(_, lhs, _) | Node::IsDistinct(lhs, _) | Node::Between { x: lhs, .. } => {
+                lhs.kind().expr_type()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn kind() -> impl Strategy<Value = Kind> {
+    prop_oneof![Just(Kind::Bool), Just(Kind::Num), Just(Kind::Text)]
+}
+
+/// Text with the characters that need escaping or that have a meaning within SQL or `LIKE`,
+/// along with spaces and letters of either case.
+fn text() -> impl Strategy<Value = String> {
+    "[ab A'\"%_!\\\\é-]{0,4}"
+}
+
+fn leaf() -> impl Strategy<Value = Node> {
+    prop_oneof![
+        kind().prop_map(Node::Null),
+        prop::sample::select(COLS.to_vec()).prop_map(|(name, kind)| Node::Col(name, kind)),
+        any::<bool>().prop_map(Node::Bool),
+        (-20i64..20).prop_map(Node::Num),
+        prop::sample::select(vec![-2.5, 1e-7, 0.1, 1.0, 1e20]).prop_map(Node::Float),
+        // a negated negative literal renders as `- -n`, which mustn't start a comment
+        (-20i64..0).prop_map(|n| Node::Math(MathFn::Neg, Box::new(Node::Num(n)))),
+        text().prop_map(Node::Str),
+    ]
+}
+
+fn node() -> impl Strategy<Value = Node> {
+    leaf().prop_recursive(5, 48, 3, |inner| {
+        let boxed = |node: Node, kind: Kind| Box::new(node.coerce(kind));
+        let cmp = prop_oneof![
+            Just(CmpOp::Eq),
+            Just(CmpOp::Neq),
+            Just(CmpOp::Gt),
+            Just(CmpOp::Geq),
+            Just(CmpOp::Lt),
+            Just(CmpOp::Leq),
+        ];
+        let arith = prop_oneof![
+            Just(ArithOp::Add),
+            Just(ArithOp::Sub),
+            Just(ArithOp::Mul),
+            Just(ArithOp::Div),
+            Just(ArithOp::Rem),
+        ];
+        let math = prop_oneof![
+            Just(MathFn::Neg),
+            Just(MathFn::Abs),
+            Just(MathFn::Floor),
+            Just(MathFn::Ceil),
+            Just(MathFn::Sqrt),
+            Just(MathFn::Ln),
+        ];
+        let text_fn = prop_oneof![
+            Just(TextFn::Lower),
+            Just(TextFn::Upper),
+            Just(TextFn::Trim(TrimSide::Both)),
+            Just(TextFn::Trim(TrimSide::Leading)),
+            Just(TextFn::Trim(TrimSide::Trailing)),
+        ];
+        let affix = prop_oneof![
+            Just(Affix::Prefix),
+            Just(Affix::Suffix),
+            Just(Affix::Substring),
+        ];
+        let pair = (inner.clone(), inner.clone());
+        prop_oneof![
+            pair.clone()
+                .prop_map(move |(l, r)| Node::And(boxed(l, Kind::Bool), boxed(r, Kind::Bool))),
+            pair.clone()
+                .prop_map(move |(l, r)| Node::Or(boxed(l, Kind::Bool), boxed(r, Kind::Bool))),
+            inner
+                .clone()
+                .prop_map(move |x| Node::Not(boxed(x, Kind::Bool))),
+            (cmp, pair.clone()).prop_map(move |(op, (l, r))| {
+                let kind = l.kind();
+                Node::Cmp(op, Box::new(l), boxed(r, kind))
+            }),
+            (inner.clone(), pair.clone(), any::<bool>(), any::<bool>()).prop_map(
+                move |(x, (lower, upper), negated, symmetric)| {
+                    let kind = x.kind();
+                    Node::Between {
+                        x: Box::new(x),
+                        lower: boxed(lower, kind),
+                        upper: boxed(upper, kind),
+                        negated,
+                        symmetric,
+                    }
+                }
+            ),
+            inner.clone().prop_map(|x| Node::IsNull(Box::new(x))),
+            pair.clone().prop_map(move |(l, r)| {
+                let kind = l.kind();
+                Node::IsDistinct(Box::new(l), boxed(r, kind))
+            }),
+            (arith, pair.clone()).prop_map(move |(op, (l, r))| {
+                Node::Arith(op, boxed(l, Kind::Num), boxed(r, Kind::Num))
+            }),
+            (math, inner.clone()).prop_map(move |(f, x)| Node::Math(f, boxed(x, Kind::Num))),
+            (
+                inner.clone(),
+                prop::option::of(prop_oneof![(-2i64..4).prop_map(Node::Num), inner.clone()])
+            )
+                .prop_map(move |(x, digits)| {
+                    Node::Round(boxed(x, Kind::Num), digits.map(|d| boxed(d, Kind::Num)))
+                }),
+            pair.clone().prop_map(move |(base, exponent)| {
+                Node::Power(boxed(base, Kind::Num), boxed(exponent, Kind::Num))
+            }),
+            inner
+                .clone()
+                .prop_map(move |x| Node::Len(boxed(x, Kind::Text))),
+            pair.clone().prop_map(move |(x, needle)| {
+                Node::Position(boxed(x, Kind::Text), boxed(needle, Kind::Text))
+            }),
+            (pair.clone(), any::<bool>()).prop_map(move |((x, pattern), case_insensitive)| {
+                Node::Like(
+                    boxed(x, Kind::Text),
+                    boxed(pattern, Kind::Text),
+                    case_insensitive,
+                )
+            }),
+            (affix, inner.clone(), text()).prop_map(move |(affix, x, literal)| Node::Affix(
+                affix,
+                boxed(x, Kind::Text),
+                literal
+            )),
+            prop::collection::vec(inner.clone(), 2..4).prop_map(|nodes| {
+                Node::Concat(nodes.into_iter().map(|n| n.coerce(Kind::Text)).collect())
+            }),
+            (text_fn, inner.clone()).prop_map(move |(f, x)| Node::Text(f, boxed(x, Kind::Text))),
+            (pair.clone(), prop::option::of(inner.clone())).prop_map(move |((x, start), len)| {
+                Node::Substring(
+                    boxed(x, Kind::Text),
+                    boxed(start, Kind::Num),
+                    len.map(|len| boxed(len, Kind::Num)),
+                )
+            }),
+            (inner.clone(), pair.clone()).prop_map(move |(x, (from, to))| Node::Replace(
+                boxed(x, Kind::Text),
+                boxed(from, Kind::Text),
+                boxed(to, Kind::Text)
+            )),
+            // like the builders, numbers are converted from text or booleans and into text
+            inner.clone().prop_map(|x| match x.kind() {
+                Kind::Num => Node::ToNum(Box::new(x.coerce(Kind::Text))),
+                _ => Node::ToNum(Box::new(x)),
+            }),
+            inner.clone().prop_map(|x| match x.kind() {
+                Kind::Text => Node::ToText(Box::new(x.coerce(Kind::Num))),
+                _ => Node::ToText(Box::new(x)),
+            }),
+            (
+                prop::collection::vec(pair.clone(), 1..3),
+                prop::option::of(inner.clone())
+            )
+                .prop_map(move |(branches, otherwise)| {
+                    let kind = branches[0].1.kind();
+                    let branches = branches
+                        .into_iter()
+                        .map(|(cond, then)| (cond.coerce(Kind::Bool), then.coerce(kind)))
+                        .collect();
+                    Node::Case(branches, otherwise.map(|x| boxed(x, kind)))
+                }),
+            pair.clone().prop_map(move |(l, r)| {
+                let kind = l.kind();
+                Node::Coalesce(Box::new(l), boxed(r, kind))
+            }),
+            pair.prop_map(move |(l, r)| {
+                let kind = l.kind();
+                Node::NullIf(Box::new(l), boxed(r, kind))
+            }),
+        ]
+    })
+}
+
+/// The values of the columns of the single row of the test table.
+fn row() -> impl Strategy<Value = MapRow> {
+    (
+        prop::option::of(any::<bool>()),
+        prop::option::of(-20i64..20),
+        prop::option::of(prop::sample::select(vec![
+            -1e20, -2.5, -0.75, 1.5e-7, 0.1, 0.5, 1.25, 2.125,
+        ])),
+        prop::option::of(text()),
+    )
+        .prop_map(|(b, n, f, s)| {
+            MapRow::from([
+                ("b".to_string(), b.map_or(Value::Null, Value::Bool)),
+                ("n".to_string(), n.map_or(Value::Null, Value::Int)),
+                ("f".to_string(), f.map_or(Value::Null, Value::Float)),
+                ("s".to_string(), s.map_or(Value::Null, Value::Text)),
+            ])
+        })
+}
+
+fn schema() -> CompiledSchema {
+    CompiledSchema::new().with_table(
+        Table::new("t")
+            .with_column("b", ExprType::Bool)
+            .with_column("n", ExprType::Num)
+            .with_column("f", ExprType::Num)
+            .with_column("s", ExprType::Text),
+    )
+}
+
+/// Evaluates the expression with SQLite over a table holding the row.
+fn sqlite_eval(expr: &dyn Expression, row: &MapRow) -> Result<Value, String> {
+    let schema = schema();
+    let db = SqliteExecutor::open_in_memory()?;
+    db.apply_schema(&schema)?;
+
+    let mut checker = CompiledChecker::new(&schema);
+    let insert = Writer::new(&mut checker)
+        .insert_into("t", COLS.map(|(name, _)| name))
+        .values(multi![
+            param(NonZeroUsize::MIN),
+            param(NonZeroUsize::new(2).unwrap()),
+            param(NonZeroUsize::new(3).unwrap()),
+            param(NonZeroUsize::new(4).unwrap())
+        ])
+        .seal()?;
+    db.execute(&insert, &COLS.map(|(name, _)| row[name].clone()))?;
+
+    let sql = format!("SELECT {} FROM t", expr.display(Dialect::Sqlite));
+    let rows = db.query_sql(&sql, &[])?;
+    Ok(rows[0].values()[0].clone())
+}
+
+/// Whether both values are the same, given that SQLite stores booleans as integers.
+fn same(kind: Kind, interpreted: &Value, sqlite: &Value) -> bool {
+    let sqlite = match (kind, sqlite) {
+        (Kind::Bool, Value::Int(i @ (0 | 1))) => Value::Bool(*i == 1),
+        (_, value) => value.clone(),
+    };
+    match (interpreted, &sqlite) {
+        (Value::Null, Value::Null) => true,
+        (Value::Bool(_), Value::Bool(_))
+        | (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_))
+        | (Value::Text(_), Value::Text(_)) => {
+            compare(interpreted, &sqlite) == Ok(Some(std::cmp::Ordering::Equal))
+        }
+        _ => false,
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn interpreter_matches_sqlite(node in node(), row in row()) {
+        let expr = node.build();
+        let sql = expr.display(Dialect::Sqlite);
+        let interpreted = eval(expr.as_ref(), &row);
+        let sqlite = sqlite_eval(expr.as_ref(), &row);
+        // both failing agrees too, e.g. on `ABS` of the smallest integer
+        prop_assert!(
+            matches!((&interpreted, &sqlite), (Ok(i), Ok(s)) if same(node.kind(), i, s))
+                || (interpreted.is_err() && sqlite.is_err()),
+            "`{}` evaluates into {:?} in memory but into {:?} with SQLite",
+            sql,
+            interpreted,
+            sqlite
+        );
+    }
+}
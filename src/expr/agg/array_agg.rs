@@ -0,0 +1,69 @@
+use super::{AggOptions, Aggregate};
+use crate::expr::prelude::*;
+use crate::expr::window::WindowFunction;
+
+/// Values collected into an array.
+///
+/// Arrays have no type of their own yet, hence the result belongs to no family.
+/// MySQL and SQLite collect the values into a JSON array instead, and MySQL ignores any
+/// ordering.
+pub struct ArrayAgg {
+    inner: Box<dyn Expression>, // Common
+    options: AggOptions,
+}
+
+impl ArrayAgg {
+    pub fn new(inner: Box<dyn Expression>) -> Self {
+        Self {
+            inner,
+            options: AggOptions::default(),
+        }
+    }
+}
+
+impl Client for ArrayAgg {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        let mut children = vec![(self.inner.as_ref() as _, self.inner.eval_type())];
+        children.extend(self.options.children());
+        children
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Vec::new()
+    }
+}
+impl Checkable for ArrayAgg {}
+impl Expression for ArrayAgg {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Any
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let inner = Some(self.inner.as_ref());
+        match dialect {
+            Dialect::Postgres => self.options.call("ARRAY_AGG", inner, &[], "", dialect),
+            Dialect::MySql => self.options.call("JSON_ARRAYAGG", inner, &[], "", dialect),
+            Dialect::Sqlite => self
+                .options
+                .call("json_group_array", inner, &[], "", dialect),
+        }
+    }
+
+    // aggregates over no rows are `NULL`
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::Nullable
+    }
+}
+impl Common for ArrayAgg {}
+impl Aggregate for ArrayAgg {
+    fn options_mut(&mut self) -> &mut AggOptions {
+        &mut self.options
+    }
+}
+impl WindowFunction for ArrayAgg {}
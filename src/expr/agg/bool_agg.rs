@@ -0,0 +1,74 @@
+use super::{AggOptions, Aggregate};
+use crate::expr::prelude::*;
+use crate::expr::window::WindowFunction;
+
+/// Defines a boolean aggregate of a boolean expression.
+///
+/// Arguments are ($struct, $function, $fallback)
+/// where `$fallback` is used by the dialects that store booleans as integers.
+macro_rules! impl_bool_agg {
+    ($struct:ident, $function:expr, $fallback:expr) => {
+        pub struct $struct {
+            inner: Box<dyn Expression>, // Boolean
+            options: AggOptions,
+        }
+
+        impl $struct {
+            pub fn new(inner: Box<dyn Expression>) -> Self {
+                Self {
+                    inner,
+                    options: AggOptions::default(),
+                }
+            }
+        }
+
+        impl Client for $struct {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                let mut children = vec![(self.inner.as_ref() as _, ExprType::Bool)];
+                children.extend(self.options.children());
+                children
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Signal::ctx_mismatch(ctx, ExprType::Bool)
+            }
+        }
+        impl Checkable for $struct {}
+        impl Expression for $struct {
+            fn eval_type(&self) -> ExprType {
+                ExprType::Bool
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                let func = match dialect {
+                    Dialect::Postgres => $function,
+                    Dialect::MySql | Dialect::Sqlite => $fallback,
+                };
+                self.options
+                    .call(func, Some(self.inner.as_ref()), &[], "", dialect)
+            }
+
+            // aggregates over no rows are `NULL`
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                Nullability::Nullable
+            }
+        }
+        impl Common for $struct {}
+        impl Boolean for $struct {}
+        impl Aggregate for $struct {
+            fn options_mut(&mut self) -> &mut AggOptions {
+                &mut self.options
+            }
+        }
+        impl WindowFunction for $struct {}
+    };
+}
+
+impl_bool_agg!(BoolAnd, "BOOL_AND", "MIN");
+impl_bool_agg!(BoolOr, "BOOL_OR", "MAX");
@@ -0,0 +1,63 @@
+use super::{AggOptions, Aggregate};
+use crate::expr::prelude::*;
+use crate::expr::window::WindowFunction;
+
+/// The number of rows, or of non-`NULL` values of an expression.
+pub struct Count {
+    inner: Option<Box<dyn Expression>>, // Common, `None` for `COUNT(*)`
+    options: AggOptions,
+}
+
+impl Count {
+    pub fn new(inner: Option<Box<dyn Expression>>) -> Self {
+        Self {
+            inner,
+            options: AggOptions::default(),
+        }
+    }
+}
+
+impl Client for Count {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        let mut children: Vec<_> = self
+            .inner
+            .iter()
+            .map(|inner| (inner.as_ref() as _, ExprType::Any))
+            .collect();
+        children.extend(self.options.children());
+        children
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for Count {}
+impl Expression for Count {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        self.options
+            .call("COUNT", self.inner.as_deref(), &[], "", dialect)
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+}
+impl Common for Count {}
+impl Numeric for Count {}
+impl Aggregate for Count {
+    fn options_mut(&mut self) -> &mut AggOptions {
+        &mut self.options
+    }
+}
+impl WindowFunction for Count {}
@@ -0,0 +1,82 @@
+use std::marker::PhantomData;
+
+use super::{AggOptions, Aggregate};
+use crate::expr::prelude::*;
+use crate::expr::window::WindowFunction;
+
+/// Defines an aggregate that returns one of the values of its expression.
+///
+/// The expression's type is kept so that the aggregate belongs to the same families.
+///
+/// Arguments are ($struct, $function)
+macro_rules! impl_min_max {
+    ($struct:ident, $function:expr) => {
+        pub struct $struct<T> {
+            inner: Box<dyn Expression>, // T
+            options: AggOptions,
+            family: PhantomData<T>,
+        }
+
+        impl<T: Common> $struct<T> {
+            pub fn new(inner: Box<dyn Expression>) -> Self {
+                Self {
+                    inner,
+                    options: AggOptions::default(),
+                    family: PhantomData,
+                }
+            }
+        }
+
+        impl<T: Common> Client for $struct<T> {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                let ctx = match ctx {
+                    ExprType::Any => self.inner.eval_type(),
+                    ctx => ctx,
+                };
+                let mut children = vec![(self.inner.as_ref() as _, ctx)];
+                children.extend(self.options.children());
+                children
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Vec::new()
+            }
+        }
+        impl<T: Common> Checkable for $struct<T> {}
+        impl<T: Common> Expression for $struct<T> {
+            fn eval_type(&self) -> ExprType {
+                self.inner.eval_type()
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                self.options
+                    .call($function, Some(self.inner.as_ref()), &[], "", dialect)
+            }
+
+            // aggregates over no rows are `NULL`
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                Nullability::Nullable
+            }
+        }
+        impl<T: Common> Common for $struct<T> {}
+        impl<T: Boolean> Boolean for $struct<T> {}
+        impl<T: Numeric> Numeric for $struct<T> {}
+        impl<T: Textual> Textual for $struct<T> {}
+        impl<T: Temporal> Temporal for $struct<T> {}
+        impl<T: Common> Aggregate for $struct<T> {
+            fn options_mut(&mut self) -> &mut AggOptions {
+                &mut self.options
+            }
+        }
+        impl<T: Common> WindowFunction for $struct<T> {}
+    };
+}
+
+impl_min_max!(Min, "MIN");
+impl_min_max!(Max, "MAX");
@@ -0,0 +1,329 @@
+//! Aggregate functions, which combine the values of many rows into one
+//!
+//! Aggregates are also [`WindowFunction`]s, computing running values when used with
+//! [`WindowFunction::over`].
+
+use crate::expr::prelude::*;
+use crate::expr::render;
+use crate::expr::window::WindowFunction;
+use crate::reader::Order;
+
+pub mod count;
+#[doc(inline)]
+pub use count::Count;
+
+pub mod sum;
+#[doc(inline)]
+pub use sum::{Avg, Sum};
+
+pub mod min_max;
+#[doc(inline)]
+pub use min_max::{Max, Min};
+
+pub mod bool_agg;
+#[doc(inline)]
+pub use bool_agg::{BoolAnd, BoolOr};
+
+pub mod string_agg;
+#[doc(inline)]
+pub use string_agg::StringAgg;
+
+pub mod array_agg;
+#[doc(inline)]
+pub use array_agg::ArrayAgg;
+
+pub mod percentile;
+#[doc(inline)]
+pub use percentile::PercentileCont;
+
+/// Modifiers shared by all aggregates.
+#[derive(Default)]
+pub struct AggOptions {
+    distinct: bool,
+    filter: Option<Box<dyn Expression>>, // Boolean
+    order_by: Vec<(Box<dyn Expression>, Order)>,
+}
+
+impl AggOptions {
+    /// The expressions within the modifiers, along with their contexts.
+    fn children(&self) -> Vec<(&dyn Client<Ctx = ExprType, Msg = Message>, ExprType)> {
+        let filter = self
+            .filter
+            .iter()
+            .map(|f| (f.as_ref() as _, ExprType::Bool));
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|(e, _)| (e.as_ref() as _, ExprType::Any));
+        filter.chain(order_by).collect()
+    }
+
+    /// Displays the call of an aggregate with the modifiers applied.
+    ///
+    /// `arg` is the aggregated expression, `None` for `*`. Any further arguments are listed in
+    /// `rest`, and `suffix` is placed after the `ORDER BY` within the parentheses.
+    fn call(
+        &self,
+        func: &str,
+        arg: Option<&dyn Expression>,
+        rest: &[String],
+        suffix: &str,
+        dialect: Dialect,
+    ) -> String {
+        if arg.is_none() && self.distinct {
+            render::report(format!("{func}(*) can't be DISTINCT"));
+        }
+
+        // MySQL has no `FILTER`, but aggregates skip the `NULL`s of a `CASE` without `ELSE`
+        let emulate_filter = dialect == Dialect::MySql;
+        let filter = self.filter.as_ref().map(|f| f.display(dialect));
+
+        let arg = match (arg, &filter) {
+            (Some(arg), Some(filter)) if emulate_filter => {
+                format!("CASE WHEN {filter} THEN {} END", arg.display(dialect))
+            }
+            (None, Some(filter)) if emulate_filter => format!("CASE WHEN {filter} THEN 1 END"),
+            (Some(arg), _) => arg.display(dialect),
+            (None, _) => "*".to_string(),
+        };
+
+        let mut args = vec![arg];
+        args.extend_from_slice(rest);
+        let mut inner = args.join(", ");
+        if self.distinct {
+            inner = format!("DISTINCT {inner}");
+        }
+        if !self.order_by.is_empty() {
+            inner += &format!(" ORDER BY {}", display_order(&self.order_by, dialect));
+        }
+        inner += suffix;
+
+        match filter {
+            Some(filter) if !emulate_filter => format!("{func}({inner}) FILTER (WHERE {filter})"),
+            _ => format!("{func}({inner})"),
+        }
+    }
+}
+
+/// Displays the terms of an `ORDER BY`.
+fn display_order(terms: &[(Box<dyn Expression>, Order)], dialect: Dialect) -> String {
+    terms
+        .iter()
+        .map(|(e, order)| match order {
+            Order::Asc => e.display(dialect),
+            Order::Desc => format!("{} DESC", e.display(dialect)),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Expressions that aggregate many rows.
+pub trait Aggregate: Expression {
+    fn options_mut(&mut self) -> &mut AggOptions;
+}
+
+impl<T: Aggregate> CommonExpr<T> {
+    /// Only aggregates the distinct values.
+    pub fn distinct(mut self) -> Self {
+        self.0.options_mut().distinct = true;
+        self
+    }
+
+    /// Only aggregates the rows for which the condition holds, `FILTER (WHERE cond)`.
+    pub fn filter<B: Boolean + 'static>(mut self, cond: CommonExpr<B>) -> Self {
+        self.0.options_mut().filter = Some(Box::new(cond));
+        self
+    }
+
+    /// Aggregates the values in the order of the given expression,
+    /// which matters for aggregates such as [`string_agg`].
+    pub fn order_by(mut self, expr: impl Common + 'static) -> Self {
+        self.0
+            .options_mut()
+            .order_by
+            .push((Box::new(expr), Order::Asc));
+        self
+    }
+
+    pub fn order_by_desc(mut self, expr: impl Common + 'static) -> Self {
+        self.0
+            .options_mut()
+            .order_by
+            .push((Box::new(expr), Order::Desc));
+        self
+    }
+}
+
+/// Counts the rows where the expression is not `NULL`.
+pub fn count<T: Common + 'static>(expr: T) -> CommonExpr<Count> {
+    CommonExpr(Count::new(Some(Box::new(expr))))
+}
+
+/// Counts all rows, `COUNT(*)`.
+pub fn count_all() -> CommonExpr<Count> {
+    CommonExpr(Count::new(None))
+}
+
+pub fn sum<T: Numeric + 'static>(expr: T) -> CommonExpr<Sum> {
+    CommonExpr(Sum::new(Box::new(expr)))
+}
+
+pub fn avg<T: Numeric + 'static>(expr: T) -> CommonExpr<Avg> {
+    CommonExpr(Avg::new(Box::new(expr)))
+}
+
+/// The smallest value, of the same family as the expression.
+pub fn min<T: Common + 'static>(expr: T) -> CommonExpr<Min<T>> {
+    CommonExpr(Min::new(Box::new(expr)))
+}
+
+/// The largest value, of the same family as the expression.
+pub fn max<T: Common + 'static>(expr: T) -> CommonExpr<Max<T>> {
+    CommonExpr(Max::new(Box::new(expr)))
+}
+
+/// Whether the condition holds for every row.
+pub fn bool_and<T: Boolean + 'static>(expr: T) -> CommonExpr<BoolAnd> {
+    CommonExpr(BoolAnd::new(Box::new(expr)))
+}
+
+/// Whether the condition holds for any row.
+pub fn bool_or<T: Boolean + 'static>(expr: T) -> CommonExpr<BoolOr> {
+    CommonExpr(BoolOr::new(Box::new(expr)))
+}
+
+/// The values joined into a single text by the separator.
+pub fn string_agg<T: Textual + 'static>(expr: T, separator: &str) -> CommonExpr<StringAgg> {
+    CommonExpr(StringAgg::new(Box::new(expr), separator.to_string()))
+}
+
+/// The values collected into an array.
+pub fn array_agg<T: Common + 'static>(expr: T) -> CommonExpr<ArrayAgg> {
+    CommonExpr(ArrayAgg::new(Box::new(expr)))
+}
+
+/// The value at the given fraction of the ordered values, interpolating between the two
+/// closest values, `PERCENTILE_CONT(fraction) WITHIN GROUP (ORDER BY expr)`.
+///
+/// Statements with fractions outside of 0 to 1 can't be rendered.
+pub fn percentile_cont<T: Numeric + 'static>(fraction: f64, expr: T) -> CommonExpr<PercentileCont> {
+    CommonExpr(PercentileCont::new(fraction, Box::new(expr)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+    use crate::expr::temporal::TimeUnit;
+
+    #[test]
+    fn display() {
+        assert_eq!(count_all().display(Dialect::Postgres), "COUNT(*)");
+        assert_eq!(
+            (sum(col("price")) / count(col("id"))).display(Dialect::Sqlite),
+            "(SUM(price) / COUNT(id))"
+        );
+        // `Min<T>` keeps the family of its argument
+        let first = Temporal::date_trunc(min(col("created_at")), TimeUnit::Day);
+        assert_eq!(
+            first.display(Dialect::Postgres),
+            "DATE_TRUNC('day', MIN(created_at))"
+        );
+    }
+
+    #[test]
+    fn modifiers() {
+        let paid = count_all().filter(Textual::eq(col("status"), "paid"));
+        assert_eq!(
+            paid.display(Dialect::Postgres),
+            "COUNT(*) FILTER (WHERE status = 'paid')"
+        );
+        assert_eq!(
+            paid.display(Dialect::MySql),
+            "COUNT(CASE WHEN status = 'paid' THEN 1 END)"
+        );
+
+        let buyers = count(col("buyer"))
+            .distinct()
+            .filter(Numeric::gt(col("total"), 0));
+        assert_eq!(
+            buyers.display(Dialect::Sqlite),
+            "COUNT(DISTINCT buyer) FILTER (WHERE total > 0)"
+        );
+        assert_eq!(
+            buyers.display(Dialect::MySql),
+            "COUNT(DISTINCT CASE WHEN total > 0 THEN buyer END)"
+        );
+
+        let tags = string_agg(col("tag"), ",").order_by(col("tag"));
+        assert_eq!(
+            tags.display(Dialect::Postgres),
+            "STRING_AGG(tag, ',' ORDER BY tag)"
+        );
+        assert_eq!(
+            tags.display(Dialect::MySql),
+            "GROUP_CONCAT(tag ORDER BY tag SEPARATOR ',')"
+        );
+
+        let median = percentile_cont(0.5, col("latency"));
+        assert_eq!(
+            median.display(Dialect::Postgres),
+            "PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY latency)"
+        );
+
+        let all_done = bool_and(col("done"));
+        assert_eq!(all_done.display(Dialect::Postgres), "BOOL_AND(done)");
+        assert_eq!(all_done.display(Dialect::Sqlite), "MIN(done)");
+    }
+
+    #[test]
+    fn unsupported() {
+        let render = |expr: &dyn Expression, dialect| {
+            crate::expr::render::check(dialect, || Ok(expr.display(dialect)))
+        };
+
+        let tags = string_agg(col("tag"), ",").distinct();
+        assert_eq!(
+            render(&tags, Dialect::Sqlite),
+            Ok("GROUP_CONCAT(DISTINCT tag)".to_string())
+        );
+        let tags = string_agg(col("tag"), "; ").distinct();
+        assert_eq!(
+            render(&tags, Dialect::Sqlite),
+            Err("SQLite's DISTINCT GROUP_CONCAT can't take the separator '; '".to_string())
+        );
+        assert!(render(&tags, Dialect::Postgres).is_ok());
+
+        let median = percentile_cont(0.5, col("latency"));
+        assert!(render(&median, Dialect::Postgres).is_ok());
+        assert_eq!(
+            render(&median, Dialect::MySql),
+            Err("percentiles are not supported by MySql".to_string())
+        );
+
+        for (median, error) in [
+            (
+                percentile_cont(f64::NAN, col("latency")),
+                "percentiles are fractions between 0 and 1, not NaN",
+            ),
+            (
+                percentile_cont(0.5, col("latency")).distinct(),
+                "percentiles order by their argument, hence can't be DISTINCT or take an ORDER BY",
+            ),
+            (
+                percentile_cont(0.5, col("latency")).order_by(col("id")),
+                "percentiles order by their argument, hence can't be DISTINCT or take an ORDER BY",
+            ),
+        ] {
+            for dialect in [Dialect::Postgres, Dialect::Sqlite] {
+                assert_eq!(render(&median, dialect), Err(error.to_string()));
+            }
+        }
+        let rows = count_all().distinct();
+        assert_eq!(
+            render(&rows, Dialect::Postgres),
+            Err("COUNT(*) can't be DISTINCT".to_string())
+        );
+        assert!(render(&count(col("id")).distinct(), Dialect::Postgres).is_ok());
+    }
+}
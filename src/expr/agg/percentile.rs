@@ -0,0 +1,99 @@
+use super::{AggOptions, Aggregate};
+use crate::expr::prelude::*;
+use crate::expr::render;
+
+/// The continuous percentile of the ordered values of an expression.
+///
+/// Ordered-set aggregates are not window functions, and only support `FILTER`.
+/// MySQL has no percentile functions, and SQLite only provides `percentile_cont` when it is
+/// compiled with `SQLITE_ENABLE_PERCENTILE`.
+pub struct PercentileCont {
+    fraction: f64,
+    inner: Box<dyn Expression>, // Numeric
+    options: AggOptions,
+}
+
+impl PercentileCont {
+    pub fn new(fraction: f64, inner: Box<dyn Expression>) -> Self {
+        Self {
+            fraction,
+            inner,
+            options: AggOptions::default(),
+        }
+    }
+}
+
+impl Client for PercentileCont {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        let mut children = vec![(self.inner.as_ref() as _, ExprType::Num)];
+        children.extend(self.options.children());
+        children
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for PercentileCont {}
+impl Expression for PercentileCont {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        if !(0.0..=1.0).contains(&self.fraction) {
+            render::report(format!(
+                "percentiles are fractions between 0 and 1, not {}",
+                self.fraction
+            ));
+        }
+        if self.options.distinct || !self.options.order_by.is_empty() {
+            render::report(
+                "percentiles order by their argument, hence can't be DISTINCT or take an ORDER BY"
+                    .to_string(),
+            );
+        }
+
+        let fraction = self.fraction.to_string();
+        match dialect {
+            Dialect::Postgres | Dialect::MySql => {
+                if dialect == Dialect::MySql {
+                    render::report("percentiles are not supported by MySql".to_string());
+                }
+                let mut out = format!(
+                    "PERCENTILE_CONT({fraction}) WITHIN GROUP (ORDER BY {})",
+                    self.inner.display(dialect)
+                );
+                if let Some(filter) = &self.options.filter {
+                    out += &format!(" FILTER (WHERE {})", filter.display(dialect));
+                }
+                out
+            }
+            Dialect::Sqlite => self.options.call(
+                "percentile_cont",
+                Some(self.inner.as_ref()),
+                &[fraction],
+                "",
+                dialect,
+            ),
+        }
+    }
+
+    // aggregates over no rows are `NULL`
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::Nullable
+    }
+}
+impl Common for PercentileCont {}
+impl Numeric for PercentileCont {}
+impl Aggregate for PercentileCont {
+    fn options_mut(&mut self) -> &mut AggOptions {
+        &mut self.options
+    }
+}
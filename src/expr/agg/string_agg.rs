@@ -0,0 +1,90 @@
+use super::{AggOptions, Aggregate};
+use crate::expr::prelude::*;
+use crate::expr::render;
+use crate::expr::text::quote;
+use crate::expr::window::WindowFunction;
+
+/// Textual values joined by a separator.
+pub struct StringAgg {
+    inner: Box<dyn Expression>, // Textual
+    separator: String,
+    options: AggOptions,
+}
+
+impl StringAgg {
+    pub fn new(inner: Box<dyn Expression>, separator: String) -> Self {
+        Self {
+            inner,
+            separator,
+            options: AggOptions::default(),
+        }
+    }
+}
+
+impl Client for StringAgg {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        let mut children = vec![(self.inner.as_ref() as _, ExprType::Text)];
+        children.extend(self.options.children());
+        children
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for StringAgg {}
+impl Expression for StringAgg {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let separator = quote(&self.separator, dialect);
+        let inner = Some(self.inner.as_ref());
+        match dialect {
+            Dialect::Postgres => self
+                .options
+                .call("STRING_AGG", inner, &[separator], "", dialect),
+            // the separator follows the `ORDER BY`
+            Dialect::MySql => self.options.call(
+                "GROUP_CONCAT",
+                inner,
+                &[],
+                &format!(" SEPARATOR {separator}"),
+                dialect,
+            ),
+            // `DISTINCT` aggregates only take a single argument in SQLite, which leaves the
+            // default separator
+            Dialect::Sqlite if self.options.distinct => {
+                if self.separator != "," {
+                    render::report(format!(
+                        "SQLite's DISTINCT GROUP_CONCAT can't take the separator {separator}"
+                    ));
+                }
+                self.options.call("GROUP_CONCAT", inner, &[], "", dialect)
+            }
+            Dialect::Sqlite => self
+                .options
+                .call("GROUP_CONCAT", inner, &[separator], "", dialect),
+        }
+    }
+
+    // aggregates over no rows are `NULL`
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::Nullable
+    }
+}
+impl Common for StringAgg {}
+impl Textual for StringAgg {}
+impl Aggregate for StringAgg {
+    fn options_mut(&mut self) -> &mut AggOptions {
+        &mut self.options
+    }
+}
+impl WindowFunction for StringAgg {}
@@ -0,0 +1,70 @@
+use super::{AggOptions, Aggregate};
+use crate::expr::prelude::*;
+use crate::expr::window::WindowFunction;
+
+/// Defines a numeric aggregate of a numeric expression.
+///
+/// Arguments are ($struct, $function)
+macro_rules! impl_numeric_agg {
+    ($struct:ident, $function:expr) => {
+        pub struct $struct {
+            inner: Box<dyn Expression>, // Numeric
+            options: AggOptions,
+        }
+
+        impl $struct {
+            pub fn new(inner: Box<dyn Expression>) -> Self {
+                Self {
+                    inner,
+                    options: AggOptions::default(),
+                }
+            }
+        }
+
+        impl Client for $struct {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                let mut children = vec![(self.inner.as_ref() as _, ExprType::Num)];
+                children.extend(self.options.children());
+                children
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Signal::ctx_mismatch(ctx, ExprType::Num)
+            }
+        }
+        impl Checkable for $struct {}
+        impl Expression for $struct {
+            fn eval_type(&self) -> ExprType {
+                ExprType::Num
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                self.options
+                    .call($function, Some(self.inner.as_ref()), &[], "", dialect)
+            }
+
+            // aggregates over no rows are `NULL`
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                Nullability::Nullable
+            }
+        }
+        impl Common for $struct {}
+        impl Numeric for $struct {}
+        impl Aggregate for $struct {
+            fn options_mut(&mut self) -> &mut AggOptions {
+                &mut self.options
+            }
+        }
+        impl WindowFunction for $struct {}
+    };
+}
+
+impl_numeric_agg!(Sum, "SUM");
+// the average of integers is a decimal in PostgreSQL and MySQL, and a float in SQLite
+impl_numeric_agg!(Avg, "AVG");
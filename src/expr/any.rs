@@ -1,6 +1,9 @@
+use std::cmp::Ordering;
+
 use super::prelude::*;
+use crate::interpreter::{compare, truth};
 
-pub trait Anything: Boolean + Numeric + Textual {}
+pub trait Anything: Boolean + Numeric + Textual + Temporal {}
 impl<T: Anything> Anything for CommonExpr<T> {}
 
 mod col {
@@ -38,15 +41,234 @@ mod col {
         fn display(&self, dialect: Dialect) -> String {
             self.name.to_string()
         }
+
+        fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+            cols(&self.name)
+        }
+
+        fn name(&self) -> Option<&str> {
+            Some(&self.name)
+        }
+
+        fn source(&self) -> Option<&str> {
+            Some(&self.name)
+        }
+
+        fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+            cols(&self.name).ok_or_else(|| format!("the row has no column `{}`", self.name))
+        }
     }
     impl Common for Col {}
     impl Boolean for Col {}
     impl Numeric for Col {}
     impl Textual for Col {}
+    impl Temporal for Col {}
     impl Anything for Col {}
 }
 pub use col::*;
 
+mod typed_col {
+    use std::marker::PhantomData;
+
+    use super::*;
+    use crate::expr::cast::{AsBool, AsDate, AsNum, AsText, AsTime, AsTimestamp, CastTarget};
+
+    /// A column with a type known at compile time, usually generated by `#[derive(Table)]`.
+    ///
+    /// It only implements the family of its type, the target markers of
+    /// [`crate::expr::cast::Cast`] are reused to name the type.
+    pub struct TypedCol<T> {
+        name: &'static str,
+        kind: PhantomData<T>,
+    }
+
+    impl<T> Clone for TypedCol<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl<T> Copy for TypedCol<T> {}
+
+    pub const fn typed_col<T: CastTarget>(name: &'static str) -> CommonExpr<TypedCol<T>> {
+        CommonExpr(TypedCol {
+            name,
+            kind: PhantomData,
+        })
+    }
+
+    impl<T: CastTarget> Client for TypedCol<T> {
+        type Ctx = ExprType;
+        type Msg = Message;
+
+        fn children(
+            &self,
+            ctx: Self::Ctx,
+        ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+            Vec::new()
+        }
+
+        fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+            let mut messages =
+                vec![Condition::ColExistsAndType(self.name.to_string(), T::KIND).into()];
+            messages.extend(Signal::ctx_mismatch(ctx, T::KIND));
+            messages
+        }
+    }
+    impl<T: CastTarget> Checkable for TypedCol<T> {}
+    impl<T: CastTarget> Expression for TypedCol<T> {
+        fn eval_type(&self) -> ExprType {
+            T::KIND
+        }
+
+        fn display(&self, dialect: Dialect) -> String {
+            self.name.to_string()
+        }
+
+        fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+            cols(self.name)
+        }
+
+        fn name(&self) -> Option<&str> {
+            Some(self.name)
+        }
+
+        fn source(&self) -> Option<&str> {
+            Some(self.name)
+        }
+
+        fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+            cols(self.name).ok_or_else(|| format!("the row has no column `{}`", self.name))
+        }
+    }
+    impl<T: CastTarget> Common for TypedCol<T> {}
+    impl Boolean for TypedCol<AsBool> {}
+    impl Numeric for TypedCol<AsNum> {}
+    impl Textual for TypedCol<AsText> {}
+    impl Temporal for TypedCol<AsDate> {}
+    impl Temporal for TypedCol<AsTime> {}
+    impl Temporal for TypedCol<AsTimestamp> {}
+}
+pub use typed_col::*;
+
+/// Wraps a literal value so it can be used wherever an expression is expected, such as the first
+/// item of a `multi!`.
+pub fn lit<T: Common>(value: T) -> CommonExpr<T> {
+    CommonExpr(value)
+}
+
+mod null {
+    use super::*;
+
+    /// The `NULL` literal.
+    #[derive(Debug, Copy, Clone)]
+    pub struct Null;
+
+    /// The `NULL` literal.
+    pub const NULL: Null = Null;
+
+    impl Client for Null {
+        type Ctx = ExprType;
+        type Msg = Message;
+
+        fn children(
+            &self,
+            ctx: Self::Ctx,
+        ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+            Vec::new()
+        }
+
+        fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+            Vec::new()
+        }
+    }
+    impl Checkable for Null {}
+    impl Expression for Null {
+        fn eval_type(&self) -> ExprType {
+            ExprType::Any
+        }
+
+        fn display(&self, dialect: Dialect) -> String {
+            "NULL".to_string()
+        }
+
+        fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+            Nullability::Null
+        }
+
+        fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+            Ok(Value::Null)
+        }
+    }
+    impl Common for Null {}
+    impl Boolean for Null {}
+    impl Numeric for Null {}
+    impl Textual for Null {}
+    impl Temporal for Null {}
+    impl Anything for Null {}
+}
+pub use null::*;
+
+mod param {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::expr::render;
+
+    /// A parameter that is bound when the statement is executed.
+    #[derive(Debug, Copy, Clone)]
+    pub struct Param {
+        index: NonZeroUsize,
+    }
+
+    /// The parameter at the given index, starting at 1.
+    ///
+    /// MySQL only has positional `?` parameters, hence statements whose parameters don't appear
+    /// in order from 1 can't be rendered for it.
+    pub fn param(index: NonZeroUsize) -> CommonExpr<Param> {
+        CommonExpr(Param { index })
+    }
+
+    impl Client for Param {
+        type Ctx = ExprType;
+        type Msg = Message;
+
+        fn children(
+            &self,
+            ctx: Self::Ctx,
+        ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+            Vec::new()
+        }
+
+        fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+            Vec::new()
+        }
+    }
+    impl Checkable for Param {}
+    impl Expression for Param {
+        fn eval_type(&self) -> ExprType {
+            ExprType::Any
+        }
+
+        fn display(&self, dialect: Dialect) -> String {
+            match dialect {
+                Dialect::Postgres => format!("${}", self.index),
+                Dialect::Sqlite => format!("?{}", self.index),
+                Dialect::MySql => {
+                    render::positional_param(self.index.get());
+                    "?".to_string()
+                }
+            }
+        }
+    }
+    impl Common for Param {}
+    impl Boolean for Param {}
+    impl Numeric for Param {}
+    impl Textual for Param {}
+    impl Temporal for Param {}
+    impl Anything for Param {}
+}
+pub use param::*;
+
 mod case_branch {
     use super::*;
 
@@ -59,6 +281,19 @@ mod case_branch {
         pub fn new(pattern: Option<Box<dyn Expression>>, result: Box<dyn Expression>) -> Self {
             Self { pattern, result }
         }
+
+        /// Whether this is the final `ELSE` branch.
+        pub fn is_else(&self) -> bool {
+            self.pattern.is_none()
+        }
+
+        /// Whether the branch is taken for the row, which an `ELSE` branch always is.
+        pub fn matches(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<bool, String> {
+            match &self.pattern {
+                Some(pattern) => Ok(truth(&pattern.eval(cols)?)? == Some(true)),
+                None => Ok(true),
+            }
+        }
     }
 
     impl Client for CaseBranch {
@@ -70,8 +305,8 @@ mod case_branch {
             ctx: Self::Ctx,
         ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
             let mut out = Vec::new();
-            if self.pattern.is_some() {
-                out.push((self.pattern.as_deref().unwrap() as _, ExprType::Bool))
+            if let Some(pattern) = &self.pattern {
+                out.push((pattern.as_ref() as _, ExprType::Bool))
             }
             out.push((self.result.as_ref() as _, ctx));
             out
@@ -97,11 +332,20 @@ mod case_branch {
                 None => format!("ELSE {}", self.result.display(dialect)),
             }
         }
+
+        fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+            self.result.nullability(cols)
+        }
+
+        fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+            self.result.eval(cols)
+        }
     }
     impl Common for CaseBranch {}
     impl Boolean for CaseBranch {}
     impl Numeric for CaseBranch {}
     impl Textual for CaseBranch {}
+    impl Temporal for CaseBranch {}
     impl Anything for CaseBranch {}
 }
 pub use case_branch::*;
@@ -122,6 +366,14 @@ mod case {
             match (expr_t1, expr_t2) {
                 (ExprType::Any, ExprType::Any) => Ok(ExprType::Any),
                 (ExprType::Any, e) | (e, ExprType::Any) => Ok(e),
+                (
+                    ExprType::Temporal,
+                    e @ (ExprType::Date | ExprType::Time | ExprType::Timestamp),
+                )
+                | (
+                    e @ (ExprType::Date | ExprType::Time | ExprType::Timestamp),
+                    ExprType::Temporal,
+                ) => Ok(e),
                 (a, b) if a == b => Ok(a),
                 mismatch => Err(mismatch),
             }
@@ -160,8 +412,7 @@ mod case {
         fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
             // No standard messages to be sent, instead we use a custom `send_all` implementation
             // to ensure the required ordering of messages.
-            // The custom `send_all` implementation should never call this function.
-            unreachable!();
+            Vec::new()
         }
 
         fn send_all(&self, ctx: Self::Ctx, server: &mut dyn Server<Msg = Self::Msg>) {
@@ -191,13 +442,37 @@ mod case {
         }
 
         fn display(&self, dialect: Dialect) -> String {
-            todo!()
+            let branches: Vec<_> = self.branches.iter().map(|b| b.display(dialect)).collect();
+            format!("CASE {} END", branches.join(" "))
+        }
+
+        fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+            let results = self.branches.iter().map(|b| b.nullability(cols));
+            // without an `ELSE` branch unmatched rows evaluate into `NULL`
+            let unmatched = match self.branches.last() {
+                Some(b) if b.is_else() => None,
+                _ => Some(Nullability::Null),
+            };
+            results
+                .chain(unmatched)
+                .reduce(Nullability::either)
+                .unwrap_or(Nullability::Null)
+        }
+
+        fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+            for branch in &self.branches {
+                if branch.matches(cols)? {
+                    return branch.eval(cols);
+                }
+            }
+            Ok(Value::Null)
         }
     }
     impl Common for Case {}
     impl Boolean for Case {}
     impl Numeric for Case {}
     impl Textual for Case {}
+    impl Temporal for Case {}
     impl Anything for Case {}
 
     /// Build `CommonExpr<Case>` expressions using `match` semantics.
@@ -220,8 +495,190 @@ mod case {
 }
 pub use case::*;
 
+#[macro_use]
+mod coalesce {
+    use super::*;
+
+    /// The first non-null value out of a list of expressions.
+    pub struct Coalesce {
+        exprs: Vec<Box<dyn Expression>>, // Common
+    }
+
+    impl Coalesce {
+        pub fn new(exprs: Vec<Box<dyn Expression>>) -> Self {
+            Self { exprs }
+        }
+
+        /// Returns the unified type of all of the arguments.
+        pub(in super::super) fn validate(&self) -> Result<ExprType, (ExprType, ExprType)> {
+            self.exprs
+                .iter()
+                .map(|e| e.eval_type())
+                .try_fold(ExprType::Any, ExprType::try_fold)
+        }
+    }
+
+    impl Client for Coalesce {
+        type Ctx = ExprType;
+        type Msg = Message;
+
+        fn children(
+            &self,
+            ctx: Self::Ctx,
+        ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+            let kind = ExprType::try_fold(ctx, self.eval_type()).unwrap_or(ctx);
+            self.exprs.iter().map(|e| (e.as_ref() as _, kind)).collect()
+        }
+
+        fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+            Vec::new()
+        }
+
+        fn send_all(&self, ctx: Self::Ctx, server: &mut dyn Server<Msg = Self::Msg>) {
+            let kind = match self.validate() {
+                Err(mismatch) => {
+                    server.accept(Message::Sig(Signal::TypeMismatch(mismatch)));
+                    return;
+                }
+                Ok(kind) => ExprType::try_fold(ctx, kind),
+            };
+            match kind {
+                Err(mismatch) => server.accept(Message::Sig(Signal::TypeMismatch(mismatch))),
+                Ok(ExprType::Any) => {
+                    server.accept(Signal::StartLink.into());
+                    for (child, ctx) in self.children(ctx) {
+                        child.send_all(ctx, server);
+                    }
+                    server.accept(Signal::EndLink.into());
+                }
+                Ok(_) => {
+                    for (child, ctx) in self.children(ctx) {
+                        child.send_all(ctx, server);
+                    }
+                }
+            }
+        }
+    }
+    impl Checkable for Coalesce {}
+    impl Expression for Coalesce {
+        fn eval_type(&self) -> ExprType {
+            self.validate().unwrap_or(ExprType::Any)
+        }
+
+        fn display(&self, dialect: Dialect) -> String {
+            let exprs: Vec<_> = self.exprs.iter().map(|e| e.display(dialect)).collect();
+            format!("COALESCE({})", exprs.join(", "))
+        }
+
+        fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+            // any non-null argument ensures a non-null result
+            self.exprs
+                .iter()
+                .map(|e| e.nullability(cols))
+                .min()
+                .unwrap_or(Nullability::Null)
+        }
+
+        fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+            for expr in &self.exprs {
+                match expr.eval(cols)? {
+                    Value::Null => continue,
+                    value => return Ok(value),
+                }
+            }
+            Ok(Value::Null)
+        }
+    }
+    impl Common for Coalesce {}
+    impl Boolean for Coalesce {}
+    impl Numeric for Coalesce {}
+    impl Textual for Coalesce {}
+    impl Temporal for Coalesce {}
+    impl Anything for Coalesce {}
+
+    /// Build `CommonExpr<Coalesce>` expressions out of any number of arguments.
+    macro_rules! coalesce {
+    ($($e:expr),+ $(,)?) => {{
+        let arr: Vec<Box<dyn Expression>> = vec![$(Box::new($e),)+];
+        CommonExpr(Coalesce::new(arr))
+    }};
+}
+}
+pub use coalesce::*;
+
+mod nullif {
+    use super::*;
+
+    /// `NULL` if both expressions are equal, otherwise the first expression.
+    pub struct NullIf {
+        lhs: Box<dyn Expression>, // Common
+        rhs: Box<dyn Expression>, // Common
+    }
+
+    impl NullIf {
+        pub fn new(lhs: Box<dyn Expression>, rhs: Box<dyn Expression>) -> Self {
+            Self { lhs, rhs }
+        }
+    }
+
+    impl Client for NullIf {
+        type Ctx = ExprType;
+        type Msg = Message;
+
+        fn children(
+            &self,
+            ctx: Self::Ctx,
+        ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+            let kind = ExprType::try_fold(ctx, self.eval_type()).unwrap_or(ctx);
+            vec![(self.lhs.as_ref(), kind), (self.rhs.as_ref(), kind)]
+        }
+
+        fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+            match ExprType::try_fold(self.lhs.eval_type(), self.rhs.eval_type()) {
+                Err(mismatch) => vec![Signal::TypeMismatch(mismatch).into()],
+                Ok(_) => Vec::new(),
+            }
+        }
+    }
+    impl Checkable for NullIf {}
+    impl Expression for NullIf {
+        fn eval_type(&self) -> ExprType {
+            ExprType::try_fold(self.lhs.eval_type(), self.rhs.eval_type()).unwrap_or(ExprType::Any)
+        }
+
+        fn display(&self, dialect: Dialect) -> String {
+            format!(
+                "NULLIF({}, {})",
+                self.lhs.display(dialect),
+                self.rhs.display(dialect)
+            )
+        }
+
+        fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+            self.lhs.nullability(cols).max(Nullability::Nullable)
+        }
+
+        fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+            let lhs = self.lhs.eval(cols)?;
+            match compare(&lhs, &self.rhs.eval(cols)?)? {
+                Some(Ordering::Equal) => Ok(Value::Null),
+                _ => Ok(lhs),
+            }
+        }
+    }
+    impl Common for NullIf {}
+    impl Boolean for NullIf {}
+    impl Numeric for NullIf {}
+    impl Textual for NullIf {}
+    impl Temporal for NullIf {}
+    impl Anything for NullIf {}
+}
+pub use nullif::*;
+
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroUsize;
+
     use super::*;
 
     #[test]
@@ -251,6 +708,76 @@ mod tests {
         assert!(case.validate().is_err());
     }
 
+    #[test]
+    fn coalesce_type() {
+        assert_eq!(coalesce![col("a"), col("b")].eval_type(), ExprType::Any);
+        assert_eq!(coalesce![col("a"), 0, col("c")].eval_type(), ExprType::Num);
+        assert!(coalesce![col("a"), true, 0].validate().is_err());
+        assert_eq!(
+            col("a").coalesce(0).display(Dialect::Postgres),
+            "COALESCE(a, 0)"
+        );
+    }
+
+    #[test]
+    fn param_display() {
+        let expr = Numeric::eq(col("a"), param(NonZeroUsize::MIN));
+        assert_eq!(expr.display(Dialect::Postgres), "a = $1");
+        assert_eq!(expr.display(Dialect::Sqlite), "a = ?1");
+        assert_eq!(expr.display(Dialect::MySql), "a = ?");
+
+        // MySQL binds parameters by their position
+        let param = |i| param(NonZeroUsize::new(i).unwrap());
+        let render = |expr: &dyn Expression, dialect| {
+            crate::expr::render::check(dialect, || Ok(expr.display(dialect)))
+        };
+        let ordered = Numeric::eq(col("a"), param(1)) & Numeric::eq(col("b"), param(2));
+        assert_eq!(
+            render(&ordered, Dialect::MySql),
+            Ok("a = ? AND b = ?".to_string())
+        );
+        for (expr, order) in [
+            (
+                Numeric::eq(col("a"), param(2)) & Numeric::eq(col("b"), param(1)),
+                "2, 1",
+            ),
+            (
+                Numeric::eq(col("a"), param(1)) & Numeric::eq(col("b"), param(1)),
+                "1, 1",
+            ),
+        ] {
+            assert!(render(&expr, Dialect::Postgres).is_ok());
+            assert_eq!(
+                render(&expr, Dialect::MySql),
+                Err(format!(
+                    "MySql binds parameters by position, so they must appear in order from 1, \
+                     but appear as {order}"
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn case_eval() {
+        let x = |col: &str| (col == "x").then_some(Value::Int(7));
+        let size = case! {
+            Numeric::gt(col("x"), 10) => "big",
+            Numeric::gt(col("x"), 5) => "medium",
+        };
+        assert_eq!(size.eval(&x), Ok(Value::Text("medium".into())));
+
+        let size = case! {
+            Numeric::gt(col("x"), 10) => "big",
+        };
+        assert_eq!(size.eval(&x), Ok(Value::Null));
+
+        let size = case! {
+            Numeric::gt(col("x"), 10) => "big",
+            _ => NULL.coalesce("small"),
+        };
+        assert_eq!(size.eval(&x), Ok(Value::Text("small".into())));
+    }
+
     #[test]
     fn conds() {
         let case = case! {
@@ -258,8 +785,10 @@ mod tests {
             col("c") => col("d"),
         };
 
-        for msg in case.messages(ExprType::Any) {
-            println!("{msg:?}");
-        }
+        let messages = crate::expr::tests::record(&case, ExprType::Any)
+            .iter()
+            .map(|msg| format!("{msg:?}"))
+            .collect::<Vec<_>>();
+        crate::snapshot::assert_snapshot("case_conds", &messages.join("\n"));
     }
 }
@@ -1,9 +1,19 @@
+use std::cmp::Ordering;
+
 use crate::expr::prelude::*;
+use crate::interpreter::{and, compare, from_truth, or};
 
+/// `x BETWEEN lower AND upper`, the bounds being inclusive.
+/// Its negation is displayed as `NOT BETWEEN`.
 pub struct Between {
-    inner: Box<dyn Expression>, // Numeric
-    lower: Box<dyn Expression>, // Numeric
-    upper: Box<dyn Expression>, // Numeric
+    inner: Box<dyn Expression>,
+    lower: Box<dyn Expression>,
+    upper: Box<dyn Expression>,
+    // keep track of the inner expression types
+    kind: ExprType,
+    negated: bool,
+    // whether the bounds may be given in either order
+    symmetric: bool,
 }
 
 impl Between {
@@ -11,11 +21,47 @@ impl Between {
         inner: Box<dyn Expression>,
         lower: Box<dyn Expression>,
         upper: Box<dyn Expression>,
+        kind: ExprType,
     ) -> Self {
         Self {
             inner,
             lower,
             upper,
+            kind,
+            negated: false,
+            symmetric: false,
+        }
+    }
+
+    /// Displays the expression as `NOT BETWEEN`.
+    pub fn negated(mut self) -> Self {
+        self.negated = !self.negated;
+        self
+    }
+
+    /// Accepts the bounds in either order, `BETWEEN SYMMETRIC`.
+    pub fn symmetric(mut self) -> Self {
+        self.symmetric = true;
+        self
+    }
+
+    fn display_with(&self, dialect: Dialect, negated: bool) -> String {
+        let (x, lower, upper) = (
+            display_operand(self.inner.as_ref(), Precedence::Atom, dialect),
+            display_operand(self.lower.as_ref(), Precedence::Atom, dialect),
+            display_operand(self.upper.as_ref(), Precedence::Atom, dialect),
+        );
+        let not = if negated { "NOT " } else { "" };
+        match (self.symmetric, dialect) {
+            (false, _) => format!("{x} {not}BETWEEN {lower} AND {upper}"),
+            (true, Dialect::Postgres) => format!("{x} {not}BETWEEN SYMMETRIC {lower} AND {upper}"),
+            // the other dialects only support ordered bounds
+            (true, Dialect::MySql | Dialect::Sqlite) => {
+                let join = if negated { "AND" } else { "OR" };
+                format!(
+                    "({x} {not}BETWEEN {lower} AND {upper} {join} {x} {not}BETWEEN {upper} AND {lower})"
+                )
+            }
         }
     }
 }
@@ -29,14 +75,34 @@ impl Client for Between {
         ctx: Self::Ctx,
     ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
         vec![
-            (self.inner.as_ref(), ExprType::Num),
-            (self.lower.as_ref(), ExprType::Num),
-            (self.upper.as_ref(), ExprType::Num),
+            (self.inner.as_ref(), self.kind),
+            (self.lower.as_ref(), self.kind),
+            (self.upper.as_ref(), self.kind),
         ]
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        todo!()
+        super::cmp::null_comparison(&[
+            self.inner.as_ref(),
+            self.lower.as_ref(),
+            self.upper.as_ref(),
+        ])
+    }
+
+    fn send_all(&self, ctx: Self::Ctx, server: &mut dyn Server<Msg = Self::Msg>) {
+        let link = self.kind == ExprType::Any;
+        if link {
+            server.accept(Signal::StartLink.into());
+        }
+        for (child, ctx) in self.children(ctx) {
+            child.send_all(ctx, server);
+        }
+        if link {
+            server.accept(Signal::EndLink.into());
+        }
+        for msg in self.messages(ctx) {
+            server.accept(msg);
+        }
     }
 }
 impl Checkable for Between {}
@@ -46,13 +112,86 @@ impl Expression for Between {
     }
 
     fn display(&self, dialect: Dialect) -> String {
-        format!(
-            "{} BETWEEN {} AND {}",
-            self.inner.display(dialect),
-            self.lower.display(dialect),
-            self.upper.display(dialect)
-        )
+        self.display_with(dialect, self.negated)
+    }
+
+    fn display_negated(&self, dialect: Dialect) -> Option<String> {
+        Some(self.display_with(dialect, !self.negated))
+    }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::Cmp
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner
+            .nullability(cols)
+            .propagate(self.lower.nullability(cols))
+            .propagate(self.upper.nullability(cols))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let x = self.inner.eval(cols)?;
+        let (lower, upper) = (self.lower.eval(cols)?, self.upper.eval(cols)?);
+        let within = |lower: &Value, upper: &Value| -> Result<Option<bool>, String> {
+            let above = compare(&x, lower)?.map(Ordering::is_ge);
+            let below = compare(&x, upper)?.map(Ordering::is_le);
+            Ok(and(above, below))
+        };
+        let mut result = within(&lower, &upper)?;
+        if self.symmetric {
+            result = or(result, within(&upper, &lower)?);
+        }
+        Ok(from_truth(result.map(|b| b != self.negated)))
     }
 }
 impl Common for Between {}
 impl Boolean for Between {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+    use crate::expr::temporal::Date;
+
+    #[test]
+    fn display() {
+        let initials = Textual::between(col("name"), "A", "M");
+        assert_eq!(
+            initials.display(Dialect::Postgres),
+            "name BETWEEN 'A' AND 'M'"
+        );
+        assert_eq!(
+            (!initials).display(Dialect::Postgres),
+            "name NOT BETWEEN 'A' AND 'M'"
+        );
+
+        let before = Textual::lt(Textual::collate(col("name"), "C"), "b");
+        assert_eq!(
+            before.display(Dialect::Postgres),
+            "name COLLATE \"C\" < 'b'"
+        );
+
+        let outside = Numeric::not_between(col("age"), 18, 65);
+        assert_eq!(
+            outside.display(Dialect::Sqlite),
+            "age NOT BETWEEN 18 AND 65"
+        );
+        assert_eq!((!outside).display(Dialect::Sqlite), "age BETWEEN 18 AND 65");
+
+        let range = Temporal::between_symmetric(
+            col("day"),
+            Date::new("2024-12-31"),
+            Date::new("2024-01-01"),
+        );
+        assert_eq!(
+            range.display(Dialect::Postgres),
+            "day BETWEEN SYMMETRIC DATE '2024-12-31' AND DATE '2024-01-01'"
+        );
+        assert_eq!(
+            (!range).display(Dialect::MySql),
+            "(day NOT BETWEEN DATE '2024-12-31' AND DATE '2024-01-01' \
+             AND day NOT BETWEEN DATE '2024-01-01' AND DATE '2024-12-31')"
+        );
+    }
+}
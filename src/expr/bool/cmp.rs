@@ -1,4 +1,7 @@
+use std::cmp::Ordering;
+
 use crate::expr::prelude::*;
+use crate::interpreter::compare_with;
 
 // these cases are more complex
 // and `neq` is likely to deviate when display is further refined
@@ -9,19 +12,36 @@ pub use eq::*;
 pub mod neq;
 pub use neq::*;
 
+pub mod distinct;
+pub use distinct::*;
+
+/// Returns a `Signal::NullComparison` message if any of the operands is always `NULL`,
+/// as the comparison then always evaluates into `NULL`.
+pub(crate) fn null_comparison(operands: &[&dyn Expression]) -> Vec<Message> {
+    let is_null =
+        |e: &&dyn Expression| e.nullability(&|_| Nullability::Nullable) == Nullability::Null;
+    match operands.iter().any(is_null) {
+        true => vec![Signal::NullComparison.into()],
+        false => Vec::new(),
+    }
+}
+
 /// Defines and implements behaviour for the 4 basic comparison operators: `<`, `<=`, `>`, `>=`.
 ///
-/// Arguments are ($struct, $trait, $method, $display)
-macro_rules! impl_num_cmp {
-    ($struct:ident, $display:expr) => {
+/// Arguments are ($struct, $display, $pred), the predicate accepting the orderings that
+/// satisfy the comparison.
+macro_rules! impl_ord_cmp {
+    ($struct:ident, $display:expr, $pred:expr) => {
         pub struct $struct {
-            lhs: Box<dyn Expression>, // Numeric
-            rhs: Box<dyn Expression>, // Numeric
+            lhs: Box<dyn Expression>,
+            rhs: Box<dyn Expression>,
+            // keep track of the inner expression types
+            kind: ExprType,
         }
 
         impl $struct {
-            pub fn new(lhs: Box<dyn Expression>, rhs: Box<dyn Expression>) -> Self {
-                Self { lhs, rhs }
+            pub fn new(lhs: Box<dyn Expression>, rhs: Box<dyn Expression>, kind: ExprType) -> Self {
+                Self { lhs, rhs, kind }
             }
         }
 
@@ -34,13 +54,29 @@ macro_rules! impl_num_cmp {
                 ctx: Self::Ctx,
             ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
                 vec![
-                    (self.lhs.as_ref(), ExprType::Num),
-                    (self.rhs.as_ref(), ExprType::Num),
+                    (self.lhs.as_ref(), self.kind),
+                    (self.rhs.as_ref(), self.kind),
                 ]
             }
 
             fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-                Vec::new()
+                null_comparison(&[self.lhs.as_ref(), self.rhs.as_ref()])
+            }
+
+            fn send_all(&self, ctx: Self::Ctx, server: &mut dyn Server<Msg = Self::Msg>) {
+                let link = self.kind == ExprType::Any;
+                if link {
+                    server.accept(Signal::StartLink.into());
+                }
+                for (child, ctx) in self.children(ctx) {
+                    child.send_all(ctx, server);
+                }
+                if link {
+                    server.accept(Signal::EndLink.into());
+                }
+                for msg in self.messages(ctx) {
+                    server.accept(msg);
+                }
             }
         }
         impl Checkable for $struct {}
@@ -52,17 +88,31 @@ macro_rules! impl_num_cmp {
             fn display(&self, dialect: Dialect) -> String {
                 format!(
                     $display,
-                    self.lhs.display(dialect),
-                    self.rhs.display(dialect)
+                    display_operand(self.lhs.as_ref(), Precedence::Atom, dialect),
+                    display_operand(self.rhs.as_ref(), Precedence::Atom, dialect)
                 )
             }
+
+            fn precedence(&self) -> Precedence {
+                Precedence::Cmp
+            }
+
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                self.lhs
+                    .nullability(cols)
+                    .propagate(self.rhs.nullability(cols))
+            }
+
+            fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+                compare_with(&self.lhs.eval(cols)?, &self.rhs.eval(cols)?, $pred)
+            }
         }
         impl Common for $struct {}
         impl Boolean for $struct {}
     };
 }
 
-impl_num_cmp!(Gt, "{} > {}");
-impl_num_cmp!(Geq, "{} >= {}");
-impl_num_cmp!(Lt, "{} < {}");
-impl_num_cmp!(Leq, "{} <= {}");
+impl_ord_cmp!(Gt, "{} > {}", Ordering::is_gt);
+impl_ord_cmp!(Geq, "{} >= {}", Ordering::is_ge);
+impl_ord_cmp!(Lt, "{} < {}", Ordering::is_lt);
+impl_ord_cmp!(Leq, "{} <= {}", Ordering::is_le);
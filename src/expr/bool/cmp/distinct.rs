@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+use crate::expr::prelude::*;
+use crate::interpreter::compare;
+
+/// A null-safe inequality operation, where `NULL` is considered a comparable value.
+/// Its negation is the null-safe equality operation.
+pub struct IsDistinct {
+    lhs: Box<dyn Expression>,
+    rhs: Box<dyn Expression>,
+    // keep track of the inner expression types
+    kind: ExprType,
+}
+
+impl IsDistinct {
+    pub fn new(lhs: Box<dyn Expression>, rhs: Box<dyn Expression>, kind: ExprType) -> Self {
+        Self { lhs, rhs, kind }
+    }
+}
+
+impl Client for IsDistinct {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![
+            (self.lhs.as_ref(), self.kind),
+            (self.rhs.as_ref(), self.kind),
+        ]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Vec::new()
+    }
+}
+impl Checkable for IsDistinct {}
+impl Expression for IsDistinct {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Bool
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let (lhs, rhs) = (
+            display_operand(self.lhs.as_ref(), Precedence::Atom, dialect),
+            display_operand(self.rhs.as_ref(), Precedence::Atom, dialect),
+        );
+        match dialect {
+            Dialect::Postgres => format!("{lhs} IS DISTINCT FROM {rhs}"),
+            Dialect::Sqlite => format!("{lhs} IS NOT {rhs}"),
+            Dialect::MySql => format!("NOT ({lhs} <=> {rhs})"),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+
+    fn precedence(&self) -> Precedence {
+        // MySQL displays it as a `NOT`
+        Precedence::Not
+    }
+
+    fn display_negated(&self, dialect: Dialect) -> Option<String> {
+        let (lhs, rhs) = (
+            display_operand(self.lhs.as_ref(), Precedence::Atom, dialect),
+            display_operand(self.rhs.as_ref(), Precedence::Atom, dialect),
+        );
+        let negated = match dialect {
+            Dialect::Postgres => format!("{lhs} IS NOT DISTINCT FROM {rhs}"),
+            Dialect::Sqlite => format!("{lhs} IS {rhs}"),
+            Dialect::MySql => format!("{lhs} <=> {rhs}"),
+        };
+        Some(negated)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let (lhs, rhs) = (self.lhs.eval(cols)?, self.rhs.eval(cols)?);
+        let distinct = match (&lhs, &rhs) {
+            (Value::Null, Value::Null) => false,
+            (Value::Null, _) | (_, Value::Null) => true,
+            _ => compare(&lhs, &rhs)? != Some(Ordering::Equal),
+        };
+        Ok(Value::Bool(distinct))
+    }
+}
+impl Common for IsDistinct {}
+impl Boolean for IsDistinct {}
@@ -1,4 +1,5 @@
 use crate::expr::prelude::*;
+use crate::interpreter::compare_with;
 
 /// An equality operation.
 /// This struct is common to all equality operations regardless of the CommonType.
@@ -29,7 +30,7 @@ impl Client for Eq {
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        unreachable!();
+        super::null_comparison(&[self.lhs.as_ref(), self.rhs.as_ref()])
     }
 
     fn send_all(&self, ctx: Self::Ctx, server: &mut dyn Server<Msg = Self::Msg>) {
@@ -44,6 +45,10 @@ impl Client for Eq {
                 child.send_all(ctx, server);
             }
         }
+
+        for msg in self.messages(ctx) {
+            server.accept(msg);
+        }
     }
 }
 impl Checkable for Eq {}
@@ -55,10 +60,24 @@ impl Expression for Eq {
     fn display(&self, dialect: Dialect) -> String {
         format!(
             "{} = {}",
-            self.lhs.display(dialect),
-            self.rhs.display(dialect)
+            display_operand(self.lhs.as_ref(), Precedence::Atom, dialect),
+            display_operand(self.rhs.as_ref(), Precedence::Atom, dialect)
         )
     }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::Cmp
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.lhs
+            .nullability(cols)
+            .propagate(self.rhs.nullability(cols))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        compare_with(&self.lhs.eval(cols)?, &self.rhs.eval(cols)?, |o| o.is_eq())
+    }
 }
 impl Common for Eq {}
 impl Boolean for Eq {}
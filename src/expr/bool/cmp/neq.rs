@@ -1,4 +1,5 @@
 use crate::expr::prelude::*;
+use crate::interpreter::compare_with;
 
 pub struct Neq {
     // `dyn Expression` as we can have equality for `(num, num)`, `(text, text)` etc
@@ -29,7 +30,7 @@ impl Client for Neq {
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        Vec::new()
+        super::null_comparison(&[self.lhs.as_ref(), self.rhs.as_ref()])
     }
 }
 impl Checkable for Neq {}
@@ -41,10 +42,24 @@ impl Expression for Neq {
     fn display(&self, dialect: Dialect) -> String {
         format!(
             "{} != {}",
-            self.lhs.display(dialect),
-            self.rhs.display(dialect)
+            display_operand(self.lhs.as_ref(), Precedence::Atom, dialect),
+            display_operand(self.rhs.as_ref(), Precedence::Atom, dialect)
         )
     }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::Cmp
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.lhs
+            .nullability(cols)
+            .propagate(self.rhs.nullability(cols))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        compare_with(&self.lhs.eval(cols)?, &self.rhs.eval(cols)?, |o| o.is_ne())
+    }
 }
 impl Common for Neq {}
 impl Boolean for Neq {}
@@ -0,0 +1,145 @@
+use crate::expr::prelude::*;
+use crate::expr::text::LIKE_ESCAPE;
+use crate::interpreter::{from_truth, like};
+use crate::row::FromValue;
+
+/// A `LIKE` pattern match.
+/// Its negation is displayed as `NOT LIKE`.
+pub struct Like {
+    inner: Box<dyn Expression>,   // Textual
+    pattern: Box<dyn Expression>, // Textual
+    case_insensitive: bool,
+    // whether the pattern escapes its wildcards with `LIKE_ESCAPE`
+    escaped: bool,
+}
+
+impl Like {
+    pub fn new(
+        inner: Box<dyn Expression>,
+        pattern: Box<dyn Expression>,
+        case_insensitive: bool,
+    ) -> Self {
+        Self {
+            inner,
+            pattern,
+            case_insensitive,
+            escaped: false,
+        }
+    }
+
+    /// Creates a match against a pattern whose wildcards are escaped with `LIKE_ESCAPE`, which
+    /// follows the dialect's case sensitivity like `LIKE` does.
+    pub(crate) fn escaped(inner: Box<dyn Expression>, pattern: String) -> Self {
+        Self {
+            inner,
+            pattern: Box::new(pattern),
+            case_insensitive: false,
+            escaped: true,
+        }
+    }
+
+    fn display_with(&self, dialect: Dialect, negated: bool) -> String {
+        let (inner, pattern) = (self.inner.display(dialect), self.pattern.display(dialect));
+        let not = if negated { "NOT " } else { "" };
+        let mut out = match (self.case_insensitive, dialect) {
+            (true, Dialect::Postgres) => format!("{inner} {not}ILIKE {pattern}"),
+            // MySQL's case sensitivity depends on the collation
+            (true, Dialect::MySql) => format!("LOWER({inner}) {not}LIKE LOWER({pattern})"),
+            // SQLite's `LIKE` is case-insensitive by default
+            _ => format!("{inner} {not}LIKE {pattern}"),
+        };
+        if self.escaped {
+            out += &format!(" ESCAPE '{LIKE_ESCAPE}'");
+        }
+        out
+    }
+}
+
+impl Client for Like {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![
+            (self.inner.as_ref(), ExprType::Text),
+            (self.pattern.as_ref(), ExprType::Text),
+        ]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Bool)
+    }
+}
+impl Checkable for Like {}
+impl Expression for Like {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Bool
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        self.display_with(dialect, false)
+    }
+
+    fn display_negated(&self, dialect: Dialect) -> Option<String> {
+        Some(self.display_with(dialect, true))
+    }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::Cmp
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner
+            .nullability(cols)
+            .propagate(self.pattern.nullability(cols))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let inner = Option::<String>::from_value(&self.inner.eval(cols)?)?;
+        let pattern = Option::<String>::from_value(&self.pattern.eval(cols)?)?;
+        let escape = self.escaped.then_some(LIKE_ESCAPE);
+        // like SQLite, `LIKE` is case-insensitive regardless of `case_insensitive`
+        Ok(from_truth(
+            inner
+                .zip(pattern)
+                .map(|(inner, pattern)| like(&inner, &pattern, escape)),
+        ))
+    }
+}
+impl Common for Like {}
+impl Boolean for Like {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn display() {
+        let prefix = Textual::starts_with(col("code"), "50%_off!");
+        assert_eq!(
+            prefix.display(Dialect::Postgres),
+            "code LIKE '50!%!_off!!%' ESCAPE '!'"
+        );
+        assert_eq!(
+            (!prefix).display(Dialect::Sqlite),
+            "code NOT LIKE '50!%!_off!!%' ESCAPE '!'"
+        );
+
+        let name = Textual::ilike(col("name"), "jo%");
+        assert_eq!(name.display(Dialect::Postgres), "name ILIKE 'jo%'");
+        assert_eq!(
+            name.display(Dialect::MySql),
+            "LOWER(name) LIKE LOWER('jo%')"
+        );
+
+        let path = Textual::contains(col("path"), "C:\\dir");
+        assert_eq!(
+            path.display(Dialect::MySql),
+            "path LIKE '%C:\\\\dir%' ESCAPE '!'"
+        );
+    }
+}
@@ -1,6 +1,5 @@
 use crate::expr::prelude::*;
 
-// TODO: add ctx safety checks
 impl Client for bool {
     type Ctx = ExprType;
     type Msg = Message;
@@ -13,7 +12,7 @@ impl Client for bool {
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        Vec::new()
+        Signal::ctx_mismatch(ctx, ExprType::Bool)
     }
 }
 impl Checkable for bool {}
@@ -25,6 +24,14 @@ impl Expression for bool {
     fn display(&self, dialect: Dialect) -> String {
         self.to_string()
     }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        Ok(Value::Bool(*self))
+    }
 }
 impl Common for bool {}
 impl Boolean for bool {}
@@ -43,3 +43,34 @@ where
         CommonExpr(Not::new(Box::new(self)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn precedence() {
+        let either = col("a") | col("b");
+        assert_eq!(
+            (either & col("c")).display(Dialect::Postgres),
+            "(a OR b) AND c"
+        );
+        assert_eq!(
+            (!(col("a") & col("b"))).display(Dialect::Postgres),
+            "NOT (a AND b)"
+        );
+        assert_eq!(
+            Boolean::eq(Numeric::gt(col("a"), 1), col("b")).display(Dialect::Sqlite),
+            "(a > 1) = b"
+        );
+        assert_eq!(
+            (col("a") & col("b")).is_null().display(Dialect::Sqlite),
+            "(a AND b) IS NULL"
+        );
+        assert_eq!(
+            (col("a") | Numeric::eq(col("b"), 1)).display(Dialect::Postgres),
+            "a OR b = 1"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::expr::prelude::*;
+use crate::interpreter::{and, from_truth, truth};
 
 pub struct And {
     lhs: Box<dyn Expression>, // Boolean
@@ -26,8 +27,7 @@ impl Client for And {
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        debug_assert!(matches!(ctx, ExprType::Any | ExprType::Bool));
-        Vec::new()
+        Signal::ctx_mismatch(ctx, ExprType::Bool)
     }
 }
 impl Checkable for And {}
@@ -39,10 +39,28 @@ impl Expression for And {
     fn display(&self, dialect: Dialect) -> String {
         format!(
             "{} AND {}",
-            self.lhs.display(dialect),
-            self.rhs.display(dialect)
+            display_operand(self.lhs.as_ref(), Precedence::And, dialect),
+            display_operand(self.rhs.as_ref(), Precedence::And, dialect)
         )
     }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::And
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        // a `NULL` operand can still be short-circuited by the other operand
+        self.lhs
+            .nullability(cols)
+            .propagate(self.rhs.nullability(cols))
+            .min(Nullability::Nullable)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let lhs = truth(&self.lhs.eval(cols)?)?;
+        let rhs = truth(&self.rhs.eval(cols)?)?;
+        Ok(from_truth(and(lhs, rhs)))
+    }
 }
 impl Common for And {}
 impl Boolean for And {}
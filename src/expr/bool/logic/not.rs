@@ -1,4 +1,5 @@
 use crate::expr::prelude::*;
+use crate::interpreter::{from_truth, truth};
 
 pub struct Not {
     inner: Box<dyn Expression>, // Boolean
@@ -18,12 +19,11 @@ impl Client for Not {
         &self,
         ctx: Self::Ctx,
     ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
-        debug_assert!(matches!(ctx, ExprType::Any | ExprType::Bool));
         vec![(self.inner.as_ref(), ExprType::Bool)]
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        Vec::new()
+        Signal::ctx_mismatch(ctx, ExprType::Bool)
     }
 }
 impl Checkable for Not {}
@@ -32,9 +32,28 @@ impl Expression for Not {
         ExprType::Bool
     }
 
-    // TODO: Account for "IS NOT NULL" for `IS`-type of expressions
     fn display(&self, dialect: Dialect) -> String {
-        format!("NOT {}", self.inner.display(dialect))
+        self.inner.display_negated(dialect).unwrap_or_else(|| {
+            let inner = display_operand(self.inner.as_ref(), Precedence::Not, dialect);
+            format!("NOT {inner}")
+        })
+    }
+
+    fn precedence(&self) -> Precedence {
+        // dedicated negations keep the form of the inner expression
+        match self.inner.display_negated(Dialect::Postgres) {
+            Some(_) => self.inner.precedence(),
+            None => Precedence::Not,
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let inner = truth(&self.inner.eval(cols)?)?;
+        Ok(from_truth(inner.map(|b| !b)))
     }
 }
 impl Common for Not {}
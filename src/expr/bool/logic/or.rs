@@ -1,4 +1,5 @@
 use crate::expr::prelude::*;
+use crate::interpreter::{from_truth, or, truth};
 
 pub struct Or {
     lhs: Box<dyn Expression>, // Boolean
@@ -19,7 +20,6 @@ impl Client for Or {
         &self,
         ctx: Self::Ctx,
     ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
-        debug_assert!(matches!(ctx, ExprType::Any | ExprType::Bool));
         vec![
             (self.lhs.as_ref(), ExprType::Bool),
             (self.rhs.as_ref(), ExprType::Bool),
@@ -27,7 +27,7 @@ impl Client for Or {
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        Vec::new()
+        Signal::ctx_mismatch(ctx, ExprType::Bool)
     }
 }
 impl Checkable for Or {}
@@ -43,6 +43,24 @@ impl Expression for Or {
             self.rhs.display(dialect)
         )
     }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::Or
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        // a `NULL` operand can still be short-circuited by the other operand
+        self.lhs
+            .nullability(cols)
+            .propagate(self.rhs.nullability(cols))
+            .min(Nullability::Nullable)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let lhs = truth(&self.lhs.eval(cols)?)?;
+        let rhs = truth(&self.rhs.eval(cols)?)?;
+        Ok(from_truth(or(lhs, rhs)))
+    }
 }
 impl Common for Or {}
 impl Boolean for Or {}
@@ -2,8 +2,11 @@
 
 use crate::expr::prelude::*;
 
+use super::cast::{AsNum, AsText, Cast};
+
 /// Common expressions that evaluate into boolean values.
 pub trait Boolean: Common {
+    #![allow(clippy::wrong_self_convention)]
     fn eq<R>(self, rhs: R) -> CommonExpr<Eq>
     where
         Self: Sized + 'static,
@@ -19,13 +22,41 @@ pub trait Boolean: Common {
     {
         CommonExpr(Neq::new(Box::new(self), Box::new(rhs), ExprType::Bool))
     }
+
+    fn is_distinct_from<R>(self, rhs: R) -> CommonExpr<IsDistinct>
+    where
+        Self: Sized + 'static,
+        R: Boolean + 'static,
+    {
+        CommonExpr(IsDistinct::new(
+            Box::new(self),
+            Box::new(rhs),
+            ExprType::Bool,
+        ))
+    }
+
+    /// Converts the value into text.
+    fn to_text(self) -> CommonExpr<Cast<AsText>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Bool))
+    }
+
+    /// Converts the value into a number.
+    fn to_num(self) -> CommonExpr<Cast<AsNum>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Bool))
+    }
 }
 impl<T: Boolean> Boolean for CommonExpr<T> {}
 
 /// Comparison expressions
 pub mod cmp;
 #[doc(inline)]
-pub use cmp::{Eq, Geq, Gt, Leq, Lt, Neq};
+pub use cmp::{Eq, Geq, Gt, IsDistinct, Leq, Lt, Neq};
 
 /// Logical operator expressions
 #[macro_use]
@@ -37,4 +68,16 @@ pub mod between;
 #[doc(inline)]
 pub use between::Between;
 
+pub mod like;
+#[doc(inline)]
+pub use like::Like;
+
+pub mod regex;
+#[doc(inline)]
+pub use regex::Regex;
+
+pub mod null;
+#[doc(inline)]
+pub use null::IsNull;
+
 pub mod lit;
@@ -0,0 +1,81 @@
+use crate::expr::prelude::*;
+
+/// An `IS NULL` check.
+/// Its negation is displayed as `IS NOT NULL`.
+pub struct IsNull {
+    inner: Box<dyn Expression>, // Common
+}
+
+impl IsNull {
+    pub fn new(inner: Box<dyn Expression>) -> Self {
+        Self { inner }
+    }
+}
+
+impl Client for IsNull {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), self.inner.eval_type())]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Bool)
+    }
+}
+impl Checkable for IsNull {}
+impl Expression for IsNull {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Bool
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let inner = display_operand(self.inner.as_ref(), Precedence::Atom, dialect);
+        format!("{inner} IS NULL")
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::Cmp
+    }
+
+    fn display_negated(&self, dialect: Dialect) -> Option<String> {
+        let inner = display_operand(self.inner.as_ref(), Precedence::Atom, dialect);
+        Some(format!("{inner} IS NOT NULL"))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        Ok(Value::Bool(self.inner.eval(cols)? == Value::Null))
+    }
+}
+impl Common for IsNull {}
+impl Boolean for IsNull {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn display() {
+        let is_null = col("manager_id").is_null();
+        assert_eq!(is_null.display(Dialect::Postgres), "manager_id IS NULL");
+
+        let not_null = col("manager_id").is_not_null();
+        assert_eq!(
+            not_null.display(Dialect::Postgres),
+            "manager_id IS NOT NULL"
+        );
+
+        let distinct = Numeric::is_distinct_from(col("a"), 1);
+        assert_eq!(distinct.display(Dialect::Sqlite), "a IS NOT 1");
+        assert_eq!((!distinct).display(Dialect::MySql), "a <=> 1");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::expr::prelude::*;
+use crate::expr::render;
+
+/// A regular expression match.
+pub struct Regex {
+    inner: Box<dyn Expression>,   // Textual
+    pattern: Box<dyn Expression>, // Textual
+}
+
+impl Regex {
+    pub fn new(inner: Box<dyn Expression>, pattern: Box<dyn Expression>) -> Self {
+        Self { inner, pattern }
+    }
+
+    fn display_with(&self, dialect: Dialect, negated: bool) -> String {
+        if dialect == Dialect::Sqlite {
+            // `REGEXP` calls a `regexp()` function, which SQLite doesn't define by default
+            render::report("regular expressions are not supported by SQLite".to_string());
+        }
+        let (inner, pattern) = (self.inner.display(dialect), self.pattern.display(dialect));
+        let op = match (dialect, negated) {
+            (Dialect::Postgres, false) => "~",
+            (Dialect::Postgres, true) => "!~",
+            (Dialect::MySql | Dialect::Sqlite, false) => "REGEXP",
+            (Dialect::MySql | Dialect::Sqlite, true) => "NOT REGEXP",
+        };
+        format!("{inner} {op} {pattern}")
+    }
+}
+
+impl Client for Regex {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![
+            (self.inner.as_ref(), ExprType::Text),
+            (self.pattern.as_ref(), ExprType::Text),
+        ]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Bool)
+    }
+}
+impl Checkable for Regex {}
+impl Expression for Regex {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Bool
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        self.display_with(dialect, false)
+    }
+
+    fn display_negated(&self, dialect: Dialect) -> Option<String> {
+        Some(self.display_with(dialect, true))
+    }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::Cmp
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner
+            .nullability(cols)
+            .propagate(self.pattern.nullability(cols))
+    }
+}
+impl Common for Regex {}
+impl Boolean for Regex {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn unsupported() {
+        let render =
+            |expr: &dyn Expression, dialect| render::check(dialect, || Ok(expr.display(dialect)));
+
+        let slug = col("slug").matches_regex("^[a-z-]+$");
+        assert_eq!(
+            render(&slug, Dialect::MySql),
+            Ok("slug REGEXP '^[a-z-]+$'".to_string())
+        );
+        assert_eq!(
+            render(&!slug, Dialect::Postgres),
+            Ok("slug !~ '^[a-z-]+$'".to_string())
+        );
+        let slug = col("slug").matches_regex("^[a-z-]+$");
+        assert_eq!(
+            render(&slug, Dialect::Sqlite),
+            Err("regular expressions are not supported by SQLite".to_string())
+        );
+    }
+}
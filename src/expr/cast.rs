@@ -0,0 +1,173 @@
+//! Explicit conversions between expression families
+
+use std::marker::PhantomData;
+
+use crate::expr::prelude::*;
+use crate::interpreter::{to_num, to_text};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The target types of a [`Cast`].
+///
+/// The trait is sealed, as each target is rendered by its kind.
+pub trait CastTarget: sealed::Sealed + 'static {
+    /// The type of the converted expression.
+    const KIND: ExprType;
+}
+
+macro_rules! impl_cast_target {
+    ($struct:ident, $kind:expr, $family:ident) => {
+        /// Cast target marker.
+        pub struct $struct;
+
+        impl sealed::Sealed for $struct {}
+        impl CastTarget for $struct {
+            const KIND: ExprType = $kind;
+        }
+
+        impl $family for Cast<$struct> {}
+    };
+}
+
+impl_cast_target!(AsBool, ExprType::Bool, Boolean);
+impl_cast_target!(AsNum, ExprType::Num, Numeric);
+impl_cast_target!(AsText, ExprType::Text, Textual);
+impl_cast_target!(AsDate, ExprType::Date, Temporal);
+impl_cast_target!(AsTime, ExprType::Time, Temporal);
+impl_cast_target!(AsTimestamp, ExprType::Timestamp, Temporal);
+
+/// An explicit conversion of an expression into another type.
+///
+/// The source type is kept so that the checker can validate the converted expression.
+pub struct Cast<T> {
+    inner: Box<dyn Expression>,
+    from: ExprType,
+    target: PhantomData<T>,
+}
+
+impl<T: CastTarget> Cast<T> {
+    pub fn new(inner: Box<dyn Expression>, from: ExprType) -> Self {
+        Self {
+            inner,
+            from,
+            target: PhantomData,
+        }
+    }
+
+    /// The name of the target type within `CAST(x AS type)`.
+    fn type_name(dialect: Dialect) -> &'static str {
+        match (T::KIND, dialect) {
+            (ExprType::Bool, Dialect::Postgres) => "BOOLEAN",
+            (ExprType::Bool, Dialect::Sqlite) => "INTEGER",
+            (ExprType::Bool, Dialect::MySql) => "SIGNED",
+            (ExprType::Num, Dialect::Postgres | Dialect::Sqlite) => "NUMERIC",
+            (ExprType::Num, Dialect::MySql) => "DOUBLE",
+            (ExprType::Text, Dialect::Postgres | Dialect::Sqlite) => "TEXT",
+            (ExprType::Text, Dialect::MySql) => "CHAR",
+            (ExprType::Date, _) => "DATE",
+            (ExprType::Time, _) => "TIME",
+            (ExprType::Timestamp, Dialect::MySql) => "DATETIME",
+            (ExprType::Timestamp, _) => "TIMESTAMP",
+            _ => unreachable!("no `CastTarget` of this kind exists"),
+        }
+    }
+}
+
+impl<T: CastTarget> Client for Cast<T> {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), self.from)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Vec::new()
+    }
+}
+impl<T: CastTarget> Checkable for Cast<T> {}
+impl<T: CastTarget> Expression for Cast<T> {
+    fn eval_type(&self) -> ExprType {
+        T::KIND
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let inner = self.inner.display(dialect);
+        match (dialect, T::KIND) {
+            // `::` binds tighter than any other operator
+            (Dialect::Postgres, _) if self.inner.source().is_some() => {
+                format!("{inner}::{}", Self::type_name(dialect))
+            }
+            (Dialect::Postgres, _) => format!("({inner})::{}", Self::type_name(dialect)),
+            // SQLite stores temporal values as text, which `CAST` would turn into numbers
+            (Dialect::Sqlite, ExprType::Date) => format!("date({inner})"),
+            (Dialect::Sqlite, ExprType::Time) => format!("time({inner})"),
+            (Dialect::Sqlite, ExprType::Timestamp) => format!("datetime({inner})"),
+            _ => format!("CAST({inner} AS {})", Self::type_name(dialect)),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let convert = match T::KIND {
+            ExprType::Num => to_num,
+            ExprType::Text => to_text,
+            // booleans and temporal values are stored differently by each dialect
+            _ => {
+                return Err(format!(
+                    "`{}` can't be evaluated in memory",
+                    self.display(Dialect::Postgres)
+                ))
+            }
+        };
+        convert(&self.inner.eval(cols)?)
+    }
+}
+impl<T: CastTarget> Common for Cast<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::DerivedChecker;
+    use crate::expr::any::col;
+    use crate::protocol::ServerHandler;
+
+    #[test]
+    fn display() {
+        let version = Numeric::to_text(col("version"));
+        assert_eq!(version.display(Dialect::Postgres), "version::TEXT");
+        assert_eq!(version.display(Dialect::MySql), "CAST(version AS CHAR)");
+
+        let total = Textual::to_num(Numeric::to_text(col("a") + 1));
+        assert_eq!(
+            total.display(Dialect::Postgres),
+            "(((a + 1))::TEXT)::NUMERIC"
+        );
+        assert_eq!(
+            total.display(Dialect::Sqlite),
+            "CAST(CAST((a + 1) AS TEXT) AS NUMERIC)"
+        );
+
+        let day = Textual::to_date(col("day"));
+        assert_eq!(day.display(Dialect::Sqlite), "date(day)");
+    }
+
+    #[test]
+    fn checker() {
+        let mut checker = DerivedChecker::new();
+        checker.accept(Condition::TableExists("releases".into()).into());
+        Numeric::to_text(col("version")).send_all(ExprType::Text, &mut checker);
+        assert_eq!(checker.col_type("version"), Some(ExprType::Num));
+
+        Textual::to_num(col("version")).send_all(ExprType::Num, &mut checker);
+        assert!(checker.state().is_err());
+    }
+}
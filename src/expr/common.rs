@@ -1,5 +1,8 @@
 use crate::expr::prelude::*;
 
+use super::any::{Coalesce, NullIf};
+use super::bool::{IsNull, Not};
+
 impl<T: Client> Client for CommonExpr<T> {
     type Ctx = T::Ctx;
     type Msg = T::Msg;
@@ -28,18 +31,80 @@ impl<T: Expression> Expression for CommonExpr<T> {
     fn display(&self, dialect: Dialect) -> String {
         self.0.display(dialect)
     }
+
+    fn display_negated(&self, dialect: Dialect) -> Option<String> {
+        self.0.display_negated(dialect)
+    }
+
+    fn precedence(&self) -> Precedence {
+        self.0.precedence()
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.0.nullability(cols)
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.0.name()
+    }
+
+    fn source(&self) -> Option<&str> {
+        self.0.source()
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        self.0.eval(cols)
+    }
+}
+impl<T: Common + 'static> Projection for CommonExpr<T> {
+    fn items(&self) -> Vec<&dyn Expression> {
+        vec![&self.0]
+    }
 }
 
 // Common::eval_type is used by `CommonExpr<Case>` to assess if it can directly downcast from `Anything` into
 // a more specific type.
 /// Expressions that evaluate into DB primitive types: boolean, numeric, textual, temporal, etc.
 pub trait Common: Expression {
+    #![allow(clippy::wrong_self_convention)]
     fn alias(self, s: impl Into<String>) -> CommonExpr<Alias>
     where
         Self: Sized + 'static,
     {
         CommonExpr(Alias::new(Box::new(self), s.into()))
     }
+
+    fn is_null(self) -> CommonExpr<IsNull>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(IsNull::new(Box::new(self)))
+    }
+
+    fn is_not_null(self) -> CommonExpr<Not>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Not::new(Box::new(self.is_null())))
+    }
+
+    /// Returns the first non-null value out of `self` and `rhs`.
+    fn coalesce<R>(self, rhs: R) -> CommonExpr<Coalesce>
+    where
+        Self: Sized + 'static,
+        R: Common + 'static,
+    {
+        CommonExpr(Coalesce::new(vec![Box::new(self), Box::new(rhs)]))
+    }
+
+    /// Returns `NULL` if `self` equals `rhs`, otherwise returns `self`.
+    fn nullif<R>(self, rhs: R) -> CommonExpr<NullIf>
+    where
+        Self: Sized + 'static,
+        R: Common + 'static,
+    {
+        CommonExpr(NullIf::new(Box::new(self), Box::new(rhs)))
+    }
 }
 impl<T: Common> Common for CommonExpr<T> {}
 
@@ -81,5 +146,21 @@ impl Expression for Alias {
     fn display(&self, dialect: Dialect) -> String {
         format!("{} AS {}", self.inner.display(dialect), self.alias)
     }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.alias)
+    }
+
+    fn source(&self) -> Option<&str> {
+        self.inner.source()
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        self.inner.eval(cols)
+    }
 }
 impl Common for Alias {}
@@ -1,14 +1,24 @@
+pub mod agg;
+#[macro_use]
 pub mod any;
 pub mod bool;
+pub mod cast;
 pub mod common;
 pub mod num;
 mod prelude;
+pub(crate) mod render;
+pub mod temporal;
 pub mod text;
 pub mod unique;
+pub mod window;
+
+#[cfg(test)]
+mod tests;
 
 use std::ops::Deref;
 
 use super::checker::{Checkable, Message};
+use crate::row::Value;
 
 /// The possible evaluation types of an expression.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -22,16 +32,89 @@ pub enum ExprType {
     Num,
     /// Expressions that return textual values
     Text,
+    /// Expressions that return calendar dates
+    Date,
+    /// Expressions that return times of day
+    Time,
+    /// Expressions that return dates with a time of day
+    Timestamp,
+    /// Expressions that return dates, times of day or timestamps, whichever it is.
+    /// Like `Any`, it narrows within coercion environments.
+    Temporal,
+    /// Expressions that return time intervals
+    Interval,
     /// Unique expressions
     Unique,
 }
 
+/// Whether an expression can evaluate into `NULL`.
+///
+/// Ordered from least to most null.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Nullability {
+    /// Never evaluates into `NULL`
+    NotNull,
+    /// May evaluate into `NULL`
+    Nullable,
+    /// Always evaluates into `NULL`
+    Null,
+}
+
+impl Nullability {
+    /// The nullability of an expression that is `NULL` whenever any of its operands are,
+    /// such as arithmetic and comparisons.
+    pub fn propagate(self, other: Nullability) -> Nullability {
+        self.max(other)
+    }
+
+    /// The nullability of an expression that evaluates into either of the operands.
+    pub fn either(self, other: Nullability) -> Nullability {
+        match self == other {
+            true => self,
+            false => Nullability::Nullable,
+        }
+    }
+}
+
+/// How tightly an expression binds its operands when displayed.
+///
+/// Ordered from loosest to tightest.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Precedence {
+    /// `OR`
+    Or,
+    /// `AND`
+    And,
+    /// `NOT`
+    Not,
+    /// Comparisons and other predicates, such as `=`, `BETWEEN`, `LIKE` or `IS NULL`
+    Cmp,
+    /// Expressions that are never split by their parent, such as literals, function calls or
+    /// parenthesized arithmetic
+    Atom,
+}
+
+/// Displays the operand of an operator, within parentheses if it binds looser than `min`.
+pub(crate) fn display_operand(expr: &dyn Expression, min: Precedence, dialect: Dialect) -> String {
+    match expr.precedence() < min {
+        true => format!("({})", expr.display(dialect)),
+        false => expr.display(dialect),
+    }
+}
+
 /// Supported dialects, used for expression to string conversion
 // RFC: these might also need to be used to validate the actual expressions
 // themselves as not all dialects support all operations
-#[derive(Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum Dialect {
     Postgres,
+    Sqlite,
+    MySql,
+}
+
+impl Dialect {
+    /// Every supported dialect.
+    pub const ALL: [Dialect; 3] = [Dialect::Postgres, Dialect::Sqlite, Dialect::MySql];
 }
 
 /// Common additional functionality for expressions.
@@ -44,6 +127,48 @@ pub trait Expression: Checkable {
 
     /// Returns the `String` representation of the expression in the given dialect
     fn display(&self, dialect: Dialect) -> String;
+
+    /// How tightly the expression binds its operands when displayed, which decides whether it is
+    /// parenthesized as the operand of another expression.
+    fn precedence(&self) -> Precedence {
+        Precedence::Atom
+    }
+
+    /// Returns the `String` representation of the expression's negation in the given dialect,
+    /// if it has a dedicated form (e.g. `IS NOT NULL`).
+    fn display_negated(&self, dialect: Dialect) -> Option<String> {
+        None
+    }
+
+    /// The nullability of the expression, given the nullability of the columns it accesses.
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::Nullable
+    }
+
+    /// The name of the column that the expression produces when it is selected, if any.
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// The column whose values are directly returned by the expression, if any.
+    fn source(&self) -> Option<&str> {
+        None
+    }
+
+    /// Evaluates the expression over a single row, given the values of the columns it accesses.
+    /// See [`crate::interpreter`] for the semantics.
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        Err(format!(
+            "`{}` can't be evaluated in memory",
+            self.display(Dialect::Postgres)
+        ))
+    }
+}
+
+/// Expressions that can be selected by a query.
+pub trait Projection: Expression {
+    /// Returns the expressions making up each of the selected columns.
+    fn items(&self) -> Vec<&dyn Expression>;
 }
 
 // Expressions are wrapped in types (separate ones depending on if they are Common or not) to
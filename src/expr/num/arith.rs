@@ -1,9 +1,10 @@
 //! Arithmetic operators as expressions
 
 use crate::expr::prelude::*;
+use crate::interpreter::Arith;
 
 macro_rules! impl_arith_expr {
-    ($struct:ident, $display:expr) => {
+    ($struct:ident, $display:expr, $op:expr) => {
         pub struct $struct {
             lhs: Box<dyn Expression>, // Numeric
             rhs: Box<dyn Expression>, // Numeric
@@ -46,17 +47,27 @@ macro_rules! impl_arith_expr {
                     self.rhs.display(dialect)
                 )
             }
+
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                self.lhs
+                    .nullability(cols)
+                    .propagate(self.rhs.nullability(cols))
+            }
+
+            fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+                $op.apply(&self.lhs.eval(cols)?, &self.rhs.eval(cols)?)
+            }
         }
         impl Common for $struct {}
         impl Numeric for $struct {}
     };
 }
 
-impl_arith_expr!(Add, "({} + {})");
-impl_arith_expr!(Sub, "({} - {})");
-impl_arith_expr!(Mul, "({} * {})");
-impl_arith_expr!(Div, "({} / {})");
-impl_arith_expr!(Rem, "({} % {})");
+impl_arith_expr!(Add, "({} + {})", Arith::Add);
+impl_arith_expr!(Sub, "({} - {})", Arith::Sub);
+impl_arith_expr!(Mul, "({} * {})", Arith::Mul);
+impl_arith_expr!(Div, "({} / {})", Arith::Div);
+impl_arith_expr!(Rem, "({} % {})", Arith::Rem);
 
 impl<L, R> std::ops::Add<R> for CommonExpr<L>
 where
@@ -0,0 +1,66 @@
+use crate::expr::prelude::*;
+use crate::expr::temporal::TimeUnit;
+
+/// A field extracted out of a temporal value, e.g. its month.
+pub struct Extract {
+    inner: Box<dyn Expression>, // Temporal
+    field: TimeUnit,
+}
+
+impl Extract {
+    pub fn new(inner: Box<dyn Expression>, field: TimeUnit) -> Self {
+        Self { inner, field }
+    }
+}
+
+impl Client for Extract {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), ExprType::Temporal)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for Extract {}
+impl Expression for Extract {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let x = self.inner.display(dialect);
+        match dialect {
+            Dialect::Postgres | Dialect::MySql => {
+                format!("EXTRACT({} FROM {x})", self.field.keyword())
+            }
+            Dialect::Sqlite => {
+                let format = match self.field {
+                    TimeUnit::Quarter => {
+                        return format!("((CAST(strftime('%m', {x}) AS INTEGER) + 2) / 3)")
+                    }
+                    TimeUnit::Year => "%Y",
+                    TimeUnit::Month => "%m",
+                    TimeUnit::Week => "%W",
+                    TimeUnit::Day => "%d",
+                    TimeUnit::Hour => "%H",
+                    TimeUnit::Minute => "%M",
+                    TimeUnit::Second => "%S",
+                };
+                format!("CAST(strftime('{format}', {x}) AS INTEGER)")
+            }
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+}
+impl Common for Extract {}
+impl Numeric for Extract {}
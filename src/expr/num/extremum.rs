@@ -0,0 +1,90 @@
+use crate::expr::prelude::*;
+
+/// Defines the largest or smallest value out of a list of numeric expressions.
+///
+/// Arguments are ($struct, $function, $sqlite_function)
+macro_rules! impl_extremum {
+    ($struct:ident, $function:expr, $sqlite_function:expr) => {
+        pub struct $struct {
+            exprs: Vec<Box<dyn Expression>>, // Numeric
+        }
+
+        impl $struct {
+            pub fn new(exprs: Vec<Box<dyn Expression>>) -> Self {
+                Self { exprs }
+            }
+
+            pub fn push(&mut self, expr: Box<dyn Expression>) {
+                self.exprs.push(expr);
+            }
+        }
+
+        impl Client for $struct {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                self.exprs
+                    .iter()
+                    .map(|e| (e.as_ref() as _, ExprType::Num))
+                    .collect()
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Signal::ctx_mismatch(ctx, ExprType::Num)
+            }
+        }
+        impl Checkable for $struct {}
+        impl Expression for $struct {
+            fn eval_type(&self) -> ExprType {
+                ExprType::Num
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                let exprs = self
+                    .exprs
+                    .iter()
+                    .map(|e| e.display(dialect))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match dialect {
+                    Dialect::Postgres | Dialect::MySql => format!("{}({exprs})", $function),
+                    // the scalar versions of the aggregates
+                    Dialect::Sqlite => format!("{}({exprs})", $sqlite_function),
+                }
+            }
+
+            // PostgreSQL ignores NULL arguments, while the other dialects return NULL
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                self.exprs
+                    .iter()
+                    .map(|e| e.nullability(cols).min(Nullability::Nullable))
+                    .fold(Nullability::NotNull, Nullability::propagate)
+            }
+        }
+        impl Common for $struct {}
+        impl Numeric for $struct {}
+    };
+}
+
+impl_extremum!(Greatest, "GREATEST", "MAX");
+impl_extremum!(Least, "LEAST", "MIN");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn display() {
+        let top = Numeric::greatest(Numeric::greatest(col("a"), col("b")), 0);
+        assert_eq!(top.display(Dialect::Postgres), "GREATEST(a, b, 0)");
+        assert_eq!(top.display(Dialect::Sqlite), "MAX(a, b, 0)");
+
+        let bottom = Numeric::least(col("a"), Numeric::least(col("b"), 0));
+        assert_eq!(bottom.display(Dialect::MySql), "LEAST(a, LEAST(b, 0))");
+    }
+}
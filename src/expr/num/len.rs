@@ -18,12 +18,11 @@ impl Client for Len {
         &self,
         ctx: Self::Ctx,
     ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
-        debug_assert!(matches!(ctx, ExprType::Any | ExprType::Num));
         vec![(self.inner.as_ref(), ExprType::Text)]
     }
 
     fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-        Vec::new()
+        Signal::ctx_mismatch(ctx, ExprType::Num)
     }
 }
 impl Checkable for Len {}
@@ -34,7 +33,23 @@ impl Expression for Len {
 
     fn display(&self, dialect: Dialect) -> String {
         match dialect {
-            Dialect::Postgres => format!("LENGTH({})", self.inner.display(dialect)),
+            Dialect::Postgres | Dialect::Sqlite => {
+                format!("LENGTH({})", self.inner.display(dialect))
+            }
+            // `LENGTH` returns the number of bytes in MySQL
+            Dialect::MySql => format!("CHAR_LENGTH({})", self.inner.display(dialect)),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        match self.inner.eval(cols)? {
+            Value::Null => Ok(Value::Null),
+            Value::Text(s) => Ok(Value::Int(s.chars().count() as i64)),
+            value => Err(format!("expected text, found {value}")),
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::expr::prelude::*;
+use crate::expr::render;
 
 macro_rules! impl_numeric_lit {
     ($t:ty) => {
@@ -14,10 +15,8 @@ macro_rules! impl_numeric_lit {
             }
 
             fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
-                Vec::new()
+                Signal::ctx_mismatch(ctx, ExprType::Num)
             }
-
-            fn send_all(&self, ctx: Self::Ctx, server: &mut dyn Server<Msg = Self::Msg>) {}
         }
         impl Checkable for $t {}
         impl Expression for $t {
@@ -28,6 +27,16 @@ macro_rules! impl_numeric_lit {
             fn display(&self, dialect: Dialect) -> String {
                 self.to_string()
             }
+
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                Nullability::NotNull
+            }
+
+            fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+                i64::try_from(*self)
+                    .map(Value::Int)
+                    .map_err(|_| format!("{self} is out of range for an integer"))
+            }
         }
         impl Common for $t {}
         impl Numeric for $t {}
@@ -47,3 +56,70 @@ impl_numeric_lit!(i32);
 impl_numeric_lit!(i64);
 impl_numeric_lit!(i128);
 impl_numeric_lit!(isize);
+
+/// Float literals, which keep their decimal point or exponent so that dialects read them as
+/// non-integral numbers.
+impl Client for f64 {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        Vec::new()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for f64 {}
+impl Expression for f64 {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        if !self.is_finite() {
+            render::report(format!("{self} has no SQL literal"));
+        }
+        // unlike `Display`, `Debug` keeps the `.0` of integral floats
+        format!("{self:?}")
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        Ok(Value::Float(*self))
+    }
+}
+impl Common for f64 {}
+impl Numeric for f64 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn floats() {
+        let ratio = col("done") * 1.0 / col("total");
+        assert_eq!(ratio.display(Dialect::Postgres), "((done * 1.0) / total)");
+        assert_eq!(
+            Numeric::round_to(col("x") * 1e-7, 2).display(Dialect::Sqlite),
+            "ROUND((x * 1e-7), 2)"
+        );
+
+        let render = |expr: &dyn Expression| {
+            render::check(Dialect::Sqlite, || Ok(expr.display(Dialect::Sqlite)))
+        };
+        assert!(render(&(col("x") + 2.5)).is_ok());
+        assert_eq!(
+            render(&(col("x") + f64::INFINITY)),
+            Err("inf has no SQL literal".to_string())
+        );
+    }
+}
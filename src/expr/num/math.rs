@@ -0,0 +1,263 @@
+//! Scalar math functions as expressions
+//!
+//! SQLite only provides the functions besides `ABS` and `ROUND` when it is compiled with
+//! `SQLITE_ENABLE_MATH_FUNCTIONS`, which is the default since version 3.35.
+
+use crate::expr::prelude::*;
+use crate::interpreter;
+use crate::row::FromValue;
+
+/// Defines a numeric function of a single numeric argument.
+///
+/// Arguments are ($struct, $display, $eval, $partial), the latter being whether the function is
+/// `NULL` for arguments outside of its domain.
+macro_rules! impl_unary_math {
+    ($struct:ident, $display:expr, $eval:path, $partial:literal) => {
+        pub struct $struct {
+            inner: Box<dyn Expression>, // Numeric
+        }
+
+        impl $struct {
+            pub fn new(inner: Box<dyn Expression>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl Client for $struct {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                vec![(self.inner.as_ref(), ExprType::Num)]
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Signal::ctx_mismatch(ctx, ExprType::Num)
+            }
+        }
+        impl Checkable for $struct {}
+        impl Expression for $struct {
+            fn eval_type(&self) -> ExprType {
+                ExprType::Num
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                format!($display, self.inner.display(dialect))
+            }
+
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                let nullability = self.inner.nullability(cols);
+                if $partial {
+                    nullability.propagate(Nullability::Nullable)
+                } else {
+                    nullability
+                }
+            }
+
+            fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+                $eval(&self.inner.eval(cols)?)
+            }
+        }
+        impl Common for $struct {}
+        impl Numeric for $struct {}
+    };
+}
+
+// the space keeps a negative operand from starting a `--` comment
+impl_unary_math!(Neg, "(- {})", interpreter::neg, false);
+impl_unary_math!(Abs, "ABS({})", interpreter::abs, false);
+impl_unary_math!(Floor, "FLOOR({})", interpreter::floor, false);
+impl_unary_math!(Ceil, "CEIL({})", interpreter::ceil, false);
+// `SQRT` and `LN` of negative numbers are NULL in SQLite and MySQL
+impl_unary_math!(Sqrt, "SQRT({})", interpreter::sqrt, true);
+impl_unary_math!(Ln, "LN({})", interpreter::ln, true);
+
+/// A number rounded half away from zero to a number of decimal places.
+///
+/// PostgreSQL only accepts decimal places for `NUMERIC` values, not for floats.
+pub struct Round {
+    inner: Box<dyn Expression>,          // Numeric
+    digits: Option<Box<dyn Expression>>, // Numeric
+}
+
+impl Round {
+    pub fn new(inner: Box<dyn Expression>, digits: Option<Box<dyn Expression>>) -> Self {
+        Self { inner, digits }
+    }
+}
+
+impl Client for Round {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        std::iter::once(self.inner.as_ref())
+            .chain(self.digits.as_deref())
+            .map(|n| (n as _, ExprType::Num))
+            .collect()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for Round {}
+impl Expression for Round {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let x = self.inner.display(dialect);
+        match &self.digits {
+            Some(digits) => format!("ROUND({x}, {})", digits.display(dialect)),
+            None => format!("ROUND({x})"),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        let digits = match &self.digits {
+            Some(digits) => digits.nullability(cols),
+            None => Nullability::NotNull,
+        };
+        self.inner.nullability(cols).propagate(digits)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let Some(x) = Option::<f64>::from_value(&self.inner.eval(cols)?)? else {
+            return Ok(Value::Null);
+        };
+        let places = match &self.digits {
+            Some(digits) => match interpreter::int_arg(&digits.eval(cols)?)? {
+                Some(places) => places,
+                None => return Ok(Value::Null),
+            },
+            None => 0,
+        };
+        interpreter::round(x, places)
+    }
+}
+impl Common for Round {}
+impl Numeric for Round {}
+
+/// A number raised to a power.
+///
+/// The result is always a float, even for integer arguments.
+pub struct Power {
+    base: Box<dyn Expression>,     // Numeric
+    exponent: Box<dyn Expression>, // Numeric
+}
+
+impl Power {
+    pub fn new(base: Box<dyn Expression>, exponent: Box<dyn Expression>) -> Self {
+        Self { base, exponent }
+    }
+}
+
+impl Client for Power {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![
+            (self.base.as_ref(), ExprType::Num),
+            (self.exponent.as_ref(), ExprType::Num),
+        ]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for Power {}
+impl Expression for Power {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let (base, exponent) = (self.base.display(dialect), self.exponent.display(dialect));
+        match dialect {
+            Dialect::Postgres | Dialect::Sqlite => format!("POWER({base}, {exponent})"),
+            Dialect::MySql => format!("POW({base}, {exponent})"),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.base
+            .nullability(cols)
+            .propagate(self.exponent.nullability(cols))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let base = Option::<f64>::from_value(&self.base.eval(cols)?)?;
+        let exponent = Option::<f64>::from_value(&self.exponent.eval(cols)?)?;
+        match (base, exponent) {
+            (Some(base), Some(exponent)) => Ok(interpreter::real(base.powf(exponent))),
+            _ => Ok(Value::Null),
+        }
+    }
+}
+impl Common for Power {}
+impl Numeric for Power {}
+
+impl<T: Numeric + 'static> std::ops::Neg for CommonExpr<T> {
+    type Output = CommonExpr<Neg>;
+
+    fn neg(self) -> Self::Output {
+        super::Numeric::neg(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::{col, NULL};
+
+    #[test]
+    fn display() {
+        let distance = Numeric::sqrt(Numeric::power(col("x"), 2) + Numeric::power(col("y"), 2));
+        assert_eq!(
+            distance.display(Dialect::Postgres),
+            "SQRT((POWER(x, 2) + POWER(y, 2)))"
+        );
+        assert_eq!(
+            distance.display(Dialect::MySql),
+            "SQRT((POW(x, 2) + POW(y, 2)))"
+        );
+
+        let price = Numeric::round_to(-col("discount") * 1, 2);
+        assert_eq!(
+            price.display(Dialect::Sqlite),
+            "ROUND(((- discount) * 1), 2)"
+        );
+
+        assert_eq!(Numeric::neg(-5).display(Dialect::Sqlite), "(- -5)");
+        assert_eq!((-Numeric::neg(-5)).display(Dialect::Sqlite), "(- (- -5))");
+    }
+
+    #[test]
+    fn nullability() {
+        let not_null = |_: &str| Nullability::NotNull;
+        assert_eq!(col("x").abs().nullability(&not_null), Nullability::NotNull);
+        // negative numbers have no square root or logarithm
+        assert_eq!(
+            col("x").sqrt().nullability(&not_null),
+            Nullability::Nullable
+        );
+        assert_eq!(col("x").ln().nullability(&not_null), Nullability::Nullable);
+        assert_eq!(
+            Numeric::sqrt(NULL).nullability(&not_null),
+            Nullability::Null
+        );
+    }
+}
@@ -10,14 +10,32 @@ pub mod len;
 #[doc(inline)]
 pub use len::Len;
 
+pub mod position;
+#[doc(inline)]
+pub use position::Position;
+
+pub mod extract;
+#[doc(inline)]
+pub use extract::Extract;
+
+pub mod math;
+#[doc(inline)]
+pub use math::{Abs, Ceil, Floor, Ln, Neg, Power, Round, Sqrt};
+
+pub mod extremum;
+#[doc(inline)]
+pub use extremum::{Greatest, Least};
+
 pub mod lit;
 #[doc(inline)]
 pub use lit::*;
 
 use super::bool;
+use super::cast::{AsBool, AsText, Cast};
 
 /// Marker trait for expressions that evaluate into boolean values
 pub trait Numeric: Common {
+    #![allow(clippy::wrong_self_convention)]
     fn add<R>(self, rhs: R) -> CommonExpr<Add>
     where
         Self: Sized + 'static,
@@ -42,6 +60,12 @@ pub trait Numeric: Common {
         CommonExpr(Mul::new(Box::new(self), Box::new(rhs)))
     }
 
+    /// Divides both values.
+    ///
+    /// Numbers carry no distinction between integers and floats, so the result follows the
+    /// dialect: PostgreSQL and SQLite truncate the division of two integers, while MySQL
+    /// always returns a decimal. Multiply either operand by the float literal `1.0` to divide
+    /// without truncation in every dialect.
     fn div<R>(self, rhs: R) -> CommonExpr<Div>
     where
         Self: Sized + 'static,
@@ -58,6 +82,116 @@ pub trait Numeric: Common {
         CommonExpr(Rem::new(Box::new(self), Box::new(rhs)))
     }
 
+    fn neg(self) -> CommonExpr<Neg>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Neg::new(Box::new(self)))
+    }
+
+    fn abs(self) -> CommonExpr<Abs>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Abs::new(Box::new(self)))
+    }
+
+    /// Rounds the value to the nearest integer.
+    fn round(self) -> CommonExpr<Round>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Round::new(Box::new(self), None))
+    }
+
+    /// Rounds the value to the given number of decimal places.
+    fn round_to<D>(self, digits: D) -> CommonExpr<Round>
+    where
+        Self: Sized + 'static,
+        D: Numeric + 'static,
+    {
+        CommonExpr(Round::new(Box::new(self), Some(Box::new(digits))))
+    }
+
+    fn floor(self) -> CommonExpr<Floor>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Floor::new(Box::new(self)))
+    }
+
+    fn ceil(self) -> CommonExpr<Ceil>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Ceil::new(Box::new(self)))
+    }
+
+    /// Raises the value to the given power, always resulting in a float.
+    fn power<E>(self, exponent: E) -> CommonExpr<Power>
+    where
+        Self: Sized + 'static,
+        E: Numeric + 'static,
+    {
+        CommonExpr(Power::new(Box::new(self), Box::new(exponent)))
+    }
+
+    /// The square root of the value, always a float.
+    fn sqrt(self) -> CommonExpr<Sqrt>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Sqrt::new(Box::new(self)))
+    }
+
+    /// The natural logarithm of the value, always a float.
+    fn ln(self) -> CommonExpr<Ln>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Ln::new(Box::new(self)))
+    }
+
+    /// The largest of both values.
+    fn greatest<R>(self, rhs: R) -> CommonExpr<Greatest>
+    where
+        Self: Sized + 'static,
+        R: Numeric + 'static,
+    {
+        // extend existing lists instead of nesting them
+        let lhs: Box<dyn std::any::Any> = Box::new(self);
+        match lhs.downcast::<CommonExpr<Greatest>>() {
+            Ok(mut greatest) => {
+                greatest.0.push(Box::new(rhs));
+                *greatest
+            }
+            Err(lhs) => {
+                let lhs = lhs.downcast::<Self>().expect("`lhs` is of type `Self`");
+                CommonExpr(Greatest::new(vec![lhs, Box::new(rhs)]))
+            }
+        }
+    }
+
+    /// The smallest of both values.
+    fn least<R>(self, rhs: R) -> CommonExpr<Least>
+    where
+        Self: Sized + 'static,
+        R: Numeric + 'static,
+    {
+        // extend existing lists instead of nesting them
+        let lhs: Box<dyn std::any::Any> = Box::new(self);
+        match lhs.downcast::<CommonExpr<Least>>() {
+            Ok(mut least) => {
+                least.0.push(Box::new(rhs));
+                *least
+            }
+            Err(lhs) => {
+                let lhs = lhs.downcast::<Self>().expect("`lhs` is of type `Self`");
+                CommonExpr(Least::new(vec![lhs, Box::new(rhs)]))
+            }
+        }
+    }
+
     fn eq<R>(self, rhs: R) -> CommonExpr<bool::Eq>
     where
         Self: Sized + 'static,
@@ -74,12 +208,24 @@ pub trait Numeric: Common {
         CommonExpr(bool::Neq::new(Box::new(self), Box::new(rhs), ExprType::Num))
     }
 
+    fn is_distinct_from<R>(self, rhs: R) -> CommonExpr<bool::IsDistinct>
+    where
+        Self: Sized + 'static,
+        R: Numeric + 'static,
+    {
+        CommonExpr(bool::IsDistinct::new(
+            Box::new(self),
+            Box::new(rhs),
+            ExprType::Num,
+        ))
+    }
+
     fn gt<R>(self, rhs: R) -> CommonExpr<bool::Gt>
     where
         Self: Sized + 'static,
         R: Numeric + 'static,
     {
-        CommonExpr(bool::Gt::new(Box::new(self), Box::new(rhs)))
+        CommonExpr(bool::Gt::new(Box::new(self), Box::new(rhs), ExprType::Num))
     }
 
     fn ge<R>(self, rhs: R) -> CommonExpr<bool::Geq>
@@ -87,7 +233,7 @@ pub trait Numeric: Common {
         Self: Sized + 'static,
         R: Numeric + 'static,
     {
-        CommonExpr(bool::Geq::new(Box::new(self), Box::new(rhs)))
+        CommonExpr(bool::Geq::new(Box::new(self), Box::new(rhs), ExprType::Num))
     }
 
     fn lt<R>(self, rhs: R) -> CommonExpr<bool::Lt>
@@ -95,7 +241,7 @@ pub trait Numeric: Common {
         Self: Sized + 'static,
         R: Numeric + 'static,
     {
-        CommonExpr(bool::Lt::new(Box::new(self), Box::new(rhs)))
+        CommonExpr(bool::Lt::new(Box::new(self), Box::new(rhs), ExprType::Num))
     }
 
     fn leq<R>(self, rhs: R) -> CommonExpr<bool::Leq>
@@ -103,7 +249,7 @@ pub trait Numeric: Common {
         Self: Sized + 'static,
         R: Numeric + 'static,
     {
-        CommonExpr(bool::Leq::new(Box::new(self), Box::new(rhs)))
+        CommonExpr(bool::Leq::new(Box::new(self), Box::new(rhs), ExprType::Num))
     }
 
     fn between<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
@@ -116,9 +262,32 @@ pub trait Numeric: Common {
             Box::new(self),
             Box::new(lower),
             Box::new(upper),
+            ExprType::Num,
         ))
     }
 
+    /// `NOT BETWEEN`, whether the value lies outside of the inclusive bounds.
+    fn not_between<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Numeric + 'static,
+        U: Numeric + 'static,
+    {
+        let CommonExpr(between) = self.between(lower, upper);
+        CommonExpr(between.negated())
+    }
+
+    /// `BETWEEN SYMMETRIC`, which accepts the bounds in either order.
+    fn between_symmetric<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Numeric + 'static,
+        U: Numeric + 'static,
+    {
+        let CommonExpr(between) = self.between(lower, upper);
+        CommonExpr(between.symmetric())
+    }
+
     fn within<Idx>(self, range: std::ops::RangeInclusive<Idx>) -> CommonExpr<bool::Between>
     where
         Self: Sized + 'static,
@@ -127,5 +296,21 @@ pub trait Numeric: Common {
         let (lower, upper) = range.into_inner();
         self.between(lower, upper)
     }
+
+    /// Converts the value into text.
+    fn to_text(self) -> CommonExpr<Cast<AsText>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Num))
+    }
+
+    /// Converts the value into a boolean.
+    fn to_bool(self) -> CommonExpr<Cast<AsBool>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Num))
+    }
 }
 impl<T: Numeric> Numeric for CommonExpr<T> {}
@@ -0,0 +1,64 @@
+use crate::expr::prelude::*;
+use crate::interpreter::instr;
+use crate::row::FromValue;
+
+/// The 1-based character position of a substring within a text, or 0 if it is missing.
+pub struct Position {
+    inner: Box<dyn Expression>,  // Textual
+    needle: Box<dyn Expression>, // Textual
+}
+
+impl Position {
+    pub fn new(inner: Box<dyn Expression>, needle: Box<dyn Expression>) -> Self {
+        Self { inner, needle }
+    }
+}
+
+impl Client for Position {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![
+            (self.inner.as_ref(), ExprType::Text),
+            (self.needle.as_ref(), ExprType::Text),
+        ]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for Position {}
+impl Expression for Position {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let (x, needle) = (self.inner.display(dialect), self.needle.display(dialect));
+        match dialect {
+            Dialect::Postgres | Dialect::MySql => format!("POSITION({needle} IN {x})"),
+            Dialect::Sqlite => format!("INSTR({x}, {needle})"),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner
+            .nullability(cols)
+            .propagate(self.needle.nullability(cols))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let inner = Option::<String>::from_value(&self.inner.eval(cols)?)?;
+        let needle = Option::<String>::from_value(&self.needle.eval(cols)?)?;
+        Ok(inner
+            .zip(needle)
+            .map_or(Value::Null, |(s, needle)| Value::Int(instr(&s, &needle))))
+    }
+}
+impl Common for Position {}
+impl Numeric for Position {}
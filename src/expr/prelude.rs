@@ -1,4 +1,9 @@
-pub use super::{any::Anything, bool::Boolean, num::Numeric, text::Textual};
-pub use super::{common::Common, CommonExpr, Dialect, ExprType, Expression, UniqueExpr};
+pub(crate) use super::display_operand;
+pub use super::{any::Anything, bool::Boolean, num::Numeric, temporal::Temporal, text::Textual};
+pub use super::{
+    common::Common, CommonExpr, Dialect, ExprType, Expression, Nullability, Precedence, Projection,
+    UniqueExpr,
+};
 pub use crate::checker::{Checkable, Checker, Condition, Message, Signal};
 pub use crate::protocol::{Client, Server};
+pub use crate::row::Value;
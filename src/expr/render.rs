@@ -0,0 +1,73 @@
+//! Errors found while rendering expressions into a dialect.
+//!
+//! [`Expression::display`](super::Expression::display) can't fail, so expressions that can't be
+//! expressed in a dialect report why while they are displayed, and render a best effort instead.
+//! Statements render within [`check`], which turns the reports into an error.
+
+use std::cell::RefCell;
+
+use super::Dialect;
+use crate::reader::join_errors;
+
+/// What has been reported while rendering the current statement.
+#[derive(Default)]
+struct Reports {
+    errors: Vec<String>,
+    /// The indices of the positional parameters, in the order they were rendered
+    params: Vec<usize>,
+}
+
+thread_local! {
+    /// The reports of the statement that is being rendered on this thread, if any
+    static REPORTS: RefCell<Option<Reports>> = const { RefCell::new(None) };
+}
+
+/// Reports that an expression can't be rendered as it is.
+/// Outside of a statement the report is dropped.
+pub(crate) fn report(error: String) {
+    REPORTS.with_borrow_mut(|reports| {
+        if let Some(reports) = reports {
+            if !reports.errors.contains(&error) {
+                reports.errors.push(error);
+            }
+        }
+    });
+}
+
+/// Records a positional parameter, which is bound by the order of its appearance.
+pub(crate) fn positional_param(index: usize) {
+    REPORTS.with_borrow_mut(|reports| {
+        if let Some(reports) = reports {
+            reports.params.push(index);
+        }
+    });
+}
+
+/// Renders a statement, returning an error if any of its expressions can't be rendered.
+/// Statements rendered within another one are checked as part of the outer statement.
+pub(crate) fn check(
+    dialect: Dialect,
+    render: impl FnOnce() -> Result<String, String>,
+) -> Result<String, String> {
+    if REPORTS.with_borrow(Option::is_some) {
+        return render();
+    }
+
+    REPORTS.set(Some(Reports::default()));
+    let sql = render();
+    let Reports { mut errors, params } = REPORTS.take().unwrap_or_default();
+
+    if params.iter().copied().ne(1..=params.len()) {
+        let params: Vec<_> = params.iter().map(ToString::to_string).collect();
+        errors.push(format!(
+            "{dialect:?} binds parameters by position, so they must appear in order from 1, \
+             but appear as {}",
+            params.join(", ")
+        ));
+    }
+    match sql {
+        Ok(sql) if errors.is_empty() => Ok(sql),
+        Ok(_) => Err(join_errors(errors)),
+        Err(e) => Err(e),
+    }
+}
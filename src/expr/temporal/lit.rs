@@ -0,0 +1,140 @@
+use super::TimeUnit;
+use crate::expr::prelude::*;
+use crate::expr::text::quote;
+
+/// Defines a temporal literal written in ISO 8601 format.
+///
+/// Arguments are ($struct, $kind, $keyword)
+macro_rules! impl_temporal_lit {
+    ($struct:ident, $kind:expr, $keyword:expr) => {
+        pub struct $struct {
+            value: String,
+        }
+
+        impl $struct {
+            pub fn new(value: impl Into<String>) -> Self {
+                Self {
+                    value: value.into(),
+                }
+            }
+        }
+
+        impl Client for $struct {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                Vec::new()
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Signal::ctx_mismatch(ctx, $kind)
+            }
+        }
+        impl Checkable for $struct {}
+        impl Expression for $struct {
+            fn eval_type(&self) -> ExprType {
+                $kind
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                match dialect {
+                    Dialect::Postgres | Dialect::MySql => {
+                        format!("{} {}", $keyword, quote(&self.value, dialect))
+                    }
+                    // SQLite stores temporal values as text
+                    Dialect::Sqlite => quote(&self.value, dialect),
+                }
+            }
+
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                Nullability::NotNull
+            }
+        }
+        impl Common for $struct {}
+        impl Temporal for $struct {}
+    };
+}
+
+impl_temporal_lit!(Date, ExprType::Date, "DATE");
+impl_temporal_lit!(Time, ExprType::Time, "TIME");
+impl_temporal_lit!(Timestamp, ExprType::Timestamp, "TIMESTAMP");
+
+/// A time interval of a single unit, e.g. 3 days.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Interval {
+    amount: i64,
+    unit: TimeUnit,
+}
+
+impl Interval {
+    pub fn new(amount: i64, unit: TimeUnit) -> Self {
+        Self { amount, unit }
+    }
+
+    /// Returns the interval in the units supported by every dialect.
+    /// Quarters and weeks are converted into months and days respectively.
+    fn normalized(self) -> (i64, TimeUnit) {
+        match self.unit {
+            TimeUnit::Quarter => (self.amount * 3, TimeUnit::Month),
+            TimeUnit::Week => (self.amount * 7, TimeUnit::Day),
+            unit => (self.amount, unit),
+        }
+    }
+
+    /// Returns the interval as an SQLite date modifier, e.g. `'+3 days'`.
+    pub(crate) fn modifier(self) -> String {
+        let (amount, unit) = self.normalized();
+        format!("'{amount:+} {}s'", unit.keyword().to_lowercase())
+    }
+}
+
+impl std::ops::Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Self::Output {
+        Interval::new(-self.amount, self.unit)
+    }
+}
+
+impl Client for Interval {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        Vec::new()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Vec::new()
+    }
+}
+impl Checkable for Interval {}
+impl Expression for Interval {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Interval
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        match dialect {
+            Dialect::Postgres => {
+                let (amount, unit) = self.normalized();
+                format!("INTERVAL '{amount} {}s'", unit.keyword().to_lowercase())
+            }
+            Dialect::MySql => format!("INTERVAL {} {}", self.amount, self.unit.keyword()),
+            // SQLite has no interval type, they only exist as date modifiers
+            Dialect::Sqlite => self.modifier(),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+}
+impl Common for Interval {}
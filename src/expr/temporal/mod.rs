@@ -0,0 +1,345 @@
+//! Expressions that evaluate into temporal values: dates, times and timestamps
+
+use crate::expr::prelude::*;
+
+use super::cast::{AsDate, AsText, AsTimestamp, Cast};
+use super::{bool, num, text};
+
+pub mod lit;
+#[doc(inline)]
+pub use lit::{Date, Interval, Time, Timestamp};
+
+pub mod shift;
+#[doc(inline)]
+pub use shift::Shift;
+
+pub mod trunc;
+#[doc(inline)]
+pub use trunc::DateTrunc;
+
+pub mod now;
+#[doc(inline)]
+pub use now::{now, Now};
+
+/// Units of time, used for intervals, truncation and field extraction.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum TimeUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl TimeUnit {
+    /// The unit's keyword, e.g. `MONTH` in `EXTRACT(MONTH FROM x)`.
+    pub(crate) fn keyword(self) -> &'static str {
+        match self {
+            TimeUnit::Year => "YEAR",
+            TimeUnit::Quarter => "QUARTER",
+            TimeUnit::Month => "MONTH",
+            TimeUnit::Week => "WEEK",
+            TimeUnit::Day => "DAY",
+            TimeUnit::Hour => "HOUR",
+            TimeUnit::Minute => "MINUTE",
+            TimeUnit::Second => "SECOND",
+        }
+    }
+}
+
+/// Returns the type in which two temporal expressions are compared.
+fn cmp_kind(lhs: &dyn Expression, rhs: &dyn Expression) -> ExprType {
+    ExprType::try_fold(lhs.eval_type(), rhs.eval_type()).unwrap_or(lhs.eval_type())
+}
+
+/// Common expressions that evaluate into temporal values.
+pub trait Temporal: Common {
+    #![allow(clippy::wrong_self_convention)]
+    fn eq<R>(self, rhs: R) -> CommonExpr<bool::Eq>
+    where
+        Self: Sized + 'static,
+        R: Temporal + 'static,
+    {
+        let kind = cmp_kind(&self, &rhs);
+        CommonExpr(bool::Eq::new(Box::new(self), Box::new(rhs), kind))
+    }
+
+    fn neq<R>(self, rhs: R) -> CommonExpr<bool::Neq>
+    where
+        Self: Sized + 'static,
+        R: Temporal + 'static,
+    {
+        let kind = cmp_kind(&self, &rhs);
+        CommonExpr(bool::Neq::new(Box::new(self), Box::new(rhs), kind))
+    }
+
+    fn is_distinct_from<R>(self, rhs: R) -> CommonExpr<bool::IsDistinct>
+    where
+        Self: Sized + 'static,
+        R: Temporal + 'static,
+    {
+        let kind = cmp_kind(&self, &rhs);
+        CommonExpr(bool::IsDistinct::new(Box::new(self), Box::new(rhs), kind))
+    }
+
+    fn gt<R>(self, rhs: R) -> CommonExpr<bool::Gt>
+    where
+        Self: Sized + 'static,
+        R: Temporal + 'static,
+    {
+        let kind = cmp_kind(&self, &rhs);
+        CommonExpr(bool::Gt::new(Box::new(self), Box::new(rhs), kind))
+    }
+
+    fn ge<R>(self, rhs: R) -> CommonExpr<bool::Geq>
+    where
+        Self: Sized + 'static,
+        R: Temporal + 'static,
+    {
+        let kind = cmp_kind(&self, &rhs);
+        CommonExpr(bool::Geq::new(Box::new(self), Box::new(rhs), kind))
+    }
+
+    fn lt<R>(self, rhs: R) -> CommonExpr<bool::Lt>
+    where
+        Self: Sized + 'static,
+        R: Temporal + 'static,
+    {
+        let kind = cmp_kind(&self, &rhs);
+        CommonExpr(bool::Lt::new(Box::new(self), Box::new(rhs), kind))
+    }
+
+    fn leq<R>(self, rhs: R) -> CommonExpr<bool::Leq>
+    where
+        Self: Sized + 'static,
+        R: Temporal + 'static,
+    {
+        let kind = cmp_kind(&self, &rhs);
+        CommonExpr(bool::Leq::new(Box::new(self), Box::new(rhs), kind))
+    }
+
+    fn between<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Temporal + 'static,
+        U: Temporal + 'static,
+    {
+        let kind = ExprType::try_fold(cmp_kind(&self, &lower), upper.eval_type())
+            .unwrap_or(self.eval_type());
+        CommonExpr(bool::Between::new(
+            Box::new(self),
+            Box::new(lower),
+            Box::new(upper),
+            kind,
+        ))
+    }
+
+    /// `NOT BETWEEN`, whether the value lies outside of the inclusive bounds.
+    fn not_between<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Temporal + 'static,
+        U: Temporal + 'static,
+    {
+        let CommonExpr(between) = self.between(lower, upper);
+        CommonExpr(between.negated())
+    }
+
+    /// `BETWEEN SYMMETRIC`, which accepts the bounds in either order.
+    fn between_symmetric<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Temporal + 'static,
+        U: Temporal + 'static,
+    {
+        let CommonExpr(between) = self.between(lower, upper);
+        CommonExpr(between.symmetric())
+    }
+
+    fn within<Idx>(self, range: std::ops::RangeInclusive<Idx>) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        Idx: Temporal + 'static,
+    {
+        let (lower, upper) = range.into_inner();
+        self.between(lower, upper)
+    }
+
+    /// Moves the value forward by the given interval.
+    fn add(self, interval: Interval) -> CommonExpr<Shift>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Shift::new(Box::new(self), interval))
+    }
+
+    /// Moves the value backward by the given interval.
+    fn sub(self, interval: Interval) -> CommonExpr<Shift>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Shift::new(Box::new(self), -interval))
+    }
+
+    /// Truncates the value to the start of the given unit, e.g. the first day of the month.
+    fn date_trunc(self, unit: TimeUnit) -> CommonExpr<DateTrunc>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(DateTrunc::new(Box::new(self), unit))
+    }
+
+    /// Extracts the given field as a number, e.g. the month out of a date.
+    fn extract(self, field: TimeUnit) -> CommonExpr<num::Extract>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(num::Extract::new(Box::new(self), field))
+    }
+
+    /// Formats the value as text using a PostgreSQL template pattern, e.g. `"YYYY-MM"`.
+    fn to_char(self, format: impl Into<String>) -> CommonExpr<text::ToChar>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(text::ToChar::new(Box::new(self), format.into()))
+    }
+
+    /// Converts the value into text in the dialect's default format.
+    fn to_text(self) -> CommonExpr<Cast<AsText>>
+    where
+        Self: Sized + 'static,
+    {
+        let from = self.eval_type();
+        CommonExpr(Cast::new(Box::new(self), from))
+    }
+
+    /// Converts the value into a date, dropping any time of day.
+    fn to_date(self) -> CommonExpr<Cast<AsDate>>
+    where
+        Self: Sized + 'static,
+    {
+        let from = self.eval_type();
+        CommonExpr(Cast::new(Box::new(self), from))
+    }
+
+    /// Converts the value into a timestamp.
+    fn to_timestamp(self) -> CommonExpr<Cast<AsTimestamp>>
+    where
+        Self: Sized + 'static,
+    {
+        let from = self.eval_type();
+        CommonExpr(Cast::new(Box::new(self), from))
+    }
+}
+impl<T: Temporal> Temporal for CommonExpr<T> {}
+
+impl<T: Temporal + 'static> std::ops::Add<Interval> for CommonExpr<T> {
+    type Output = CommonExpr<Shift>;
+
+    fn add(self, rhs: Interval) -> Self::Output {
+        Temporal::add(self, rhs)
+    }
+}
+
+impl<T: Temporal + 'static> std::ops::Sub<Interval> for CommonExpr<T> {
+    type Output = CommonExpr<Shift>;
+
+    fn sub(self, rhs: Interval) -> Self::Output {
+        Temporal::sub(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // only `Temporal` is imported, so that its methods are picked for `col`
+    use super::{now, Date, Interval, Temporal, TimeUnit};
+    use crate::checker::{CheckError, Checker, CompiledChecker, Condition, DerivedChecker};
+    use crate::expr::{any::col, Dialect, ExprType, Expression};
+    use crate::protocol::{Client, Server, ServerHandler};
+    use crate::schema::{CompiledSchema, Table};
+
+    #[test]
+    fn display() {
+        let cutoff = col("created_date").lt(Date::new("2020-04-01"));
+        assert_eq!(
+            cutoff.display(Dialect::Postgres),
+            "created_date < DATE '2020-04-01'"
+        );
+        assert_eq!(
+            cutoff.display(Dialect::Sqlite),
+            "created_date < '2020-04-01'"
+        );
+
+        let due = col("created_at") + Interval::new(3, TimeUnit::Day);
+        assert_eq!(
+            due.display(Dialect::Postgres),
+            "(created_at + INTERVAL '3 days')"
+        );
+        assert_eq!(due.display(Dialect::MySql), "(created_at + INTERVAL 3 DAY)");
+        assert_eq!(
+            due.display(Dialect::Sqlite),
+            "datetime(created_at, '+3 days')"
+        );
+
+        let month = col("created_date").to_char("YYYY-MM");
+        assert_eq!(
+            month.display(Dialect::Postgres),
+            "TO_CHAR(created_date, 'YYYY-MM')"
+        );
+        assert_eq!(
+            month.display(Dialect::MySql),
+            "DATE_FORMAT(created_date, '%Y-%m')"
+        );
+        assert_eq!(
+            month.display(Dialect::Sqlite),
+            "strftime('%Y-%m', created_date)"
+        );
+    }
+
+    #[test]
+    fn checkers() {
+        let schema = CompiledSchema::new().with_table(
+            Table::new("events")
+                .with_column("day", ExprType::Date)
+                .with_column("at", ExprType::Timestamp)
+                .with_column("name", ExprType::Text),
+        );
+        let mut checker = CompiledChecker::new(&schema);
+        checker.accept(Condition::TableExists("events".into()).into());
+        col("day")
+            .extract(TimeUnit::Month)
+            .send_all(ExprType::Num, &mut checker);
+        assert!(checker.state().is_ok());
+        col("name")
+            .extract(TimeUnit::Month)
+            .send_all(ExprType::Num, &mut checker);
+        assert_eq!(
+            checker.state(),
+            Err(vec![CheckError::ColTypeMismatch(
+                "name".into(),
+                ExprType::Temporal,
+                ExprType::Text
+            )])
+        );
+
+        let mut checker = CompiledChecker::new(&schema);
+        checker.accept(Condition::TableExists("events".into()).into());
+        col("day")
+            .lt(Date::new("2024-01-01"))
+            .send_all(ExprType::Bool, &mut checker);
+        assert!(checker.state().is_ok());
+        col("at")
+            .lt(Date::new("2024-01-01"))
+            .send_all(ExprType::Bool, &mut checker);
+        assert!(checker.state().is_err());
+
+        let mut checker = DerivedChecker::new();
+        checker.accept(Condition::TableExists("events".into()).into());
+        col("at").lt(now()).send_all(ExprType::Bool, &mut checker);
+        assert_eq!(checker.col_type("at"), Some(ExprType::Timestamp));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::expr::prelude::*;
+
+/// The current timestamp.
+pub struct Now;
+
+pub fn now() -> CommonExpr<Now> {
+    CommonExpr(Now)
+}
+
+impl Client for Now {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        Vec::new()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Vec::new()
+    }
+}
+impl Checkable for Now {}
+impl Expression for Now {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Timestamp
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        match dialect {
+            Dialect::Postgres | Dialect::MySql => "NOW()".to_string(),
+            Dialect::Sqlite => "CURRENT_TIMESTAMP".to_string(),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+}
+impl Common for Now {}
+impl Temporal for Now {}
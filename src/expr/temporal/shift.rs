@@ -0,0 +1,63 @@
+use super::Interval;
+use crate::expr::prelude::*;
+
+/// A temporal value moved by an interval, i.e. `x + INTERVAL '3 days'`.
+pub struct Shift {
+    inner: Box<dyn Expression>, // Temporal
+    interval: Interval,
+}
+
+impl Shift {
+    pub fn new(inner: Box<dyn Expression>, interval: Interval) -> Self {
+        Self { inner, interval }
+    }
+}
+
+impl Client for Shift {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), ExprType::Temporal)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, self.eval_type())
+    }
+}
+impl Checkable for Shift {}
+impl Expression for Shift {
+    /// Times stay within the day, while dates gain a time of day.
+    fn eval_type(&self) -> ExprType {
+        match self.inner.eval_type() {
+            ExprType::Time => ExprType::Time,
+            ExprType::Any => ExprType::Any,
+            _ => ExprType::Timestamp,
+        }
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let inner = self.inner.display(dialect);
+        match dialect {
+            Dialect::Postgres | Dialect::MySql => {
+                format!("({inner} + {})", self.interval.display(dialect))
+            }
+            Dialect::Sqlite => {
+                let function = match self.eval_type() {
+                    ExprType::Time => "time",
+                    _ => "datetime",
+                };
+                format!("{function}({inner}, {})", self.interval.modifier())
+            }
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+}
+impl Common for Shift {}
+impl Temporal for Shift {}
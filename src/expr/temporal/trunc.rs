@@ -0,0 +1,77 @@
+use super::TimeUnit;
+use crate::expr::prelude::*;
+
+/// A temporal value truncated to the start of a unit, e.g. the first day of its month.
+pub struct DateTrunc {
+    inner: Box<dyn Expression>, // Temporal
+    unit: TimeUnit,
+}
+
+impl DateTrunc {
+    pub fn new(inner: Box<dyn Expression>, unit: TimeUnit) -> Self {
+        Self { inner, unit }
+    }
+}
+
+impl Client for DateTrunc {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), ExprType::Temporal)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Timestamp)
+    }
+}
+impl Checkable for DateTrunc {}
+impl Expression for DateTrunc {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Timestamp
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let x = self.inner.display(dialect);
+        match dialect {
+            Dialect::Postgres => format!(
+                "DATE_TRUNC('{}', {x})",
+                self.unit.keyword().to_lowercase()
+            ),
+            Dialect::MySql => match self.unit {
+                TimeUnit::Year => format!("TIMESTAMP(DATE_FORMAT({x}, '%Y-01-01'))"),
+                TimeUnit::Quarter => {
+                    format!("TIMESTAMP(MAKEDATE(YEAR({x}), 1) + INTERVAL QUARTER({x}) - 1 QUARTER)")
+                }
+                TimeUnit::Month => format!("TIMESTAMP(DATE_FORMAT({x}, '%Y-%m-01'))"),
+                TimeUnit::Week => format!("TIMESTAMP(DATE_SUB(DATE({x}), INTERVAL WEEKDAY({x}) DAY))"),
+                TimeUnit::Day => format!("TIMESTAMP(DATE({x}))"),
+                TimeUnit::Hour => format!("TIMESTAMP(DATE_FORMAT({x}, '%Y-%m-%d %H:00:00'))"),
+                TimeUnit::Minute => format!("TIMESTAMP(DATE_FORMAT({x}, '%Y-%m-%d %H:%i:00'))"),
+                TimeUnit::Second => format!("TIMESTAMP(DATE_FORMAT({x}, '%Y-%m-%d %H:%i:%s'))"),
+            },
+            Dialect::Sqlite => match self.unit {
+                TimeUnit::Year => format!("datetime({x}, 'start of year')"),
+                TimeUnit::Quarter => format!(
+                    "datetime({x}, 'start of month', '-' || ((strftime('%m', {x}) - 1) % 3) || ' months')"
+                ),
+                TimeUnit::Month => format!("datetime({x}, 'start of month')"),
+                // weeks start on Monday, as in the other dialects
+                TimeUnit::Week => format!("datetime({x}, '-6 days', 'weekday 1', 'start of day')"),
+                TimeUnit::Day => format!("datetime({x}, 'start of day')"),
+                TimeUnit::Hour => format!("strftime('%Y-%m-%d %H:00:00', {x})"),
+                TimeUnit::Minute => format!("strftime('%Y-%m-%d %H:%M:00', {x})"),
+                TimeUnit::Second => format!("strftime('%Y-%m-%d %H:%M:%S', {x})"),
+            },
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+}
+impl Common for DateTrunc {}
+impl Temporal for DateTrunc {}
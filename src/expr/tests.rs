@@ -0,0 +1,352 @@
+//! Walks every expression node through a recording server, asserting the exact message stream
+//! that it produces. Every `Client` method is called on every node along the way, so that none
+//! of them can panic.
+
+use super::agg::{
+    array_agg, avg, bool_and, bool_or, count, count_all, max, min, percentile_cont, string_agg, sum,
+};
+use super::any::{col, Case, CaseBranch, Coalesce, NULL};
+use super::temporal::{now, Date, Interval, Shift, Time, TimeUnit, Timestamp};
+use super::window::{lag, lead, rank, row_number, Window, WindowFunction};
+use crate::checker::{Condition, Message, Signal};
+use crate::expr::prelude::*;
+use crate::multi;
+use std::num::NonZeroU32;
+
+/// A server that records every message it receives.
+#[derive(Default)]
+struct Recorder {
+    messages: Vec<Message>,
+}
+
+impl Server for Recorder {
+    type Msg = Message;
+
+    fn accept(&mut self, msg: Self::Msg) {
+        self.messages.push(msg);
+    }
+}
+
+/// Calls `children` and `messages` on every node of the tree.
+fn walk(client: &dyn Client<Ctx = ExprType, Msg = Message>, ctx: ExprType) {
+    client.messages(ctx);
+    for (child, ctx) in client.children(ctx) {
+        walk(child, ctx);
+    }
+}
+
+/// Returns the messages sent by the expression within the given context.
+pub(super) fn record(expr: &dyn Expression, ctx: ExprType) -> Vec<Message> {
+    walk(expr, ctx);
+    for dialect in Dialect::ALL {
+        expr.display(dialect);
+        expr.display_negated(dialect);
+    }
+    expr.nullability(&|_| Nullability::Nullable);
+
+    let mut recorder = Recorder::default();
+    expr.send_all(ctx, &mut recorder);
+    recorder.messages
+}
+
+fn col_msg(name: &str, kind: ExprType) -> Message {
+    Condition::ColExistsAndType(name.to_string(), kind).into()
+}
+
+fn start() -> Message {
+    Signal::StartLink.into()
+}
+
+fn end() -> Message {
+    Signal::EndLink.into()
+}
+
+fn mismatch(expected: ExprType, found: ExprType) -> Message {
+    Signal::TypeMismatch((expected, found)).into()
+}
+
+use ExprType::{Any, Bool, Num, Text};
+
+#[test]
+fn any_nodes() {
+    assert_eq!(record(&col("a"), Any), [col_msg("a", Any)]);
+    assert_eq!(record(&NULL, Any), []);
+    assert_eq!(record(&col("a").alias("b"), Num), [col_msg("a", Num)]);
+    assert_eq!(
+        record(&case!(col("a") => 1, _ => col("b"),), Any),
+        [col_msg("a", Bool), col_msg("b", Any)]
+    );
+    assert_eq!(
+        record(&case!(col("a") => 1, _ => "b",), Any),
+        [mismatch(Num, Text)]
+    );
+    assert_eq!(
+        record(&coalesce![col("a"), col("b")], Any),
+        [start(), col_msg("a", Any), col_msg("b", Any), end()]
+    );
+    assert_eq!(
+        record(&Common::nullif(col("a"), 0), Any),
+        [col_msg("a", Num)]
+    );
+    assert_eq!(record(&col("a").is_null(), Bool), [col_msg("a", Any)]);
+    assert_eq!(record(&col("a").is_not_null(), Bool), [col_msg("a", Any)]);
+}
+
+#[test]
+fn bool_nodes() {
+    assert_eq!(record(&true, Bool), []);
+    assert_eq!(record(&true, Num), [mismatch(Num, Bool)]);
+    assert_eq!(
+        record(&(Boolean::eq(col("a"), true) & !col("b")), Bool),
+        [col_msg("a", Bool), col_msg("b", Bool)]
+    );
+    assert_eq!(
+        record(&(Boolean::eq(col("a"), true) | col("b")), Bool),
+        [col_msg("a", Bool), col_msg("b", Bool)]
+    );
+    assert_eq!(
+        record(&Numeric::eq(col("a"), NULL), Bool),
+        [col_msg("a", Num), Signal::NullComparison.into()]
+    );
+    assert_eq!(
+        record(&Numeric::neq(col("a"), 1), Bool),
+        [col_msg("a", Num)]
+    );
+    assert_eq!(
+        record(&Numeric::is_distinct_from(col("a"), NULL), Bool),
+        [col_msg("a", Num)]
+    );
+    assert_eq!(
+        record(&Temporal::gt(col("a"), col("b")), Bool),
+        [start(), col_msg("a", Any), col_msg("b", Any), end()]
+    );
+    assert_eq!(record(&Numeric::ge(col("a"), 1), Bool), [col_msg("a", Num)]);
+    assert_eq!(
+        record(&Textual::lt(col("a"), "b"), Bool),
+        [col_msg("a", Text)]
+    );
+    assert_eq!(
+        record(&Numeric::leq(col("a"), 1), Bool),
+        [col_msg("a", Num)]
+    );
+    assert_eq!(
+        record(&Numeric::between(col("a"), 1, NULL), Bool),
+        [col_msg("a", Num), Signal::NullComparison.into()]
+    );
+    assert_eq!(
+        record(&Temporal::between(col("a"), col("b"), col("c")), Bool),
+        [
+            start(),
+            col_msg("a", Any),
+            col_msg("b", Any),
+            col_msg("c", Any),
+            end()
+        ]
+    );
+    assert_eq!(
+        record(&Textual::like(col("a"), col("b")), Bool),
+        [col_msg("a", Text), col_msg("b", Text)]
+    );
+    assert_eq!(
+        record(&Textual::matches_regex(col("a"), "^b"), Bool),
+        [col_msg("a", Text)]
+    );
+    assert_eq!(
+        record(&Textual::like(col("a"), "b"), Num),
+        [col_msg("a", Text), mismatch(Num, Bool)]
+    );
+}
+
+#[test]
+fn num_nodes() {
+    assert_eq!(record(&1, Num), []);
+    assert_eq!(record(&1, Text), [mismatch(Text, Num)]);
+    for expr in [
+        Box::new(col("a") + 1) as Box<dyn Expression>,
+        Box::new(col("a") - 1),
+        Box::new(col("a") * 1),
+        Box::new(col("a") / 1),
+        Box::new(col("a") % 2),
+        Box::new(Numeric::power(col("a"), 1)),
+        Box::new(Numeric::round_to(col("a"), 1)),
+        Box::new(Numeric::greatest(col("a"), 1)),
+        Box::new(Numeric::least(col("a"), 1)),
+    ] {
+        assert_eq!(record(expr.as_ref(), Num), [col_msg("a", Num)]);
+    }
+    for expr in [
+        Box::new(-col("a")) as Box<dyn Expression>,
+        Box::new(Numeric::abs(col("a"))),
+        Box::new(Numeric::round(col("a"))),
+        Box::new(Numeric::floor(col("a"))),
+        Box::new(Numeric::ceil(col("a"))),
+        Box::new(Numeric::sqrt(col("a"))),
+        Box::new(Numeric::ln(col("a"))),
+    ] {
+        assert_eq!(record(expr.as_ref(), Num), [col_msg("a", Num)]);
+    }
+    assert_eq!(record(&Textual::len(col("a")), Num), [col_msg("a", Text)]);
+    assert_eq!(
+        record(&Textual::position(col("a"), "b"), Num),
+        [col_msg("a", Text)]
+    );
+    assert_eq!(
+        record(&Temporal::extract(col("a"), TimeUnit::Day), Num),
+        [col_msg("a", ExprType::Temporal)]
+    );
+    assert_eq!(
+        record(&Textual::len(col("a")), Text),
+        [col_msg("a", Text), mismatch(Text, Num)]
+    );
+}
+
+#[test]
+fn text_nodes() {
+    assert_eq!(record(&"a", Text), []);
+    assert_eq!(record(&String::from("a"), Bool), [mismatch(Bool, Text)]);
+    for expr in [
+        Box::new(col("a") + "b") as Box<dyn Expression>,
+        Box::new(Textual::lower(col("a"))),
+        Box::new(Textual::upper(col("a"))),
+        Box::new(Textual::trim(col("a"))),
+        Box::new(Textual::substring(col("a"), 1, 2)),
+        Box::new(Textual::replace(col("a"), "b", "c")),
+        Box::new(Textual::split_part(col("a"), ",", NonZeroU32::MIN)),
+        Box::new(Textual::collate(col("a"), "C")),
+    ] {
+        assert_eq!(record(expr.as_ref(), Text), [col_msg("a", Text)]);
+    }
+    assert_eq!(
+        record(&Textual::substring_from(col("a"), col("b")), Text),
+        [col_msg("a", Text), col_msg("b", Num)]
+    );
+    assert_eq!(
+        record(&Temporal::to_char(col("a"), "YYYY"), Text),
+        [col_msg("a", ExprType::Temporal)]
+    );
+    assert_eq!(
+        record(&Numeric::to_text(col("a")), Text),
+        [col_msg("a", Num)]
+    );
+}
+
+#[test]
+fn temporal_nodes() {
+    assert_eq!(record(&Date::new("2024-01-01"), ExprType::Date), []);
+    assert_eq!(record(&Time::new("12:00"), ExprType::Time), []);
+    assert_eq!(
+        record(&Timestamp::new("2024-01-01 12:00"), ExprType::Date),
+        [mismatch(ExprType::Date, ExprType::Timestamp)]
+    );
+    assert_eq!(record(&now(), ExprType::Timestamp), []);
+    assert_eq!(record(&Interval::new(1, TimeUnit::Day), Any), []);
+    assert_eq!(record(&(now() + Interval::new(1, TimeUnit::Day)), Any), []);
+    assert_eq!(
+        record(&Temporal::date_trunc(col("a"), TimeUnit::Month), Any),
+        [col_msg("a", ExprType::Temporal)]
+    );
+    assert_eq!(
+        record(&(col("a") + Interval::new(1, TimeUnit::Day)), Any),
+        [col_msg("a", ExprType::Temporal)]
+    );
+    assert_eq!(
+        record(&Temporal::date_trunc(col("a"), TimeUnit::Month), Num),
+        [
+            col_msg("a", ExprType::Temporal),
+            mismatch(Num, ExprType::Timestamp)
+        ]
+    );
+    assert_eq!(
+        record(
+            &Shift::new(
+                Box::new(Date::new("2024-01-01")),
+                Interval::new(1, TimeUnit::Day)
+            ),
+            ExprType::Date
+        ),
+        [mismatch(ExprType::Date, ExprType::Timestamp)]
+    );
+    assert_eq!(
+        record(
+            &Shift::new(
+                Box::new(Time::new("12:00")),
+                Interval::new(1, TimeUnit::Hour)
+            ),
+            ExprType::Time
+        ),
+        []
+    );
+    assert_eq!(
+        record(&Textual::to_date(col("a")), ExprType::Date),
+        [col_msg("a", Text)]
+    );
+}
+
+#[test]
+fn agg_nodes() {
+    assert_eq!(record(&count_all(), Num), []);
+    assert_eq!(
+        record(&count(col("a")).filter(Numeric::gt(col("b"), 1)), Num),
+        [col_msg("a", Any), col_msg("b", Num)]
+    );
+    assert_eq!(record(&sum(col("a")).distinct(), Num), [col_msg("a", Num)]);
+    assert_eq!(record(&avg(col("a")), Num), [col_msg("a", Num)]);
+    assert_eq!(record(&min(col("a")), Any), [col_msg("a", Any)]);
+    assert_eq!(record(&max(col("a")), Text), [col_msg("a", Text)]);
+    assert_eq!(record(&bool_and(col("a")), Bool), [col_msg("a", Bool)]);
+    assert_eq!(record(&bool_or(col("a")), Bool), [col_msg("a", Bool)]);
+    assert_eq!(
+        record(&string_agg(col("a"), ",").order_by(col("b")), Text),
+        [col_msg("a", Text), col_msg("b", Any)]
+    );
+    assert_eq!(record(&array_agg(col("a")), Any), [col_msg("a", Any)]);
+    assert_eq!(
+        record(&percentile_cont(0.5, col("a")), Num),
+        [col_msg("a", Num)]
+    );
+}
+
+#[test]
+fn window_nodes() {
+    let window = || Window::new().partition_by(col("p")).order_by(col("o"));
+    assert_eq!(
+        record(&row_number().over(window()), Num),
+        [
+            col_msg("p", Any),
+            col_msg("o", Any),
+            Signal::WindowFunction.into()
+        ]
+    );
+    assert_eq!(
+        record(&rank().over(Window::named("w")), Num),
+        [
+            Condition::WindowExists("w".into()).into(),
+            Signal::WindowFunction.into()
+        ]
+    );
+    assert_eq!(
+        record(&lag(col("a"), 1).over(window()), Any),
+        [
+            col_msg("a", Any),
+            col_msg("p", Any),
+            col_msg("o", Any),
+            Signal::WindowFunction.into()
+        ]
+    );
+    assert_eq!(
+        record(&lead(col("a"), 2).over(Window::new()), Num),
+        [col_msg("a", Num), Signal::WindowFunction.into()]
+    );
+    assert_eq!(
+        record(&sum(col("a")).over(Window::new()), Num),
+        [col_msg("a", Num), Signal::WindowFunction.into()]
+    );
+}
+
+#[test]
+fn unique_nodes() {
+    assert_eq!(
+        record(&multi![col("a"), Textual::len(col("b"))], Any),
+        [col_msg("a", Any), col_msg("b", Text)]
+    );
+}
@@ -0,0 +1,82 @@
+use crate::expr::prelude::*;
+
+/// A text that is compared and ordered by the given collation.
+///
+/// Collation names are specific to each dialect and database. They are rendered as quoted
+/// identifiers, so that any name is taken literally.
+pub struct Collate {
+    inner: Box<dyn Expression>, // Textual
+    collation: String,
+}
+
+impl Collate {
+    pub fn new(inner: Box<dyn Expression>, collation: String) -> Self {
+        Self { inner, collation }
+    }
+}
+
+impl Client for Collate {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), ExprType::Text)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for Collate {}
+impl Expression for Collate {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let x = self.inner.display(dialect);
+        let collation = match dialect {
+            // quoting keeps the case, collations being case-sensitive identifiers in PostgreSQL
+            Dialect::Postgres | Dialect::Sqlite => {
+                format!("\"{}\"", str::replace(&self.collation, '"', "\"\""))
+            }
+            // double quotes delimit strings in MySQL
+            Dialect::MySql => format!("`{}`", str::replace(&self.collation, '`', "``")),
+        };
+        format!("{x} COLLATE {collation}")
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+}
+impl Common for Collate {}
+impl Textual for Collate {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn display() {
+        let name = Textual::collate(col("name"), "NOCASE");
+        assert_eq!(name.display(Dialect::Sqlite), "name COLLATE \"NOCASE\"");
+        assert_eq!(name.display(Dialect::MySql), "name COLLATE `NOCASE`");
+
+        // the name can't escape its quotes
+        let injected = Textual::collate(col("name"), "C\" = 'x' --");
+        assert_eq!(
+            injected.display(Dialect::Postgres),
+            "name COLLATE \"C\"\" = 'x' --\""
+        );
+        let injected = Textual::collate(col("name"), "utf8mb4_bin` = 'x' --");
+        assert_eq!(
+            injected.display(Dialect::MySql),
+            "name COLLATE `utf8mb4_bin`` = 'x' --`"
+        );
+    }
+}
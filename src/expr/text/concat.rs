@@ -0,0 +1,137 @@
+use crate::expr::prelude::*;
+use crate::row::FromValue;
+
+/// Concatenation of textual expressions.
+pub struct Concat {
+    exprs: Vec<Box<dyn Expression>>, // Textual
+}
+
+impl Concat {
+    pub fn new(exprs: Vec<Box<dyn Expression>>) -> Self {
+        Self { exprs }
+    }
+
+    pub fn push(&mut self, expr: Box<dyn Expression>) {
+        self.exprs.push(expr);
+    }
+}
+
+impl Client for Concat {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        self.exprs
+            .iter()
+            .map(|e| (e.as_ref() as _, ExprType::Text))
+            .collect()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for Concat {}
+impl Expression for Concat {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let exprs: Vec<_> = self.exprs.iter().map(|e| e.display(dialect)).collect();
+        match dialect {
+            Dialect::Postgres | Dialect::Sqlite => format!("({})", exprs.join(" || ")),
+            // `||` is the logical OR operator in MySQL
+            Dialect::MySql => format!("CONCAT({})", exprs.join(", ")),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.exprs
+            .iter()
+            .map(|e| e.nullability(cols))
+            .fold(Nullability::NotNull, Nullability::propagate)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let mut out = String::new();
+        for expr in &self.exprs {
+            match Option::<String>::from_value(&expr.eval(cols)?)? {
+                Some(s) => out += &s,
+                None => return Ok(Value::Null),
+            }
+        }
+        Ok(Value::Text(out))
+    }
+}
+impl Common for Concat {}
+impl Textual for Concat {}
+
+// `CommonExpr<L> + CommonExpr<R>` can't be implemented for all textual expressions, as it would
+// overlap with the numeric implementation. Instead `+` is implemented with literals on either
+// side, and for extending an existing concatenation.
+
+impl<L: Textual + 'static> std::ops::Add<&'static str> for CommonExpr<L> {
+    type Output = CommonExpr<Concat>;
+
+    fn add(self, rhs: &'static str) -> Self::Output {
+        Textual::concat(self, rhs)
+    }
+}
+
+impl<L: Textual + 'static> std::ops::Add<String> for CommonExpr<L> {
+    type Output = CommonExpr<Concat>;
+
+    fn add(self, rhs: String) -> Self::Output {
+        Textual::concat(self, rhs)
+    }
+}
+
+impl<R: Textual + 'static> std::ops::Add<CommonExpr<R>> for &'static str {
+    type Output = CommonExpr<Concat>;
+
+    fn add(self, rhs: CommonExpr<R>) -> Self::Output {
+        Textual::concat(self, rhs)
+    }
+}
+
+impl<R: Textual + 'static> std::ops::Add<CommonExpr<R>> for String {
+    type Output = CommonExpr<Concat>;
+
+    fn add(self, rhs: CommonExpr<R>) -> Self::Output {
+        Textual::concat(self, rhs)
+    }
+}
+
+impl<R: Textual + 'static> std::ops::Add<CommonExpr<R>> for CommonExpr<Concat> {
+    type Output = CommonExpr<Concat>;
+
+    fn add(self, rhs: CommonExpr<R>) -> Self::Output {
+        Textual::concat(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn display() {
+        let version = "v" + col("version") + "_x86_64-linux";
+        assert_eq!(
+            version.display(Dialect::Postgres),
+            "('v' || version || '_x86_64-linux')"
+        );
+        assert_eq!(
+            version.display(Dialect::MySql),
+            "CONCAT('v', version, '_x86_64-linux')"
+        );
+
+        let quoted = col("name") + "'s" + col("suffix");
+        assert_eq!(quoted.display(Dialect::Sqlite), "(name || '''s' || suffix)");
+    }
+}
@@ -0,0 +1,60 @@
+use crate::expr::prelude::*;
+use crate::row::FromValue;
+
+/// Defines a node that converts the letter case of a text.
+///
+/// Arguments are ($struct, $function)
+macro_rules! impl_letter_case {
+    ($struct:ident, $function:expr, $convert:path) => {
+        pub struct $struct {
+            inner: Box<dyn Expression>, // Textual
+        }
+
+        impl $struct {
+            pub fn new(inner: Box<dyn Expression>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl Client for $struct {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                vec![(self.inner.as_ref(), ExprType::Text)]
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Signal::ctx_mismatch(ctx, ExprType::Text)
+            }
+        }
+        impl Checkable for $struct {}
+        impl Expression for $struct {
+            fn eval_type(&self) -> ExprType {
+                ExprType::Text
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                format!("{}({})", $function, self.inner.display(dialect))
+            }
+
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                self.inner.nullability(cols)
+            }
+
+            // only ASCII letters are converted, like SQLite does
+            fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+                let inner = Option::<String>::from_value(&self.inner.eval(cols)?)?;
+                Ok(inner.map_or(Value::Null, |s| Value::Text($convert(&s))))
+            }
+        }
+        impl Common for $struct {}
+        impl Textual for $struct {}
+    };
+}
+
+impl_letter_case!(Lower, "LOWER", str::to_ascii_lowercase);
+impl_letter_case!(Upper, "UPPER", str::to_ascii_uppercase);
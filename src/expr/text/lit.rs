@@ -0,0 +1,45 @@
+use super::quote;
+use crate::expr::prelude::*;
+
+macro_rules! impl_textual_lit {
+    ($t:ty) => {
+        impl Client for $t {
+            type Ctx = ExprType;
+            type Msg = Message;
+
+            fn children(
+                &self,
+                ctx: Self::Ctx,
+            ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+                Vec::new()
+            }
+
+            fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+                Signal::ctx_mismatch(ctx, ExprType::Text)
+            }
+        }
+        impl Checkable for $t {}
+        impl Expression for $t {
+            fn eval_type(&self) -> ExprType {
+                ExprType::Text
+            }
+
+            fn display(&self, dialect: Dialect) -> String {
+                quote(self, dialect)
+            }
+
+            fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+                Nullability::NotNull
+            }
+
+            fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+                Ok(Value::Text(self.to_string()))
+            }
+        }
+        impl Common for $t {}
+        impl Textual for $t {}
+    };
+}
+
+impl_textual_lit!(&'static str);
+impl_textual_lit!(String);
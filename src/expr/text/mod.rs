@@ -1,11 +1,79 @@
 //! Expressions that evaluate into textual values
+use std::num::NonZeroU32;
+
 use crate::expr::prelude::*;
 
+use super::cast::{AsBool, AsDate, AsNum, AsTime, AsTimestamp, Cast};
 use super::{bool, num};
 
-/// Marker trait for expressions that evaluate into boolean values
+pub mod to_char;
+#[doc(inline)]
+pub use to_char::ToChar;
+
+pub mod lit;
+
+pub mod collate;
+#[doc(inline)]
+pub use collate::Collate;
+
+pub mod letter_case;
+#[doc(inline)]
+pub use letter_case::{Lower, Upper};
+
+pub mod trim;
+#[doc(inline)]
+pub use trim::{Trim, TrimSide};
+
+pub mod substring;
+#[doc(inline)]
+pub use substring::Substring;
+
+pub mod replace;
+#[doc(inline)]
+pub use replace::Replace;
+
+pub mod split_part;
+#[doc(inline)]
+pub use split_part::SplitPart;
+
+pub mod concat;
+#[doc(inline)]
+pub use concat::Concat;
+
+/// Returns the string as an SQL string literal, escaping any quotes.
+pub(crate) fn quote(s: &str, dialect: Dialect) -> String {
+    let s = s.replace('\'', "''");
+    match dialect {
+        // backslashes are escape characters within MySQL strings
+        Dialect::MySql => format!("'{}'", str::replace(&s, '\\', "\\\\")),
+        Dialect::Postgres | Dialect::Sqlite => format!("'{s}'"),
+    }
+}
+
+/// The escape character used within `LIKE` patterns built from user input.
+pub(crate) const LIKE_ESCAPE: char = '!';
+
+/// Escapes the `LIKE` wildcards within the string, so that it is matched literally.
+pub(crate) fn escape_like(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '%' | '_') || c == LIKE_ESCAPE {
+            out.push(LIKE_ESCAPE);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Marker trait for expressions that evaluate into textual values
+///
+/// Pattern matches follow the dialect's case sensitivity: they are case-sensitive in PostgreSQL,
+/// case-insensitive for ASCII letters in SQLite, and depend on the collation in MySQL, whose
+/// default collation is case-insensitive. Use [`Textual::ilike`] or compare [`Textual::lower`]
+/// texts for case-insensitive matches everywhere.
 pub trait Textual: Common {
-    #![allow(clippy::len_without_is_empty)]
+    #![allow(clippy::len_without_is_empty, clippy::wrong_self_convention)]
+    /// Returns the number of characters.
     fn len(self) -> CommonExpr<num::Len>
     where
         Self: Sized + 'static,
@@ -13,6 +81,180 @@ pub trait Textual: Common {
         CommonExpr(num::Len::new(Box::new(self)))
     }
 
+    fn lower(self) -> CommonExpr<Lower>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Lower::new(Box::new(self)))
+    }
+
+    fn upper(self) -> CommonExpr<Upper>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Upper::new(Box::new(self)))
+    }
+
+    /// Removes leading and trailing whitespace.
+    fn trim(self) -> CommonExpr<Trim>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Trim::new(Box::new(self), TrimSide::Both))
+    }
+
+    /// Removes leading whitespace.
+    fn trim_start(self) -> CommonExpr<Trim>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Trim::new(Box::new(self), TrimSide::Leading))
+    }
+
+    /// Removes trailing whitespace.
+    fn trim_end(self) -> CommonExpr<Trim>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Trim::new(Box::new(self), TrimSide::Trailing))
+    }
+
+    /// Returns `len` characters starting at the 1-based position `start`.
+    fn substring<S, L>(self, start: S, len: L) -> CommonExpr<Substring>
+    where
+        Self: Sized + 'static,
+        S: Numeric + 'static,
+        L: Numeric + 'static,
+    {
+        CommonExpr(Substring::new(
+            Box::new(self),
+            Box::new(start),
+            Some(Box::new(len)),
+        ))
+    }
+
+    /// Returns every character starting at the 1-based position `start`.
+    fn substring_from<S>(self, start: S) -> CommonExpr<Substring>
+    where
+        Self: Sized + 'static,
+        S: Numeric + 'static,
+    {
+        CommonExpr(Substring::new(Box::new(self), Box::new(start), None))
+    }
+
+    /// Replaces every occurrence of `from` with `to`.
+    fn replace<F, T>(self, from: F, to: T) -> CommonExpr<Replace>
+    where
+        Self: Sized + 'static,
+        F: Textual + 'static,
+        T: Textual + 'static,
+    {
+        CommonExpr(Replace::new(Box::new(self), Box::new(from), Box::new(to)))
+    }
+
+    /// Splits the text on `delimiter` and returns the 1-based `index`-th part.
+    fn split_part<D>(self, delimiter: D, index: NonZeroU32) -> CommonExpr<SplitPart>
+    where
+        Self: Sized + 'static,
+        D: Textual + 'static,
+    {
+        CommonExpr(SplitPart::new(Box::new(self), Box::new(delimiter), index))
+    }
+
+    /// Returns the 1-based position of `needle` within the text, or 0 if it is missing.
+    fn position<N>(self, needle: N) -> CommonExpr<num::Position>
+    where
+        Self: Sized + 'static,
+        N: Textual + 'static,
+    {
+        CommonExpr(num::Position::new(Box::new(self), Box::new(needle)))
+    }
+
+    /// Concatenates both expressions, see also the `+` operator.
+    fn concat<R>(self, rhs: R) -> CommonExpr<Concat>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        // extend existing concatenations instead of nesting them
+        let lhs: Box<dyn std::any::Any> = Box::new(self);
+        match lhs.downcast::<CommonExpr<Concat>>() {
+            Ok(mut concat) => {
+                concat.0.push(Box::new(rhs));
+                *concat
+            }
+            Err(lhs) => {
+                let lhs = lhs.downcast::<Self>().expect("`lhs` is of type `Self`");
+                CommonExpr(Concat::new(vec![lhs, Box::new(rhs)]))
+            }
+        }
+    }
+
+    /// Matches the text against a `LIKE` pattern, where `%` matches any sequence of characters
+    /// and `_` matches any single character.
+    ///
+    /// The match is case-sensitive in PostgreSQL only, see [`Textual`].
+    fn like<R>(self, pattern: R) -> CommonExpr<bool::Like>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::Like::new(Box::new(self), Box::new(pattern), false))
+    }
+
+    /// Case-insensitive version of [`Textual::like`].
+    fn ilike<R>(self, pattern: R) -> CommonExpr<bool::Like>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::Like::new(Box::new(self), Box::new(pattern), true))
+    }
+
+    /// Whether the text starts with the prefix, which is matched literally.
+    ///
+    /// Like [`Textual::like`], this follows the dialect's case sensitivity.
+    fn starts_with(self, prefix: &str) -> CommonExpr<bool::Like>
+    where
+        Self: Sized + 'static,
+    {
+        let pattern = format!("{}%", escape_like(prefix));
+        CommonExpr(bool::Like::escaped(Box::new(self), pattern))
+    }
+
+    /// Whether the text ends with the suffix, which is matched literally.
+    ///
+    /// Like [`Textual::like`], this follows the dialect's case sensitivity.
+    fn ends_with(self, suffix: &str) -> CommonExpr<bool::Like>
+    where
+        Self: Sized + 'static,
+    {
+        let pattern = format!("%{}", escape_like(suffix));
+        CommonExpr(bool::Like::escaped(Box::new(self), pattern))
+    }
+
+    /// Whether the text contains the infix, which is matched literally.
+    ///
+    /// Like [`Textual::like`], this follows the dialect's case sensitivity.
+    fn contains(self, infix: &str) -> CommonExpr<bool::Like>
+    where
+        Self: Sized + 'static,
+    {
+        let pattern = format!("%{}%", escape_like(infix));
+        CommonExpr(bool::Like::escaped(Box::new(self), pattern))
+    }
+
+    /// Matches the text against a regular expression.
+    ///
+    /// Not supported by SQLite, which doesn't define the `regexp()` function by default.
+    fn matches_regex<R>(self, pattern: R) -> CommonExpr<bool::Regex>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::Regex::new(Box::new(self), Box::new(pattern)))
+    }
+
     fn eq<R>(self, rhs: R) -> CommonExpr<bool::Eq>
     where
         Self: Sized + 'static,
@@ -32,5 +274,150 @@ pub trait Textual: Common {
             ExprType::Text,
         ))
     }
+
+    fn is_distinct_from<R>(self, rhs: R) -> CommonExpr<bool::IsDistinct>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::IsDistinct::new(
+            Box::new(self),
+            Box::new(rhs),
+            ExprType::Text,
+        ))
+    }
+
+    fn gt<R>(self, rhs: R) -> CommonExpr<bool::Gt>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::Gt::new(Box::new(self), Box::new(rhs), ExprType::Text))
+    }
+
+    fn ge<R>(self, rhs: R) -> CommonExpr<bool::Geq>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::Geq::new(
+            Box::new(self),
+            Box::new(rhs),
+            ExprType::Text,
+        ))
+    }
+
+    fn lt<R>(self, rhs: R) -> CommonExpr<bool::Lt>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::Lt::new(Box::new(self), Box::new(rhs), ExprType::Text))
+    }
+
+    fn leq<R>(self, rhs: R) -> CommonExpr<bool::Leq>
+    where
+        Self: Sized + 'static,
+        R: Textual + 'static,
+    {
+        CommonExpr(bool::Leq::new(
+            Box::new(self),
+            Box::new(rhs),
+            ExprType::Text,
+        ))
+    }
+
+    fn between<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Textual + 'static,
+        U: Textual + 'static,
+    {
+        CommonExpr(bool::Between::new(
+            Box::new(self),
+            Box::new(lower),
+            Box::new(upper),
+            ExprType::Text,
+        ))
+    }
+
+    /// `NOT BETWEEN`, whether the value lies outside of the inclusive bounds.
+    fn not_between<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Textual + 'static,
+        U: Textual + 'static,
+    {
+        let CommonExpr(between) = self.between(lower, upper);
+        CommonExpr(between.negated())
+    }
+
+    /// `BETWEEN SYMMETRIC`, which accepts the bounds in either order.
+    fn between_symmetric<L, U>(self, lower: L, upper: U) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        L: Textual + 'static,
+        U: Textual + 'static,
+    {
+        let CommonExpr(between) = self.between(lower, upper);
+        CommonExpr(between.symmetric())
+    }
+
+    fn within<Idx>(self, range: std::ops::RangeInclusive<Idx>) -> CommonExpr<bool::Between>
+    where
+        Self: Sized + 'static,
+        Idx: Textual + 'static,
+    {
+        let (lower, upper) = range.into_inner();
+        self.between(lower, upper)
+    }
+
+    /// Compares the text using the given collation, e.g. `"C"` or `NOCASE`.
+    fn collate(self, collation: &str) -> CommonExpr<Collate>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Collate::new(Box::new(self), collation.to_string()))
+    }
+
+    /// Parses the text as a number.
+    fn to_num(self) -> CommonExpr<Cast<AsNum>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Text))
+    }
+
+    /// Parses the text as a boolean.
+    fn to_bool(self) -> CommonExpr<Cast<AsBool>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Text))
+    }
+
+    /// Parses the text as a date.
+    fn to_date(self) -> CommonExpr<Cast<AsDate>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Text))
+    }
+
+    /// Parses the text as a time of day.
+    fn to_time(self) -> CommonExpr<Cast<AsTime>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Text))
+    }
+
+    /// Parses the text as a timestamp.
+    fn to_timestamp(self) -> CommonExpr<Cast<AsTimestamp>>
+    where
+        Self: Sized + 'static,
+    {
+        CommonExpr(Cast::new(Box::new(self), ExprType::Text))
+    }
 }
 impl<T: Textual> Textual for CommonExpr<T> {}
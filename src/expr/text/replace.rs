@@ -0,0 +1,76 @@
+use crate::expr::prelude::*;
+use crate::row::FromValue;
+
+/// A text with every occurrence of a substring replaced.
+pub struct Replace {
+    inner: Box<dyn Expression>, // Textual
+    from: Box<dyn Expression>,  // Textual
+    to: Box<dyn Expression>,    // Textual
+}
+
+impl Replace {
+    pub fn new(
+        inner: Box<dyn Expression>,
+        from: Box<dyn Expression>,
+        to: Box<dyn Expression>,
+    ) -> Self {
+        Self { inner, from, to }
+    }
+}
+
+impl Client for Replace {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![
+            (self.inner.as_ref(), ExprType::Text),
+            (self.from.as_ref(), ExprType::Text),
+            (self.to.as_ref(), ExprType::Text),
+        ]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for Replace {}
+impl Expression for Replace {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        format!(
+            "REPLACE({}, {}, {})",
+            self.inner.display(dialect),
+            self.from.display(dialect),
+            self.to.display(dialect)
+        )
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner
+            .nullability(cols)
+            .propagate(self.from.nullability(cols))
+            .propagate(self.to.nullability(cols))
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let text = |expr: &dyn Expression| Option::<String>::from_value(&expr.eval(cols)?);
+        let (Some(s), Some(from)) = (text(self.inner.as_ref())?, text(self.from.as_ref())?) else {
+            return Ok(Value::Null);
+        };
+        // SQLite returns the text as is when there is nothing to replace, even for a `NULL` `to`
+        if from.is_empty() {
+            return Ok(Value::Text(s));
+        }
+        Ok(text(self.to.as_ref())?
+            .map_or(Value::Null, |to| Value::Text(str::replace(&s, &from, &to))))
+    }
+}
+impl Common for Replace {}
+impl Textual for Replace {}
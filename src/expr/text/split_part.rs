@@ -0,0 +1,110 @@
+use std::num::NonZeroU32;
+
+use crate::expr::prelude::*;
+
+/// The n-th part of a text split on a delimiter, or an empty text if there are fewer parts.
+///
+/// The index is 1-based and fixed at build time, since SQLite has no split function:
+/// its rendering walks the parts one by one within a recursive CTE.
+pub struct SplitPart {
+    inner: Box<dyn Expression>,     // Textual
+    delimiter: Box<dyn Expression>, // Textual
+    index: NonZeroU32,
+}
+
+impl SplitPart {
+    pub fn new(
+        inner: Box<dyn Expression>,
+        delimiter: Box<dyn Expression>,
+        index: NonZeroU32,
+    ) -> Self {
+        Self {
+            inner,
+            delimiter,
+            index,
+        }
+    }
+}
+
+impl Client for SplitPart {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![
+            (self.inner.as_ref(), ExprType::Text),
+            (self.delimiter.as_ref(), ExprType::Text),
+        ]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for SplitPart {}
+impl Expression for SplitPart {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let (x, d, n) = (
+            self.inner.display(dialect),
+            self.delimiter.display(dialect),
+            self.index,
+        );
+        match dialect {
+            Dialect::Postgres => format!("SPLIT_PART({x}, {d}, {n})"),
+            // `SUBSTRING_INDEX` would return the last part when there are fewer parts
+            Dialect::MySql => format!(
+                "IF({n} > (CHAR_LENGTH({x}) - CHAR_LENGTH(REPLACE({x}, {d}, ''))) / CHAR_LENGTH({d}) + 1, '', \
+                 SUBSTRING_INDEX(SUBSTRING_INDEX({x}, {d}, {n}), {d}, -1))"
+            ),
+            // drop the leading parts from the text, which is terminated by a delimiter, so that
+            // neither is repeated per part
+            Dialect::Sqlite => format!(
+                "(WITH RECURSIVE split_parts(i, rest) AS (SELECT 1, ({x} || {d}) UNION ALL \
+                 SELECT i + 1, SUBSTR(rest, INSTR(rest, {d}) + LENGTH({d})) FROM split_parts \
+                 WHERE i < {n}) SELECT SUBSTR(rest, 1, INSTR(rest, {d}) - 1) FROM split_parts \
+                 WHERE i = {n})"
+            ),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner
+            .nullability(cols)
+            .propagate(self.delimiter.nullability(cols))
+    }
+}
+impl Common for SplitPart {}
+impl Textual for SplitPart {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::any::col;
+
+    #[test]
+    fn display() {
+        let domain = Textual::split_part(col("email"), "@", NonZeroU32::new(2).unwrap());
+        assert_eq!(
+            domain.display(Dialect::Postgres),
+            "SPLIT_PART(email, '@', 2)"
+        );
+        assert_eq!(
+            domain.display(Dialect::Sqlite),
+            "(WITH RECURSIVE split_parts(i, rest) AS (SELECT 1, (email || '@') UNION ALL \
+             SELECT i + 1, SUBSTR(rest, INSTR(rest, '@') + LENGTH('@')) FROM split_parts \
+             WHERE i < 2) SELECT SUBSTR(rest, 1, INSTR(rest, '@') - 1) FROM split_parts \
+             WHERE i = 2)"
+        );
+
+        // the rendering doesn't grow with the index
+        let last = Textual::split_part(col("path"), "/", NonZeroU32::new(30).unwrap());
+        assert!(String::len(&last.display(Dialect::Sqlite)) < 300);
+    }
+}
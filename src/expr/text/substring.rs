@@ -0,0 +1,120 @@
+use crate::expr::prelude::*;
+use crate::interpreter::{int_arg, substr};
+use crate::row::FromValue;
+
+/// A part of a text, starting at a 1-based character position.
+pub struct Substring {
+    inner: Box<dyn Expression>,       // Textual
+    start: Box<dyn Expression>,       // Numeric
+    len: Option<Box<dyn Expression>>, // Numeric
+}
+
+impl Substring {
+    pub fn new(
+        inner: Box<dyn Expression>,
+        start: Box<dyn Expression>,
+        len: Option<Box<dyn Expression>>,
+    ) -> Self {
+        Self { inner, start, len }
+    }
+}
+
+impl Client for Substring {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        let numbers = std::iter::once(self.start.as_ref()).chain(self.len.as_deref());
+        std::iter::once((self.inner.as_ref() as _, ExprType::Text))
+            .chain(numbers.map(|n| (n as _, ExprType::Num)))
+            .collect()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for Substring {}
+impl Expression for Substring {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let (x, start) = (self.inner.display(dialect), self.start.display(dialect));
+        let len = self.len.as_ref().map(|len| len.display(dialect));
+        match (dialect, len) {
+            (Dialect::Postgres, Some(len)) => format!("SUBSTRING({x} FROM {start} FOR {len})"),
+            (Dialect::Postgres, None) => format!("SUBSTRING({x} FROM {start})"),
+            (Dialect::MySql, Some(len)) => format!("SUBSTRING({x}, {start}, {len})"),
+            (Dialect::MySql, None) => format!("SUBSTRING({x}, {start})"),
+            (Dialect::Sqlite, Some(len)) => format!("SUBSTR({x}, {start}, {len})"),
+            (Dialect::Sqlite, None) => format!("SUBSTR({x}, {start})"),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        let len = match &self.len {
+            Some(len) => len.nullability(cols),
+            None => Nullability::NotNull,
+        };
+        self.inner
+            .nullability(cols)
+            .propagate(self.start.nullability(cols))
+            .propagate(len)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let Some(s) = Option::<String>::from_value(&self.inner.eval(cols)?)? else {
+            return Ok(Value::Null);
+        };
+        let Some(start) = int_arg(&self.start.eval(cols)?)? else {
+            return Ok(Value::Null);
+        };
+        let len = match &self.len {
+            Some(len) => match int_arg(&len.eval(cols)?)? {
+                Some(len) => Some(len),
+                None => return Ok(Value::Null),
+            },
+            None => None,
+        };
+        Ok(Value::Text(substr(&s, start, len)))
+    }
+}
+impl Common for Substring {}
+impl Textual for Substring {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::DerivedChecker;
+    use crate::expr::any::col;
+    use crate::protocol::ServerHandler;
+
+    #[test]
+    fn display() {
+        let year = Textual::substring(col("isbn"), 1, 4);
+        assert_eq!(
+            year.display(Dialect::Postgres),
+            "SUBSTRING(isbn FROM 1 FOR 4)"
+        );
+        assert_eq!(year.display(Dialect::MySql), "SUBSTRING(isbn, 1, 4)");
+        assert_eq!(year.display(Dialect::Sqlite), "SUBSTR(isbn, 1, 4)");
+
+        let rest = Textual::substring_from(col("isbn"), 5);
+        assert_eq!(rest.display(Dialect::Postgres), "SUBSTRING(isbn FROM 5)");
+    }
+
+    #[test]
+    fn checker() {
+        let mut checker = DerivedChecker::new();
+        checker.accept(Condition::TableExists("books".into()).into());
+        Textual::substring(col("isbn"), col("offset"), 4).send_all(ExprType::Text, &mut checker);
+        assert!(checker.state().is_ok());
+        assert_eq!(checker.col_type("isbn"), Some(ExprType::Text));
+        assert_eq!(checker.col_type("offset"), Some(ExprType::Num));
+    }
+}
@@ -0,0 +1,94 @@
+use super::quote;
+use crate::expr::prelude::*;
+
+/// A temporal value formatted as text.
+///
+/// The format is a PostgreSQL template pattern. Other dialects only support the
+/// `YYYY`, `YY`, `MM`, `DD`, `HH24`, `MI` and `SS` patterns, any other characters
+/// are kept as they are.
+pub struct ToChar {
+    inner: Box<dyn Expression>, // Temporal
+    format: String,
+}
+
+impl ToChar {
+    pub fn new(inner: Box<dyn Expression>, format: String) -> Self {
+        Self { inner, format }
+    }
+
+    /// Translates the template pattern into a `strftime`-style format.
+    fn strftime_format(&self, dialect: Dialect) -> String {
+        let (minute, second) = match dialect {
+            Dialect::MySql => ("%i", "%s"),
+            _ => ("%M", "%S"),
+        };
+        let patterns = [
+            ("YYYY", "%Y"),
+            ("YY", "%y"),
+            ("MM", "%m"),
+            ("DD", "%d"),
+            ("HH24", "%H"),
+            ("MI", minute),
+            ("SS", second),
+            ("%", "%%"),
+        ];
+
+        let mut out = String::new();
+        let mut rest = self.format.as_str();
+        'outer: while let Some(c) = rest.chars().next() {
+            for (pattern, replacement) in patterns {
+                if let Some(stripped) = rest.strip_prefix(pattern) {
+                    out += replacement;
+                    rest = stripped;
+                    continue 'outer;
+                }
+            }
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        out
+    }
+}
+
+impl Client for ToChar {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), ExprType::Temporal)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for ToChar {}
+impl Expression for ToChar {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let x = self.inner.display(dialect);
+        match dialect {
+            Dialect::Postgres => format!("TO_CHAR({x}, {})", quote(&self.format, dialect)),
+            Dialect::MySql => format!(
+                "DATE_FORMAT({x}, {})",
+                quote(&self.strftime_format(dialect), dialect)
+            ),
+            Dialect::Sqlite => format!(
+                "strftime({}, {x})",
+                quote(&self.strftime_format(dialect), dialect)
+            ),
+        }
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+}
+impl Common for ToChar {}
+impl Textual for ToChar {}
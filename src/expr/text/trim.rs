@@ -0,0 +1,72 @@
+use crate::expr::prelude::*;
+use crate::row::FromValue;
+
+/// The sides of a text that are trimmed.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum TrimSide {
+    Both,
+    Leading,
+    Trailing,
+}
+
+/// A text with its surrounding whitespace removed.
+pub struct Trim {
+    inner: Box<dyn Expression>, // Textual
+    side: TrimSide,
+}
+
+impl Trim {
+    pub fn new(inner: Box<dyn Expression>, side: TrimSide) -> Self {
+        Self { inner, side }
+    }
+}
+
+impl Client for Trim {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(self.inner.as_ref(), ExprType::Text)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Text)
+    }
+}
+impl Checkable for Trim {}
+impl Expression for Trim {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Text
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        // every dialect supports `LTRIM` and `RTRIM`, but only some `TRIM(LEADING FROM x)`
+        let function = match self.side {
+            TrimSide::Both => "TRIM",
+            TrimSide::Leading => "LTRIM",
+            TrimSide::Trailing => "RTRIM",
+        };
+        format!("{function}({})", self.inner.display(dialect))
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.inner.nullability(cols)
+    }
+
+    fn eval(&self, cols: &dyn Fn(&str) -> Option<Value>) -> Result<Value, String> {
+        let Some(s) = Option::<String>::from_value(&self.inner.eval(cols)?)? else {
+            return Ok(Value::Null);
+        };
+        let trimmed = match self.side {
+            TrimSide::Both => s.trim_matches(' '),
+            TrimSide::Leading => s.trim_start_matches(' '),
+            TrimSide::Trailing => s.trim_end_matches(' '),
+        };
+        Ok(Value::Text(trimmed.to_string()))
+    }
+}
+impl Common for Trim {}
+impl Textual for Trim {}
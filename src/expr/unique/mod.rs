@@ -7,6 +7,36 @@ pub mod multi;
 #[doc(inline)]
 pub use multi::Multi;
 
+impl<T: Client> Client for UniqueExpr<T> {
+    type Ctx = T::Ctx;
+    type Msg = T::Msg;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        self.0.children(ctx)
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        self.0.messages(ctx)
+    }
+
+    fn send_all(&self, ctx: Self::Ctx, server: &mut dyn Server<Msg = Self::Msg>) {
+        self.0.send_all(ctx, server)
+    }
+}
+impl<T: Checkable> Checkable for UniqueExpr<T> {}
+impl<T: Expression> Expression for UniqueExpr<T> {
+    fn eval_type(&self) -> ExprType {
+        self.0.eval_type()
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        self.0.display(dialect)
+    }
+}
+
 // this is done so we can have a bound on the Shl overloads,
 // stopping the ability of having `UniqueExpr<Multi<UniqueExpr<Multi<_>>>>`
 /// Unique expressions that can be put inside of a `UniqueExpr<Multi<T>>`.
@@ -48,21 +48,17 @@ impl Expression for MultiCommon {
     }
 
     fn display(&self, dialect: Dialect) -> String {
-        debug_assert!(!self.exprs.is_empty());
-
-        let mut iter = self.exprs.iter();
-
-        let mut out = match iter.next() {
-            Some(expr) => expr.display(dialect),
-            None => unreachable!("MultiExpr with no expressions can't exist"),
-        };
-
-        for expr in iter {
-            out += ", ";
-            out += &expr.display(dialect);
-        }
+        self.exprs
+            .iter()
+            .map(|expr| expr.display(dialect))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
 
-        out
+impl Projection for UniqueExpr<MultiCommon> {
+    fn items(&self) -> Vec<&dyn Expression> {
+        self.0.exprs.iter().map(|expr| expr.as_ref() as _).collect()
     }
 }
 
@@ -115,21 +111,11 @@ impl<T: Expression> Expression for Multi<T> {
     }
 
     fn display(&self, dialect: Dialect) -> String {
-        debug_assert!(!self.exprs.is_empty());
-
-        let mut iter = self.exprs.iter();
-
-        let mut out = match iter.next() {
-            Some(expr) => expr.display(dialect),
-            None => unreachable!("MultiExpr with no expressions can't exist"),
-        };
-
-        for expr in iter {
-            out += ", ";
-            out += &expr.display(dialect);
-        }
-
-        out
+        self.exprs
+            .iter()
+            .map(|expr| expr.display(dialect))
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 }
 
@@ -141,10 +127,10 @@ impl<T: Common + 'static> std::ops::Shl<CommonExpr<T>> for () {
     }
 }
 
-impl<T: Common + 'static> std::ops::Shl<CommonExpr<T>> for UniqueExpr<MultiCommon> {
+impl<T: Common + 'static> std::ops::Shl<T> for UniqueExpr<MultiCommon> {
     type Output = UniqueExpr<MultiCommon>;
 
-    fn shl(self, rhs: CommonExpr<T>) -> Self::Output {
+    fn shl(self, rhs: T) -> Self::Output {
         let mut multi = self;
         multi.0.push(Box::new(rhs));
         multi
@@ -184,13 +170,15 @@ macro_rules! multi {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::expr::Expression;
+    use crate::snapshot::assert_sql_snapshot;
 
     #[test]
     fn display() {
         use crate::expr::{any::col, bool::Gt, num::Numeric};
-        let _a = () << col("hello") << col("world").len().gt(3);
-        let _b = multi![col("hello"), col("world").len().gt(3)];
-        println!("SELECT {} FROM my_table", _b.display(Dialect::Postgres));
+        let items = multi![col("hello"), col("world").len().gt(3)];
+        assert_sql_snapshot("multi_display", &items);
+        // the macro is shorthand for the builder, hence both render the same
+        let items = () << col("hello") << col("world").len().gt(3);
+        assert_sql_snapshot("multi_display", &items);
     }
 }
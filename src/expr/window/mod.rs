@@ -0,0 +1,265 @@
+//! Window functions, which compute values over a window of rows related to the current one
+
+use crate::expr::prelude::*;
+use crate::reader::Order;
+
+pub mod rank;
+#[doc(inline)]
+pub use rank::{dense_rank, rank, row_number, Ranking};
+
+pub mod offset;
+#[doc(inline)]
+pub use offset::{lag, lead, Offset};
+
+/// The unit in which a frame's bounds are counted.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FrameUnit {
+    /// Physical rows
+    Rows,
+    /// Rows whose ordering values lie within the bounds
+    Range,
+}
+
+/// The bounds of a window frame.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
+impl FrameBound {
+    fn display(self) -> String {
+        match self {
+            FrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+            FrameBound::Preceding(n) => format!("{n} PRECEDING"),
+            FrameBound::CurrentRow => "CURRENT ROW".to_string(),
+            FrameBound::Following(n) => format!("{n} FOLLOWING"),
+            FrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+        }
+    }
+}
+
+/// The rows that a window function is computed over.
+///
+/// Windows either stand on their own, or extend a named window defined through
+/// [`crate::reader::Reader::window`].
+#[derive(Default)]
+pub struct Window {
+    name: Option<String>,
+    partition_by: Vec<Box<dyn Expression>>,
+    order_by: Vec<(Box<dyn Expression>, Order)>,
+    frame: Option<(FrameUnit, FrameBound, FrameBound)>,
+}
+
+impl Window {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refers to a window defined on the reader.
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn partition_by(mut self, expr: impl Common + 'static) -> Self {
+        self.partition_by.push(Box::new(expr));
+        self
+    }
+
+    pub fn order_by(mut self, expr: impl Common + 'static) -> Self {
+        self.order_by.push((Box::new(expr), Order::Asc));
+        self
+    }
+
+    pub fn order_by_desc(mut self, expr: impl Common + 'static) -> Self {
+        self.order_by.push((Box::new(expr), Order::Desc));
+        self
+    }
+
+    /// Limits the frame to the rows between both bounds.
+    pub fn rows(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some((FrameUnit::Rows, start, end));
+        self
+    }
+
+    /// Limits the frame to the rows whose ordering values lie between both bounds.
+    pub fn range(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some((FrameUnit::Range, start, end));
+        self
+    }
+
+    /// The name of the window this one refers to, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the window's definition, without the surrounding parentheses.
+    pub(crate) fn definition(&self, dialect: Dialect) -> String {
+        let mut parts = Vec::new();
+        if let Some(name) = &self.name {
+            parts.push(name.clone());
+        }
+        if !self.partition_by.is_empty() {
+            let exprs: Vec<_> = self
+                .partition_by
+                .iter()
+                .map(|e| e.display(dialect))
+                .collect();
+            parts.push(format!("PARTITION BY {}", exprs.join(", ")));
+        }
+        if !self.order_by.is_empty() {
+            let terms: Vec<_> = self
+                .order_by
+                .iter()
+                .map(|(e, order)| match order {
+                    Order::Asc => e.display(dialect),
+                    Order::Desc => format!("{} DESC", e.display(dialect)),
+                })
+                .collect();
+            parts.push(format!("ORDER BY {}", terms.join(", ")));
+        }
+        if let Some((unit, start, end)) = self.frame {
+            let unit = match unit {
+                FrameUnit::Rows => "ROWS",
+                FrameUnit::Range => "RANGE",
+            };
+            parts.push(format!(
+                "{unit} BETWEEN {} AND {}",
+                start.display(),
+                end.display()
+            ));
+        }
+        parts.join(" ")
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        match (&self.name, self.definition(dialect)) {
+            (Some(name), definition) if *name == definition => definition,
+            (_, definition) => format!("({definition})"),
+        }
+    }
+}
+
+impl Client for Window {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        self.partition_by
+            .iter()
+            .chain(self.order_by.iter().map(|(e, _)| e))
+            .map(|e| (e.as_ref() as _, ExprType::Any))
+            .collect()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        match &self.name {
+            Some(name) => vec![Condition::WindowExists(name.clone()).into()],
+            None => Vec::new(),
+        }
+    }
+}
+impl Checkable for Window {}
+
+/// Functions that can be computed over a [`Window`].
+pub trait WindowFunction: Expression {
+    fn over(self, window: Window) -> CommonExpr<Over<Self>>
+    where
+        Self: Sized,
+    {
+        CommonExpr(Over::new(self, window))
+    }
+}
+impl<T: WindowFunction> WindowFunction for CommonExpr<T> {}
+
+/// A window function computed over a window.
+pub struct Over<F> {
+    func: F,
+    window: Window,
+}
+
+impl<F: WindowFunction> Over<F> {
+    pub fn new(func: F, window: Window) -> Self {
+        Self { func, window }
+    }
+}
+
+impl<F: WindowFunction> Client for Over<F> {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        vec![(&self.func, ctx), (&self.window, ExprType::Any)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        vec![Signal::WindowFunction.into()]
+    }
+}
+impl<F: WindowFunction> Checkable for Over<F> {}
+impl<F: WindowFunction> Expression for Over<F> {
+    fn eval_type(&self) -> ExprType {
+        self.func.eval_type()
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        format!(
+            "{} OVER {}",
+            self.func.display(dialect),
+            self.window.display(dialect)
+        )
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        self.func.nullability(cols)
+    }
+}
+impl<F: WindowFunction> Common for Over<F> {}
+impl<F: WindowFunction + Boolean> Boolean for Over<F> {}
+impl<F: WindowFunction + Numeric> Numeric for Over<F> {}
+impl<F: WindowFunction + Textual> Textual for Over<F> {}
+impl<F: WindowFunction + Temporal> Temporal for Over<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::agg::{avg, sum};
+    use crate::expr::any::col;
+
+    #[test]
+    fn display() {
+        let running = sum(col("amount")).over(
+            Window::new()
+                .partition_by(col("account"))
+                .order_by(col("day"))
+                .rows(FrameBound::UnboundedPreceding, FrameBound::CurrentRow),
+        );
+        assert_eq!(
+            running.display(Dialect::Postgres),
+            "SUM(amount) OVER (PARTITION BY account ORDER BY day \
+             ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"
+        );
+
+        let moving = avg(col("price"))
+            .over(Window::named("w").rows(FrameBound::Preceding(6), FrameBound::CurrentRow));
+        assert_eq!(
+            moving.display(Dialect::Sqlite),
+            "AVG(price) OVER (w ROWS BETWEEN 6 PRECEDING AND CURRENT ROW)"
+        );
+
+        let top = row_number().over(Window::named("w")) + 1;
+        assert_eq!(top.display(Dialect::MySql), "(ROW_NUMBER() OVER w + 1)");
+    }
+}
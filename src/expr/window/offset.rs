@@ -0,0 +1,80 @@
+use std::marker::PhantomData;
+
+use super::{Over, WindowFunction};
+use crate::expr::prelude::*;
+
+/// The value of an expression at a row before or after the current one within the window.
+///
+/// Offsets are only valid over a window, hence they are not [`Common`] on their own.
+/// The expression's type is kept so that the result belongs to the same families.
+pub struct Offset<T> {
+    inner: Box<dyn Expression>, // T
+    offset: u64,
+    // whether the row follows the current one
+    lead: bool,
+    family: PhantomData<T>,
+}
+
+/// The value of the expression `offset` rows before the current one.
+pub fn lag<T: Common + 'static>(expr: T, offset: u64) -> Offset<T> {
+    Offset {
+        inner: Box::new(expr),
+        offset,
+        lead: false,
+        family: PhantomData,
+    }
+}
+
+/// The value of the expression `offset` rows after the current one.
+pub fn lead<T: Common + 'static>(expr: T, offset: u64) -> Offset<T> {
+    Offset {
+        inner: Box::new(expr),
+        offset,
+        lead: true,
+        family: PhantomData,
+    }
+}
+
+impl<T: Common> Client for Offset<T> {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        let ctx = match ctx {
+            ExprType::Any => self.inner.eval_type(),
+            ctx => ctx,
+        };
+        vec![(self.inner.as_ref(), ctx)]
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Vec::new()
+    }
+}
+impl<T: Common> Checkable for Offset<T> {}
+impl<T: Common> Expression for Offset<T> {
+    fn eval_type(&self) -> ExprType {
+        self.inner.eval_type()
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        let func = if self.lead { "LEAD" } else { "LAG" };
+        match self.offset {
+            1 => format!("{func}({})", self.inner.display(dialect)),
+            n => format!("{func}({}, {n})", self.inner.display(dialect)),
+        }
+    }
+
+    // rows outside of the window are `NULL`
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::Nullable
+    }
+}
+impl<T: Common> WindowFunction for Offset<T> {}
+impl<T: Boolean> Boolean for Over<Offset<T>> {}
+impl<T: Numeric> Numeric for Over<Offset<T>> {}
+impl<T: Textual> Textual for Over<Offset<T>> {}
+impl<T: Temporal> Temporal for Over<Offset<T>> {}
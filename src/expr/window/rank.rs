@@ -0,0 +1,74 @@
+use super::{Over, WindowFunction};
+use crate::expr::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum RankKind {
+    RowNumber,
+    Rank,
+    DenseRank,
+}
+
+/// The position of the current row within its window.
+///
+/// Rankings are only valid over a window, hence they are not [`Common`] on their own.
+pub struct Ranking {
+    kind: RankKind,
+}
+
+/// The number of the current row, counting from 1.
+pub fn row_number() -> Ranking {
+    Ranking {
+        kind: RankKind::RowNumber,
+    }
+}
+
+/// The rank of the current row, with gaps after ties.
+pub fn rank() -> Ranking {
+    Ranking {
+        kind: RankKind::Rank,
+    }
+}
+
+/// The rank of the current row, without gaps after ties.
+pub fn dense_rank() -> Ranking {
+    Ranking {
+        kind: RankKind::DenseRank,
+    }
+}
+
+impl Client for Ranking {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(
+        &self,
+        ctx: Self::Ctx,
+    ) -> Vec<(&dyn Client<Ctx = Self::Ctx, Msg = Self::Msg>, Self::Ctx)> {
+        Vec::new()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Signal::ctx_mismatch(ctx, ExprType::Num)
+    }
+}
+impl Checkable for Ranking {}
+impl Expression for Ranking {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Num
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        match self.kind {
+            RankKind::RowNumber => "ROW_NUMBER()",
+            RankKind::Rank => "RANK()",
+            RankKind::DenseRank => "DENSE_RANK()",
+        }
+        .to_string()
+    }
+
+    fn nullability(&self, cols: &dyn Fn(&str) -> Nullability) -> Nullability {
+        Nullability::NotNull
+    }
+}
+impl WindowFunction for Ranking {}
+impl Numeric for Over<Ranking> {}
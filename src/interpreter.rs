@@ -0,0 +1,689 @@
+//! Evaluates expressions over in-memory rows, so that filters can be tested without a database.
+//!
+//! `NULL`s follow SQL's three-valued logic: comparisons and arithmetic with a `NULL` operand
+//! evaluate into `NULL`, `AND`/`OR` only do so when the other operand doesn't decide the result,
+//! and filters only keep the rows whose condition is `TRUE`. Where dialects differ SQLite is
+//! followed, e.g. integer division truncates, remainders truncate their operands into integers,
+//! and division by zero evaluates into `NULL`.
+//!
+//! Functions follow SQLite as well: they evaluate into `NULL` when an argument is, `LIKE`, `LOWER`
+//! and `UPPER` only know the case of ASCII letters, and floats have no `NaN`, which is `NULL`
+//! instead. Casts into numbers read the longest numeric prefix of text, and casts into text render
+//! floats with the fewest of up to 17 significant digits that read back into the same float.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::expr::{bool::Boolean, CommonExpr, Expression};
+use crate::row::{FromValue, Value};
+
+/// A row whose values are accessed by column name.
+pub type MapRow = HashMap<String, Value>;
+
+/// Evaluates the expression over the row.
+pub fn eval(expr: &dyn Expression, row: &MapRow) -> Result<Value, String> {
+    expr.eval(&|col| row.get(col).cloned())
+}
+
+/// Keeps the rows for which the condition is `TRUE`, like a `WHERE` clause.
+pub fn filter<'r, T: Boolean>(
+    cond: &CommonExpr<T>,
+    rows: &'r [MapRow],
+) -> Result<Vec<&'r MapRow>, String> {
+    let mut out = Vec::new();
+    for row in rows {
+        if truth(&eval(cond, row)?)? == Some(true) {
+            out.push(row);
+        }
+    }
+    Ok(out)
+}
+
+/// The truth value of a boolean, `None` being `NULL`.
+pub(crate) fn truth(value: &Value) -> Result<Option<bool>, String> {
+    Option::<bool>::from_value(value)
+}
+
+pub(crate) fn from_truth(truth: Option<bool>) -> Value {
+    truth.map_or(Value::Null, Value::Bool)
+}
+
+pub(crate) fn and(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+pub(crate) fn or(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+/// Compares two values, `None` being `NULL` as one of them is.
+pub(crate) fn compare(lhs: &Value, rhs: &Value) -> Result<Option<Ordering>, String> {
+    let ordering = match (lhs, rhs) {
+        (Value::Null, _) | (_, Value::Null) => return Ok(None),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => {
+            let (a, b) = (f64::from_value(lhs)?, f64::from_value(rhs)?);
+            a.partial_cmp(&b)
+                .ok_or_else(|| format!("can't compare {lhs} and {rhs}"))?
+        }
+        (Value::Text(a), Value::Text(b)) => a.cmp(b),
+        _ => return Err(format!("can't compare {lhs} and {rhs}")),
+    };
+    Ok(Some(ordering))
+}
+
+/// Evaluates a comparison of two values, given which orderings satisfy it.
+pub(crate) fn compare_with(
+    lhs: &Value,
+    rhs: &Value,
+    pred: fn(Ordering) -> bool,
+) -> Result<Value, String> {
+    Ok(from_truth(compare(lhs, rhs)?.map(pred)))
+}
+
+/// The arithmetic operators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Arith {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Arith {
+    fn int(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Arith::Add => a.checked_add(b),
+            Arith::Sub => a.checked_sub(b),
+            Arith::Mul => a.checked_mul(b),
+            Arith::Div => a.checked_div(b),
+            Arith::Rem => a.checked_rem(b),
+        }
+    }
+
+    fn float(self, a: f64, b: f64) -> f64 {
+        match self {
+            Arith::Add => a + b,
+            Arith::Sub => a - b,
+            Arith::Mul => a * b,
+            Arith::Div => a / b,
+            Arith::Rem => a % b,
+        }
+    }
+
+    /// Like SQLite, the remainder truncates both operands into integers, and is only a float if
+    /// one of them is.
+    fn rem(lhs: &Value, rhs: &Value, a: i64, b: i64) -> Value {
+        if b == 0 {
+            return Value::Null;
+        }
+        // `i64::MIN % -1` overflows, though its remainder is 0
+        let rem = a.checked_rem(b).unwrap_or(0);
+        match (lhs, rhs) {
+            (Value::Int(_), Value::Int(_)) => Value::Int(rem),
+            _ => Value::Float(rem as f64),
+        }
+    }
+
+    /// Applies the operator, integers overflowing into floats.
+    pub(crate) fn apply(self, lhs: &Value, rhs: &Value) -> Result<Value, String> {
+        if matches!(lhs, Value::Null) || matches!(rhs, Value::Null) {
+            return Ok(Value::Null);
+        }
+        let (a, b) = (f64::from_value(lhs)?, f64::from_value(rhs)?);
+        if let Arith::Rem = self {
+            return Ok(Self::rem(lhs, rhs, a as i64, b as i64));
+        }
+        if matches!(self, Arith::Div) && b == 0.0 {
+            return Ok(Value::Null);
+        }
+        match (lhs, rhs) {
+            (Value::Int(x), Value::Int(y)) => match self.int(*x, *y) {
+                Some(int) => Ok(Value::Int(int)),
+                None => Ok(real(self.float(a, b))),
+            },
+            _ => Ok(real(self.float(a, b))),
+        }
+    }
+}
+
+/// A float, `NaN` being `NULL` as SQLite has no such value.
+pub(crate) fn real(x: f64) -> Value {
+    if x.is_nan() {
+        Value::Null
+    } else {
+        Value::Float(x)
+    }
+}
+
+/// The integer that a numeric argument of a function is truncated into, `None` being `NULL`.
+pub(crate) fn int_arg(value: &Value) -> Result<Option<i64>, String> {
+    match value {
+        Value::Null => Ok(None),
+        Value::Int(i) => Ok(Some(*i)),
+        value => Ok(Some(f64::from_value(value)? as i64)),
+    }
+}
+
+pub(crate) fn neg(x: &Value) -> Result<Value, String> {
+    Arith::Sub.apply(&Value::Int(0), x)
+}
+
+pub(crate) fn abs(x: &Value) -> Result<Value, String> {
+    match x {
+        Value::Null => Ok(Value::Null),
+        Value::Int(i) => i
+            .checked_abs()
+            .map(Value::Int)
+            .ok_or_else(|| "integer overflow".to_string()),
+        x => Ok(real(f64::from_value(x)?.abs())),
+    }
+}
+
+pub(crate) fn floor(x: &Value) -> Result<Value, String> {
+    match x {
+        Value::Null | Value::Int(_) => Ok(x.clone()),
+        x => Ok(real(f64::from_value(x)?.floor())),
+    }
+}
+
+pub(crate) fn ceil(x: &Value) -> Result<Value, String> {
+    match x {
+        Value::Null | Value::Int(_) => Ok(x.clone()),
+        x => Ok(real(f64::from_value(x)?.ceil())),
+    }
+}
+
+pub(crate) fn sqrt(x: &Value) -> Result<Value, String> {
+    Ok(Option::<f64>::from_value(x)?.map_or(Value::Null, |x| real(x.sqrt())))
+}
+
+/// The natural logarithm, which is `NULL` for numbers that aren't positive.
+pub(crate) fn ln(x: &Value) -> Result<Value, String> {
+    Ok(match Option::<f64>::from_value(x)? {
+        Some(x) if x > 0.0 => real(x.ln()),
+        _ => Value::Null,
+    })
+}
+
+fn undecodable(x: f64) -> String {
+    format!("can't decode {x} into decimal digits")
+}
+
+/// The significant digits of a positive finite float, along with the position of its decimal
+/// point relative to them. Like SQLite, there are 18 digits, or 19 when SQLite's estimate of the
+/// decimal exponent from the binary one falls short.
+fn decode(x: f64) -> Result<(Vec<u8>, i32), String> {
+    if !x.is_finite() || x <= 0.0 {
+        return Err(undecodable(x));
+    }
+    let bits = x.to_bits();
+    let exp2 = match (bits >> 52) as i32 {
+        0 => -1074 + 63 - (bits << 12 >> 12).leading_zeros() as i32,
+        biased => biased - 1023,
+    };
+    let scientific = |len: i32| -> Result<(Vec<u8>, i32), String> {
+        let scientific = format!("{x:.*e}", (len - 1) as usize);
+        let (mantissa, exp) = scientific.split_once('e').ok_or_else(|| undecodable(x))?;
+        let digits = mantissa.bytes().filter(u8::is_ascii_digit).collect();
+        let exp = exp.parse::<i32>().map_err(|_| undecodable(x))?;
+        Ok((digits, exp + 1))
+    };
+    // enough digits for the rounding not to carry into the next power of ten
+    let (_, point) = scientific(26)?;
+    let exp10 = point - 1;
+    scientific((18 + exp10 - ((exp2 * 78913) >> 18)).max(18))
+}
+
+/// Keeps the first `n` digits, rounding half up. Returns whether a carry added a leading digit.
+fn round_digits(digits: &mut Vec<u8>, n: usize) -> bool {
+    let up = digits.get(n).is_some_and(|d| *d >= b'5');
+    digits.truncate(n);
+    if up {
+        for d in digits.iter_mut().rev() {
+            if *d == b'9' {
+                *d = b'0';
+            } else {
+                *d += 1;
+                return false;
+            }
+        }
+        digits.insert(0, b'1');
+        return true;
+    }
+    false
+}
+
+/// Reads `digits * 10^exp`.
+fn read_float(digits: &str, exp: i32) -> Result<f64, String> {
+    let text = format!("{digits}e{exp}");
+    text.parse()
+        .map_err(|_| format!("can't read {text} as a float"))
+}
+
+/// The decoded digits as text.
+fn digits_text(digits: Vec<u8>) -> Result<String, String> {
+    String::from_utf8(digits).map_err(|_| "decoded digits aren't ASCII".to_string())
+}
+
+/// Rounds half away from zero to a number of decimal places, clamped within `0..=30`. Like
+/// SQLite, the rounding works on the decoded significant digits of the number.
+pub(crate) fn round(x: f64, places: i64) -> Result<Value, String> {
+    Ok(real(round_float(x, places)?))
+}
+
+fn round_float(x: f64, places: i64) -> Result<f64, String> {
+    let places = places.clamp(0, 30) as i32;
+    // floats beyond 2^52 have no fractional part, which includes infinities
+    if x.is_nan() || x.abs() > 4503599627370496.0 {
+        return Ok(x);
+    }
+    if places == 0 {
+        return Ok((x + 0.5_f64.copysign(x)) as i64 as f64);
+    }
+    if x == 0.0 {
+        return Ok(0.0);
+    }
+    let (mut digits, mut point) = decode(x.abs())?;
+    let mut keep = point + places;
+    if keep == 0 && digits[0] >= b'5' {
+        digits.insert(0, b'0');
+        point += 1;
+        keep = 1;
+    }
+    if keep <= 0 {
+        return Ok(0.0_f64.copysign(x));
+    }
+    if round_digits(&mut digits, keep as usize) {
+        point += 1;
+    }
+    let digits = digits_text(digits)?;
+    let len = digits.len() as i32;
+    Ok(read_float(&digits, point - len)?.copysign(x))
+}
+
+/// Converts a value into a number like `CAST(x AS NUMERIC)`.
+pub(crate) fn to_num(value: &Value) -> Result<Value, String> {
+    match value {
+        Value::Bool(b) => Ok(Value::Int(*b as i64)),
+        Value::Text(s) => parse_num(s),
+        Value::Float(x) => Ok(real(*x)),
+        value => Ok(value.clone()),
+    }
+}
+
+/// Parses the longest numeric prefix of the text, integral values of floats within ±2^51 being
+/// integers. Text without one is 0.
+fn parse_num(s: &str) -> Result<Value, String> {
+    let s = s.trim_start_matches([' ', '\t', '\n', '\x0b', '\x0c', '\r']);
+    let bytes = s.as_bytes();
+    let digits = |from: usize| {
+        bytes[from.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let mut end = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let int_digits = digits(end);
+    end += int_digits;
+    let mut real = false;
+    if bytes.get(end) == Some(&b'.') {
+        let frac_digits = digits(end + 1);
+        if int_digits + frac_digits > 0 {
+            end += 1 + frac_digits;
+            real = true;
+        }
+    }
+    if int_digits == 0 && !real {
+        return Ok(Value::Int(0));
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exp_digits = digits(end + 1 + sign);
+        if exp_digits > 0 {
+            end += 1 + sign + exp_digits;
+            real = true;
+        }
+    }
+    let prefix = &s[..end];
+    if !real {
+        if let Ok(int) = prefix.parse::<i64>() {
+            return Ok(Value::Int(int));
+        }
+    }
+    let x: f64 = prefix
+        .parse()
+        .map_err(|_| format!("can't read {prefix} as a float"))?;
+    let int = x as i64;
+    if x == 0.0 || (int as f64 == x && (-(1 << 51)..(1 << 51)).contains(&int)) {
+        Ok(Value::Int(int))
+    } else {
+        Ok(Value::Float(x))
+    }
+}
+
+/// Converts a value into text like `CAST(x AS TEXT)`, booleans being the integers that SQLite
+/// stores them as.
+pub(crate) fn to_text(value: &Value) -> Result<Value, String> {
+    Ok(match value {
+        Value::Null => Value::Null,
+        Value::Bool(b) => Value::Text((*b as i64).to_string()),
+        Value::Int(i) => Value::Text(i.to_string()),
+        Value::Float(x) if x.is_nan() => Value::Null,
+        Value::Float(x) => Value::Text(float_text(*x)?),
+        Value::Text(s) => Value::Text(s.clone()),
+    })
+}
+
+/// Renders a float like SQLite's `%!.17g`, which drops the 17th significant digit, or more, when
+/// fewer digits read back into the same float.
+fn float_text(x: f64) -> Result<String, String> {
+    if x == 0.0 {
+        return Ok("0.0".to_string());
+    }
+    if x.is_infinite() {
+        return Ok(if x < 0.0 { "-Inf" } else { "Inf" }.to_string());
+    }
+    let sign = if x < 0.0 { "-" } else { "" };
+    let abs = x.abs();
+    let (mut digits, mut point) = decode(abs)?;
+    let value = |digits: &[u8]| -> Result<u64, String> {
+        let text = std::str::from_utf8(digits).map_err(|_| undecodable(x))?;
+        text.parse().map_err(|_| undecodable(x))
+    };
+    let mut keep = 17;
+    if digits[15] == b'9' && digits[14] == b'9' {
+        let nines = digits[..14]
+            .iter()
+            .rev()
+            .take_while(|d| **d == b'9')
+            .count();
+        let kept = 14 - nines;
+        let rounded = if kept == 0 {
+            1
+        } else {
+            value(&digits[..kept])? + 1
+        };
+        if read_float(&rounded.to_string(), point - kept as i32)? == abs {
+            keep = kept + 1;
+        }
+    } else if point >= digits.len() as i32 || digits[13..16] == *b"000" {
+        let zeros = digits[..13]
+            .iter()
+            .rev()
+            .take_while(|d| **d == b'0')
+            .count();
+        let kept = 13 - zeros;
+        if read_float(&value(&digits[..kept])?.to_string(), point - kept as i32)? == abs {
+            keep = kept + 1;
+        }
+    }
+    if round_digits(&mut digits, keep) {
+        point += 1;
+    }
+    while digits.len() > 1 && digits.last() == Some(&b'0') {
+        digits.pop();
+    }
+    let digits = digits_text(digits)?;
+    let exp = point - 1;
+    Ok(if !(-4..=16).contains(&exp) {
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() { "0" } else { rest };
+        let exp_sign = if exp < 0 { '-' } else { '+' };
+        format!("{sign}{first}.{rest}e{exp_sign}{:02}", exp.abs())
+    } else if point <= 0 {
+        format!("{sign}0.{}{digits}", "0".repeat(-point as usize))
+    } else {
+        let point = point as usize;
+        let (int, frac) = digits.split_at(point.min(digits.len()));
+        let frac = if frac.is_empty() { "0" } else { frac };
+        format!("{sign}{int}{}.{frac}", "0".repeat(point - int.len()))
+    })
+}
+
+/// Whether the text matches the `LIKE` pattern, whose wildcards are matched literally after the
+/// escape character. ASCII letters match regardless of their case.
+pub(crate) fn like(text: &str, pattern: &str, escape: Option<char>) -> bool {
+    enum Token {
+        Any,
+        One,
+        Char(char),
+    }
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            c if Some(c) == escape => match chars.next() {
+                Some(c) => Token::Char(c),
+                // a trailing escape character matches nothing
+                None => return false,
+            },
+            '%' => Token::Any,
+            '_' => Token::One,
+            c => Token::Char(c),
+        });
+    }
+
+    let text: Vec<char> = text.chars().collect();
+    // whether the tokens so far match each prefix of the text
+    let mut matched = vec![false; text.len() + 1];
+    matched[0] = true;
+    for token in tokens {
+        let mut next = vec![false; text.len() + 1];
+        for i in 0..=text.len() {
+            next[i] = match token {
+                Token::Any => matched[i] || (i > 0 && next[i - 1]),
+                Token::One => i > 0 && matched[i - 1],
+                Token::Char(c) => i > 0 && matched[i - 1] && text[i - 1].eq_ignore_ascii_case(&c),
+            };
+        }
+        matched = next;
+    }
+    matched[text.len()]
+}
+
+/// The characters of the text from the 1-based start on, like SQLite's `SUBSTR`. A negative start
+/// counts from the end, and a negative length takes the characters before the start.
+pub(crate) fn substr(text: &str, start: i64, len: Option<i64>) -> String {
+    let (mut start, mut len) = (start, len.unwrap_or(1_000_000_000));
+    if start < 0 {
+        start += text.chars().count() as i64;
+        if start < 0 {
+            len = if len < 0 { 0 } else { len + start };
+            start = 0;
+        }
+    } else if start > 0 {
+        start -= 1;
+    } else if len > 0 {
+        len -= 1;
+    }
+    if len < 0 {
+        len = if len < -start { start } else { -len };
+        start -= len;
+    }
+    text.chars()
+        .skip(start as usize)
+        .take(len as usize)
+        .collect()
+}
+
+/// The 1-based character position of the needle within the text, or 0 if it is missing.
+pub(crate) fn instr(text: &str, needle: &str) -> i64 {
+    text.find(needle)
+        .map_or(0, |i| text[..i].chars().count() as i64 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{
+        any::{col, NULL},
+        common::Common,
+        num::Numeric,
+        text::Textual,
+    };
+    use crate::multi;
+
+    fn row(values: &[(&str, Value)]) -> MapRow {
+        values
+            .iter()
+            .map(|(col, value)| (col.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn three_valued_logic() {
+        let rows = [
+            row(&[("a", Value::Bool(true)), ("b", Value::Null)]),
+            row(&[("a", Value::Bool(false)), ("b", Value::Null)]),
+            row(&[("a", Value::Null), ("b", Value::Null)]),
+        ];
+        let eval_all = |expr: &dyn Expression| -> Vec<Value> {
+            rows.iter().map(|row| eval(expr, row).unwrap()).collect()
+        };
+        use Value::{Bool, Null};
+
+        assert_eq!(eval_all(&(col("a") & col("b"))), [Null, Bool(false), Null]);
+        assert_eq!(eval_all(&(col("a") | col("b"))), [Bool(true), Null, Null]);
+        assert_eq!(eval_all(&!col("a")), [Bool(false), Bool(true), Null]);
+        assert_eq!(eval_all(&Numeric::eq(col("b"), NULL)), [Null, Null, Null]);
+        assert_eq!(
+            eval_all(&col("b").is_null()),
+            [Bool(true), Bool(true), Bool(true)]
+        );
+        assert_eq!(
+            eval_all(&Boolean::is_distinct_from(col("a"), true)),
+            [Bool(false), Bool(true), Bool(true)]
+        );
+    }
+
+    #[test]
+    fn filters() {
+        let rows = [
+            row(&[("name", Value::Text("ann".into())), ("age", Value::Int(30))]),
+            row(&[("name", Value::Text("bob".into())), ("age", Value::Null)]),
+            row(&[("name", Value::Text("cy".into())), ("age", Value::Int(70))]),
+        ];
+        let names = |rows: Vec<&MapRow>| -> Vec<Value> {
+            rows.into_iter().map(|row| row["name"].clone()).collect()
+        };
+
+        let working = Numeric::between(col("age"), 18, 65);
+        assert_eq!(
+            names(filter(&working, &rows).unwrap()),
+            [Value::Text("ann".into())]
+        );
+        // `NULL` is neither kept by a condition nor by its negation
+        assert_eq!(
+            names(filter(&!working, &rows).unwrap()),
+            [Value::Text("cy".into())]
+        );
+
+        let short = Numeric::lt(col("name").len() * 10, col("age"));
+        assert_eq!(
+            names(filter(&short, &rows).unwrap()),
+            [Value::Text("cy".into())]
+        );
+    }
+
+    #[test]
+    fn values() {
+        let row = row(&[
+            ("x", Value::Int(7)),
+            ("y", Value::Float(0.5)),
+            ("z", Value::Float(-2.9)),
+        ]);
+
+        assert_eq!(eval(&(col("x") / 2), &row), Ok(Value::Int(3)));
+        assert_eq!(eval(&(col("x") % 0), &row), Ok(Value::Null));
+        // the remainder truncates its operands into integers
+        assert_eq!(eval(&(col("x") % col("y")), &row), Ok(Value::Null));
+        assert_eq!(eval(&(col("x") % col("z")), &row), Ok(Value::Float(1.0)));
+        assert_eq!(
+            eval(&((col("x") + col("y")) % 2), &row),
+            Ok(Value::Float(1.0))
+        );
+        assert_eq!(eval(&(col("x") * col("y")), &row), Ok(Value::Float(3.5)));
+        assert_eq!(eval(&(col("x") * 1.0 / 2), &row), Ok(Value::Float(3.5)));
+        assert_eq!(
+            eval(&(col("x") + i64::MAX), &row),
+            Ok(Value::Float(7.0 + i64::MAX as f64))
+        );
+
+        assert_eq!(
+            eval(&col("w"), &row),
+            Err("the row has no column `w`".to_string())
+        );
+        let items = multi![col("x"), col("y")];
+        assert!(eval(&items, &row).is_err());
+    }
+
+    #[test]
+    fn functions() {
+        let row = row(&[
+            ("x", Value::Int(-7)),
+            ("y", Value::Float(2.5)),
+            ("s", Value::Text(" Déjà vu ".into())),
+            ("n", Value::Null),
+        ]);
+        let eval = |expr: &dyn Expression| eval(expr, &row).unwrap();
+        use Value::{Bool, Float, Int, Null, Text};
+
+        assert_eq!(eval(&-col("x")), Int(7));
+        assert_eq!(eval(&col("x").abs()), Int(7));
+        assert_eq!(eval(&col("y").floor()), Float(2.0));
+        assert_eq!(eval(&col("x").round()), Float(-7.0));
+        assert_eq!(eval(&Numeric::round_to(col("y") / 8, 2)), Float(0.31));
+        // `NaN` is `NULL`
+        assert_eq!(eval(&col("x").sqrt()), Null);
+        assert_eq!(eval(&col("x").ln()), Null);
+        assert_eq!(eval(&Numeric::power(col("y"), col("n"))), Null);
+
+        assert_eq!(eval(&col("s").trim().upper()), Text("DéJà VU".into()));
+        assert_eq!(eval(&col("s").substring(-4, 2)), Text(" v".into()));
+        assert_eq!(eval(&col("s").position("vu")), Int(7));
+        assert_eq!(eval(&Textual::concat(col("s"), col("n"))), Null);
+        // only ASCII letters match regardless of their case
+        assert_eq!(eval(&col("s").like("_dé%")), Bool(true));
+        assert_eq!(eval(&col("s").like("_dÉ%")), Bool(false));
+        assert_eq!(eval(&col("s").ends_with("%")), Bool(false));
+
+        assert_eq!(eval(&Numeric::to_text(col("y") * 4)), Text("10.0".into()));
+        assert_eq!(eval(&Numeric::to_text(col("x") / 2)), Text("-3".into()));
+        assert_eq!(eval(&Textual::to_num(" 1.50abc")), Float(1.5));
+        assert_eq!(
+            eval(&Textual::to_num(Textual::concat("12", "e3x"))),
+            Int(12000)
+        );
+    }
+
+    #[test]
+    fn non_finite_floats() {
+        let row = row(&[
+            ("nan", Value::Float(f64::NAN)),
+            ("inf", Value::Float(f64::INFINITY)),
+        ]);
+        let eval = |expr: &dyn Expression| eval(expr, &row).unwrap();
+        use Value::{Float, Null, Text};
+
+        // `NaN` is `NULL`, like in SQLite
+        assert_eq!(eval(&col("nan").round_to(2)), Null);
+        assert_eq!(eval(&Numeric::to_text(col("nan"))), Null);
+        assert_eq!(eval(&col("nan").abs()), Null);
+
+        assert_eq!(eval(&col("inf").round_to(2)), Float(f64::INFINITY));
+        assert_eq!(eval(&Numeric::to_text(col("inf"))), Text("Inf".into()));
+        assert_eq!(eval(&Numeric::to_text(-col("inf"))), Text("-Inf".into()));
+        assert_eq!(eval(&(col("inf") - col("inf"))), Null);
+    }
+}
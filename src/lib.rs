@@ -1,18 +1,52 @@
 #![allow(unused)]
-#![feature(trait_upcasting)]
 
 use expr::Life;
 
+// allows the derived code to refer to `::crabql` within this crate
+extern crate self as crabql;
+
+/// Derives `schema::TableDef` and typed column constants for a struct
+pub use crabql_derive::Table;
+
+/// Derives `row::FromRow` for a struct
+pub use crabql_derive::FromRow;
+
 /// SQL Expressions
 pub mod expr;
 
 /// Create SQL Expressions
 pub mod reader;
 
+/// Create SQL write statements
+pub mod writer;
+
+/// Ordered scripts of dependent statements
+pub mod batch;
+
+/// Atomic scripts of statements
+pub mod transaction;
+
+/// Decode the rows returned by queries
+pub mod row;
+
+/// Evaluate expressions over in-memory rows
+pub mod interpreter;
+
+/// Execute statements against an embedded SQLite database
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+// Differential tests of the interpreter against SQLite
+#[cfg(all(test, feature = "sqlite"))]
+mod differential;
+
+/// Snapshot tests of generated SQL
+pub mod snapshot;
+
 /// Query Verification
 pub mod checker;
 
-/// Experimental Testing
+// Experimental Testing
 // pub mod testing;
 
 // Schemas
@@ -14,7 +14,7 @@ pub trait Client {
     /// Returns a list of other `Client`s "connected" to Self.
     /// The context may change within the network, given the relationship of the `Client`s,
     /// hence the context is returned separately to allow for possible modifications.
-    fn children(&self, ctx: Self::Ctx) -> ClientsWithCtx<Self::Ctx, Self::Msg>;
+    fn children(&self, ctx: Self::Ctx) -> ClientsWithCtx<'_, Self::Ctx, Self::Msg>;
 
     /// Return all of the messages that Self wants to send to the server.
     /// Should **not** include the messages of any peer `Client`s.
@@ -48,10 +48,11 @@ pub trait Server {
 // If this were part of the normal `Server` definition, these generics
 // would need to be specified by the `Client`s, which we don't want.
 pub trait ServerHandler: Server {
+    /// The error type reported by the server's state.
+    type Error;
+
     /// Returns the server's state
-    // TODO: Add proper return types
-    #[allow(clippy::result_unit_err)]
-    fn state(&self) -> Result<(), ()>;
+    fn state(&self) -> Result<(), Self::Error>;
 
     /// Resets the server's state
     fn reset(&mut self);
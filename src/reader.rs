@@ -1,55 +1,809 @@
-use crate::checker::Checker;
-use crate::expr::{bool::Boolean, CommonExpr, Expression};
+use crate::checker::{
+    CheckError, Checkable, Checker, Clause, Condition, Dependencies, Message, Signal,
+};
+use crate::expr::{
+    bool::Boolean, common::Common, render, window::Window, CommonExpr, Dialect, ExprType,
+    Expression, Nullability, Projection,
+};
+use crate::protocol::{Client, ClientsWithCtx};
+use crate::row::FromRow;
 
-// The query that is being built
-type Q = ();
+/// The query that is being built
+#[derive(Default)]
+pub(crate) struct Query {
+    table: Option<String>,
+    joins: Vec<Join>,
+    filters: Vec<Box<dyn Expression>>,
+    group_by: Vec<Box<dyn Expression>>,
+    windows: Vec<(String, Window)>,
+}
 
-// The context/state of the reader.
-// Used for validating expressions
-type T = ();
+impl Query {
+    fn display(&self, projection: &str, dialect: Dialect) -> String {
+        let mut out = format!("SELECT {projection}");
 
-// TODO: Remove Q and T generics once their types are settled
-pub struct Reader<'c, C> {
-    pub(super) checker: &'c mut C,
-    pub(super) state: T,
-    pub(super) query: Q,
+        if let Some(table) = &self.table {
+            out += &format!(" FROM {table}");
+        }
+
+        for join in &self.joins {
+            let keyword = match join.outer {
+                true => "LEFT JOIN",
+                false => "JOIN",
+            };
+            out += &format!(" {keyword} {} ON {}", join.table, join.on.display(dialect));
+        }
+
+        out += &display_filters(&self.filters, dialect);
+
+        if !self.group_by.is_empty() {
+            let exprs: Vec<_> = self.group_by.iter().map(|e| e.display(dialect)).collect();
+            out += &format!(" GROUP BY {}", exprs.join(", "));
+        }
+
+        if !self.windows.is_empty() {
+            let windows: Vec<_> = self
+                .windows
+                .iter()
+                .map(|(name, window)| format!("{name} AS ({})", window.definition(dialect)))
+                .collect();
+            out += &format!(" WINDOW {}", windows.join(", "));
+        }
+
+        out
+    }
 }
 
-pub struct SealedReader<T> {
-    state: T,
+/// A table joined with the current one.
+struct Join {
+    table: String,
+    on: Box<dyn Expression>,
+    /// Whether the rows without a match are kept, with `NULL` in the joined columns
+    outer: bool,
 }
 
-impl<'c, C> Reader<'c, C> {
-    pub fn new(checker: &'c mut C) -> Self
-    where
-        C: Checker,
-    {
+pub struct Reader<'c, C> {
+    pub(super) checker: &'c mut C,
+    pub(super) query: Query,
+}
+
+impl<'c, C: Checker> Reader<'c, C> {
+    pub fn new(checker: &'c mut C) -> Self {
+        checker.reset();
         Reader {
             checker,
-            state: T::default(),
-            query: Q::default(),
+            query: Query::default(),
         }
     }
 
     /// Adds a table into its state
-    pub fn table(self, _id: &str) -> Self {
-        todo!()
+    pub fn table(mut self, id: &str) -> Self {
+        self.checker
+            .accept(Condition::TableExists(id.to_string()).into());
+        self.query.table = Some(id.to_string());
+        self
+    }
+
+    /// Joins another table into the query, keeping the pairs of rows that match the condition.
+    /// Its columns are referred to as `table.col`, and must be used after this call.
+    pub fn join<T: Boolean + 'static>(self, table: &str, on: CommonExpr<T>) -> Self {
+        self.add_join(table, on, Nullability::NotNull)
+    }
+
+    /// Joins another table like `join`, also keeping the rows of the current table that have no
+    /// match, `LEFT JOIN`. The columns of the joined table are then `NULL`, hence nullable.
+    pub fn left_join<T: Boolean + 'static>(self, table: &str, on: CommonExpr<T>) -> Self {
+        self.add_join(table, on, Nullability::Nullable)
+    }
+
+    fn add_join<T: Boolean + 'static>(
+        mut self,
+        table: &str,
+        on: CommonExpr<T>,
+        nullability: Nullability,
+    ) -> Self {
+        self.checker
+            .accept(Condition::TableJoined(table.to_string(), nullability).into());
+        self.checker
+            .accept(Signal::StartClause(Clause::Join).into());
+        on.send_all(ExprType::Bool, self.checker);
+        self.checker.accept(Signal::EndClause.into());
+        self.query.joins.push(Join {
+            table: table.to_string(),
+            on: Box::new(on),
+            outer: nullability != Nullability::NotNull,
+        });
+        self
     }
 
     /// Selects the given rows for reading, returns a `SealedReader` that cannot be internally
     /// modified further.
-    pub fn select(self, expr: impl Expression) -> Result<SealedReader<T>, String> {
-        todo!()
+    pub fn select(self, expr: impl Projection + 'static) -> Result<SealedReader, String> {
+        expr.send_all(ExprType::Any, self.checker);
+        self.checker.state().map_err(join_errors)?;
+
+        let columns = output_cols(&expr, self.checker);
+
+        Ok(SealedReader::new(
+            Body::Select {
+                query: self.query,
+                projection: Box::new(expr),
+            },
+            Some(columns),
+            self.checker,
+        ))
+    }
+
+    /// Selects the given rows for reading like `select`, and checks that they can be decoded into
+    /// `T`.
+    pub fn select_into<T: FromRow>(
+        self,
+        expr: impl Projection + 'static,
+    ) -> Result<SealedReader, String> {
+        let reader = self.select(expr)?;
+        reader.check_row::<T>()?;
+        Ok(reader)
     }
 
     /// Selects all rows for reading, returns a `SealedReader` that cannot be internally modified
     /// further.
-    pub fn select_all(self) -> Result<SealedReader<T>, String> {
-        todo!()
+    pub fn select_all(self) -> Result<SealedReader, String> {
+        self.checker.state().map_err(join_errors)?;
+
+        let columns = self.checker.table_cols().map(|cols| {
+            cols.into_iter()
+                .map(|col| {
+                    OutputCol::new(Some(col.name().to_string()), col.kind(), col.nullability())
+                })
+                .collect()
+        });
+
+        Ok(SealedReader::new(
+            Body::Select {
+                query: self.query,
+                projection: Box::new(Wildcard),
+            },
+            columns,
+            self.checker,
+        ))
     }
 
     /// Filters the rows in the current table
-    pub fn filter<T: Boolean>(self, bool_expr: CommonExpr<T>) -> Self {
-        todo!()
+    pub fn filter<T: Boolean + 'static>(mut self, bool_expr: CommonExpr<T>) -> Self {
+        self.checker
+            .accept(Signal::StartClause(Clause::Filter).into());
+        bool_expr.send_all(ExprType::Bool, self.checker);
+        self.checker.accept(Signal::EndClause.into());
+        self.query.filters.push(Box::new(bool_expr));
+        self
+    }
+
+    /// Groups the rows by the given expression
+    pub fn group_by<T: Common + 'static>(mut self, expr: CommonExpr<T>) -> Self {
+        self.checker
+            .accept(Signal::StartClause(Clause::GroupBy).into());
+        expr.send_all(ExprType::Any, self.checker);
+        self.checker.accept(Signal::EndClause.into());
+        self.query.group_by.push(Box::new(expr));
+        self
+    }
+
+    /// Defines a named window, which window functions can refer to through [`Window::named`]
+    pub fn window(mut self, name: &str, window: Window) -> Self {
+        window.send_all(ExprType::Any, self.checker);
+        self.checker
+            .accept(Signal::DefineWindow(name.to_string()).into());
+        self.query.windows.push((name.to_string(), window));
+        self
+    }
+}
+
+/// Returns the columns of a projection, resolving column types through the checker.
+pub(crate) fn output_cols<C: Checker>(expr: &dyn Projection, checker: &C) -> Vec<OutputCol> {
+    expr.items()
+        .into_iter()
+        .map(|item| {
+            let kind = match item.eval_type() {
+                ExprType::Any => item
+                    .source()
+                    .and_then(|col| checker.col_type(col))
+                    .unwrap_or(ExprType::Any),
+                kind => kind,
+            };
+            let nullability = item.nullability(&|col| checker.col_nullability(col));
+            OutputCol::new(item.name().map(String::from), kind, nullability)
+        })
+        .collect()
+}
+
+/// Returns the `WHERE` clause of the filters, if there are any.
+pub(crate) fn display_filters(filters: &[Box<dyn Expression>], dialect: Dialect) -> String {
+    match filters {
+        [] => String::new(),
+        [filter] => format!(" WHERE {}", filter.display(dialect)),
+        filters => {
+            let filters: Vec<_> = filters
+                .iter()
+                .map(|f| format!("({})", f.display(dialect)))
+                .collect();
+            format!(" WHERE {}", filters.join(" AND "))
+        }
+    }
+}
+
+pub(crate) fn join_errors(errors: Vec<impl ToString>) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// The `*` projection of `SELECT *`.
+struct Wildcard;
+
+impl Client for Wildcard {
+    type Ctx = ExprType;
+    type Msg = Message;
+
+    fn children(&self, ctx: Self::Ctx) -> ClientsWithCtx<'_, Self::Ctx, Self::Msg> {
+        Vec::new()
+    }
+
+    fn messages(&self, ctx: Self::Ctx) -> Vec<Self::Msg> {
+        Vec::new()
+    }
+}
+impl Checkable for Wildcard {}
+impl Expression for Wildcard {
+    fn eval_type(&self) -> ExprType {
+        ExprType::Unique
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        "*".to_string()
+    }
+}
+
+/// A column returned by a `SealedReader`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputCol {
+    name: Option<String>,
+    kind: ExprType,
+    nullability: Nullability,
+}
+
+impl OutputCol {
+    pub fn new(name: Option<String>, kind: ExprType, nullability: Nullability) -> Self {
+        Self {
+            name,
+            kind,
+            nullability,
+        }
+    }
+
+    /// The name of the column, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The type of the column, as known by the checker when sealing.
+    pub fn kind(&self) -> ExprType {
+        self.kind
+    }
+
+    /// Whether the column may contain `NULL`s, as known by the checker when sealing.
+    /// Values of nullable columns are decoded as `Option<T>`.
+    pub fn nullability(&self) -> Nullability {
+        self.nullability
+    }
+}
+
+/// Set operations combining the rows of two queries.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SetOp {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
+}
+
+impl SetOp {
+    fn keyword(self) -> &'static str {
+        match self {
+            SetOp::Union => "UNION",
+            SetOp::UnionAll => "UNION ALL",
+            SetOp::Intersect => "INTERSECT",
+            SetOp::Except => "EXCEPT",
+        }
+    }
+}
+
+/// The direction of an `ORDER BY` term.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+pub(crate) enum Body {
+    Select {
+        query: Query,
+        projection: Box<dyn Expression>,
+    },
+    Compound(SetOp, Box<SealedReader>, Box<SealedReader>),
+}
+
+/// A finished query.
+///
+/// Only the ordering and limits of its final result can be modified.
+pub struct SealedReader {
+    body: Body,
+    /// The returned columns, `None` if they are unknown to the checker
+    columns: Option<Vec<OutputCol>>,
+    order_by: Vec<(String, Order)>,
+    limit: Option<u64>,
+    deps: Dependencies,
+    warnings: Vec<CheckError>,
+}
+
+impl SealedReader {
+    fn new<C: Checker>(body: Body, columns: Option<Vec<OutputCol>>, checker: &C) -> Self {
+        Self {
+            body,
+            columns,
+            order_by: Vec::new(),
+            limit: None,
+            deps: checker.dependencies(),
+            warnings: checker.warnings(),
+        }
+    }
+
+    /// The columns returned by the query, if they are known.
+    pub fn columns(&self) -> Option<&[OutputCol]> {
+        self.columns.as_deref()
+    }
+
+    /// The schema objects that the query reads.
+    pub fn dependencies(&self) -> &Dependencies {
+        &self.deps
+    }
+
+    /// The suspicious, but valid, uses that the checker found within the query.
+    pub fn warnings(&self) -> &[CheckError] {
+        &self.warnings
+    }
+
+    /// Combines the rows of both queries, removing duplicates.
+    pub fn union(self, other: SealedReader) -> Result<SealedReader, String> {
+        self.combine(SetOp::Union, other)
+    }
+
+    /// Combines the rows of both queries, keeping duplicates.
+    pub fn union_all(self, other: SealedReader) -> Result<SealedReader, String> {
+        self.combine(SetOp::UnionAll, other)
+    }
+
+    /// Keeps the rows that are returned by both queries.
+    pub fn intersect(self, other: SealedReader) -> Result<SealedReader, String> {
+        self.combine(SetOp::Intersect, other)
+    }
+
+    /// Keeps the rows that are not returned by the other query.
+    pub fn except(self, other: SealedReader) -> Result<SealedReader, String> {
+        self.combine(SetOp::Except, other)
+    }
+
+    /// Combines both queries with the given set operation.
+    ///
+    /// Both queries must return the same number of columns with compatible types, as found by
+    /// their checkers when they were sealed. The combined columns take their names from `self`.
+    ///
+    /// Queries whose columns are unknown, such as `select_all` on a table that the checker only
+    /// knows through its uses, can't be verified and are rejected.
+    pub fn combine(self, op: SetOp, other: SealedReader) -> Result<SealedReader, String> {
+        let columns = match (&self.columns, &other.columns) {
+            (Some(lhs), Some(rhs)) => {
+                if lhs.len() != rhs.len() {
+                    return Err(format!(
+                        "{} of queries with {} and {} columns",
+                        op.keyword(),
+                        lhs.len(),
+                        rhs.len()
+                    ));
+                }
+                let columns = lhs
+                    .iter()
+                    .zip(rhs)
+                    .enumerate()
+                    .map(|(i, (l, r))| {
+                        let nullability = match op {
+                            SetOp::Except => l.nullability,
+                            _ => l.nullability.either(r.nullability),
+                        };
+                        ExprType::try_fold(l.kind, r.kind)
+                            .map(|kind| OutputCol::new(l.name.clone(), kind, nullability))
+                            .map_err(|(expected, found)| {
+                                format!(
+                                    "{} type mismatch in column {}: expected {expected:?}, found {found:?}",
+                                    op.keyword(),
+                                    i + 1
+                                )
+                            })
+                    })
+                    .collect::<Result<_, _>>()?;
+                Some(columns)
+            }
+            _ => {
+                return Err(format!(
+                    "the columns of both sides of a {} must be known, select them explicitly",
+                    op.keyword()
+                ))
+            }
+        };
+
+        let deps = self.deps.clone().merge(other.deps.clone());
+        let warnings = [self.warnings.as_slice(), &other.warnings].concat();
+        Ok(SealedReader {
+            body: Body::Compound(op, Box::new(self), Box::new(other)),
+            columns,
+            order_by: Vec::new(),
+            limit: None,
+            deps,
+            warnings,
+        })
+    }
+
+    /// Orders the result by the given column in ascending order.
+    pub fn order_by(mut self, col: impl Into<String>) -> Self {
+        self.order_by.push((col.into(), Order::Asc));
+        self
+    }
+
+    /// Orders the result by the given column in descending order.
+    pub fn order_by_desc(mut self, col: impl Into<String>) -> Self {
+        self.order_by.push((col.into(), Order::Desc));
+        self
+    }
+
+    /// Limits the number of returned rows.
+    pub fn limit(mut self, n: u64) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Returns the SQL query in the given dialect, or an error if the dialect can't express it.
+    pub fn to_sql(&self, dialect: Dialect) -> Result<String, String> {
+        render::check(dialect, || Ok(self.display(dialect)))
+    }
+
+    /// Returns the SQL query, leaving any rendering errors to the statement being rendered.
+    pub(crate) fn display(&self, dialect: Dialect) -> String {
+        let mut out = match &self.body {
+            Body::Select { query, projection } => {
+                query.display(&projection.display(dialect), dialect)
+            }
+            Body::Compound(op, lhs, rhs) => {
+                let lhs = match &lhs.body {
+                    // compounds are evaluated left to right within the same operation
+                    Body::Compound(lhs_op, ..) if lhs_op == op && !lhs.has_modifiers() => {
+                        lhs.display(dialect)
+                    }
+                    Body::Select { .. } if !lhs.has_modifiers() => lhs.display(dialect),
+                    _ => lhs.grouped(dialect),
+                };
+                let rhs = match &rhs.body {
+                    Body::Select { .. } if !rhs.has_modifiers() => rhs.display(dialect),
+                    _ => rhs.grouped(dialect),
+                };
+                format!("{lhs} {} {rhs}", op.keyword())
+            }
+        };
+
+        if !self.order_by.is_empty() {
+            let terms: Vec<_> = self
+                .order_by
+                .iter()
+                .map(|(col, order)| match order {
+                    Order::Asc => col.to_string(),
+                    Order::Desc => format!("{col} DESC"),
+                })
+                .collect();
+            out += &format!(" ORDER BY {}", terms.join(", "));
+        }
+
+        if let Some(n) = self.limit {
+            out += &format!(" LIMIT {n}");
+        }
+
+        out
+    }
+
+    /// Whether the query has an `ORDER BY` or `LIMIT` applied to it.
+    fn has_modifiers(&self) -> bool {
+        !self.order_by.is_empty() || self.limit.is_some()
+    }
+
+    /// Returns the SQL query grouped so that it can be used as a set operation member.
+    fn grouped(&self, dialect: Dialect) -> String {
+        match dialect {
+            Dialect::Postgres | Dialect::MySql => format!("({})", self.display(dialect)),
+            // SQLite does not allow parenthesized compound members
+            Dialect::Sqlite => format!("SELECT * FROM ({})", self.display(dialect)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::CheckError;
+    use crate::checker::{CompiledChecker, DerivedChecker};
+    use crate::expr::{
+        agg::{count_all, sum},
+        any::{col, NULL},
+        common::Common,
+        num::Numeric,
+        window::{rank, Window, WindowFunction},
+    };
+    use crate::multi;
+    use crate::schema::{Column, CompiledSchema, Table};
+
+    fn schema() -> CompiledSchema {
+        CompiledSchema::new()
+            .with_table(
+                Table::new("staff")
+                    .with_column("name", ExprType::Text)
+                    .with_column("age", ExprType::Num),
+            )
+            .with_table(
+                Table::new("clients")
+                    .with_column("name", ExprType::Text)
+                    .with_column("age", ExprType::Num),
+            )
+    }
+
+    #[test]
+    fn select() {
+        let mut checker = DerivedChecker::new();
+        let query = Reader::new(&mut checker)
+            .table("staff")
+            .filter(col("age").gt(18))
+            .select(multi![col("name"), col("age")])
+            .unwrap();
+        assert_eq!(
+            query.to_sql(Dialect::Postgres).unwrap(),
+            "SELECT name, age FROM staff WHERE age > 18"
+        );
+        assert_eq!(
+            query.columns().unwrap()[1],
+            OutputCol::new(Some("age".into()), ExprType::Num, Nullability::Nullable)
+        );
+    }
+
+    #[test]
+    fn nullability() {
+        let schema = CompiledSchema::new().with_table(
+            Table::new("staff")
+                .with(Column::new("id", ExprType::Num).not_null())
+                .with_column("manager_id", ExprType::Num),
+        );
+        let mut checker = CompiledChecker::new(&schema);
+        let query = Reader::new(&mut checker)
+            .table("staff")
+            .select(multi![
+                col("id"),
+                col("id") + col("manager_id"),
+                col("manager_id").coalesce(0),
+                col("manager_id").is_null()
+            ])
+            .unwrap();
+        let nullability: Vec<_> = query
+            .columns()
+            .unwrap()
+            .iter()
+            .map(|c| c.nullability())
+            .collect();
+        assert_eq!(
+            nullability,
+            [
+                Nullability::NotNull,
+                Nullability::Nullable,
+                Nullability::NotNull,
+                Nullability::NotNull
+            ]
+        );
+
+        // comparisons against `NULL` are valid, but never true
+        let null_cmp = Reader::new(&mut checker)
+            .table("staff")
+            .filter(Numeric::eq(col("manager_id"), NULL))
+            .select_all()
+            .unwrap();
+        assert_eq!(null_cmp.warnings(), [CheckError::NullComparison]);
+    }
+
+    #[test]
+    fn joins() {
+        let schema = CompiledSchema::new()
+            .with_table(
+                Table::new("staff")
+                    .with(Column::new("id", ExprType::Num).not_null())
+                    .with_column("team_id", ExprType::Num),
+            )
+            .with_table(
+                Table::new("teams")
+                    .with(Column::new("id", ExprType::Num).not_null())
+                    .with(Column::new("name", ExprType::Text).not_null()),
+            );
+        let mut checker = CompiledChecker::new(&schema);
+        let nullability = |query: &SealedReader| -> Vec<_> {
+            query
+                .columns()
+                .unwrap()
+                .iter()
+                .map(|c| c.nullability())
+                .collect()
+        };
+
+        let query = Reader::new(&mut checker)
+            .table("staff")
+            .join("teams", Numeric::eq(col("team_id"), col("teams.id")))
+            .select(multi![col("id"), col("teams.name")])
+            .unwrap();
+        assert_eq!(
+            query.to_sql(Dialect::Postgres).unwrap(),
+            "SELECT id, teams.name FROM staff JOIN teams ON team_id = teams.id"
+        );
+        assert_eq!(
+            nullability(&query),
+            [Nullability::NotNull, Nullability::NotNull]
+        );
+
+        // staff without a team have `NULL` team names
+        let query = Reader::new(&mut checker)
+            .table("staff")
+            .left_join("teams", Numeric::eq(col("team_id"), col("teams.id")))
+            .select(multi![col("id"), col("teams.name")])
+            .unwrap();
+        assert_eq!(
+            query.to_sql(Dialect::Postgres).unwrap(),
+            "SELECT id, teams.name FROM staff LEFT JOIN teams ON team_id = teams.id"
+        );
+        assert_eq!(
+            nullability(&query),
+            [Nullability::NotNull, Nullability::Nullable]
+        );
+
+        let unknown = Reader::new(&mut checker)
+            .table("staff")
+            .left_join("teams", Numeric::eq(col("team_id"), col("teams.size")))
+            .select(multi![col("id")]);
+        assert_eq!(
+            unknown.err().unwrap(),
+            "unknown column `size` in table `teams`"
+        );
+    }
+
+    #[test]
+    fn set_ops() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let staff = Reader::new(&mut checker)
+            .table("staff")
+            .select(multi![col("name"), col("age")])
+            .unwrap();
+        let clients = Reader::new(&mut checker)
+            .table("clients")
+            .select(multi![col("name"), col("age")])
+            .unwrap()
+            .order_by("age")
+            .limit(10);
+        let young = Reader::new(&mut checker)
+            .table("clients")
+            .filter(col("age").lt(30))
+            .select(multi![col("name"), col("age")])
+            .unwrap();
+
+        let query = staff
+            .union(clients)
+            .unwrap()
+            .intersect(young)
+            .unwrap()
+            .order_by_desc("name");
+
+        assert_eq!(
+            query.to_sql(Dialect::Postgres).unwrap(),
+            "(SELECT name, age FROM staff UNION (SELECT name, age FROM clients ORDER BY age LIMIT 10)) \
+             INTERSECT SELECT name, age FROM clients WHERE age < 30 ORDER BY name DESC"
+        );
+        assert_eq!(
+            query.to_sql(Dialect::Sqlite).unwrap(),
+            "SELECT * FROM (SELECT name, age FROM staff UNION SELECT * FROM (SELECT name, age FROM clients ORDER BY age LIMIT 10)) \
+             INTERSECT SELECT name, age FROM clients WHERE age < 30 ORDER BY name DESC"
+        );
+    }
+
+    #[test]
+    fn set_op_mismatch() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let names = Reader::new(&mut checker)
+            .table("staff")
+            .select(col("name"))
+            .unwrap();
+        let ages = Reader::new(&mut checker)
+            .table("clients")
+            .select(col("age"))
+            .unwrap();
+        let all = Reader::new(&mut checker)
+            .table("clients")
+            .select_all()
+            .unwrap();
+
+        assert!(names.union(ages).is_err());
+        let names = Reader::new(&mut checker)
+            .table("staff")
+            .select(col("name"))
+            .unwrap();
+        assert!(names.except(all).is_err());
+
+        // columns derived from their uses are never fully known
+        let mut checker = DerivedChecker::new();
+        let names = Reader::new(&mut checker)
+            .table("staff")
+            .select(col("name"))
+            .unwrap();
+        let all = Reader::new(&mut checker)
+            .table("staff")
+            .select_all()
+            .unwrap();
+        assert_eq!(
+            names.union(all).err().unwrap(),
+            "the columns of both sides of a UNION must be known, select them explicitly"
+        );
+    }
+
+    #[test]
+    fn windows() {
+        let mut checker = DerivedChecker::new();
+        let query = Reader::new(&mut checker)
+            .table("sales")
+            .window("w", Window::new().partition_by(col("region")))
+            .select(multi![
+                col("region"),
+                sum(col("amount")).over(Window::named("w").order_by(col("day"))),
+                rank().over(Window::named("w").order_by_desc(col("amount")))
+            ])
+            .unwrap();
+        assert_eq!(
+            query.to_sql(Dialect::Postgres).unwrap(),
+            "SELECT region, SUM(amount) OVER (w ORDER BY day), RANK() OVER (w ORDER BY amount DESC) \
+             FROM sales WINDOW w AS (PARTITION BY region)"
+        );
+
+        let grouped = Reader::new(&mut checker)
+            .table("sales")
+            .group_by(col("region"))
+            .select(multi![col("region"), count_all()])
+            .unwrap();
+        assert_eq!(
+            grouped.to_sql(Dialect::Sqlite).unwrap(),
+            "SELECT region, COUNT(*) FROM sales GROUP BY region"
+        );
+
+        let misplaced = Reader::new(&mut checker)
+            .table("sales")
+            .filter(rank().over(Window::new().order_by(col("amount"))).leq(3))
+            .select_all();
+        assert_eq!(
+            misplaced.err().unwrap(),
+            CheckError::MisplacedWindow(Clause::Filter).to_string()
+        );
+
+        let unknown = Reader::new(&mut checker)
+            .table("sales")
+            .select(rank().over(Window::named("w")));
+        assert!(unknown.is_err());
     }
 }
@@ -0,0 +1,357 @@
+use std::fmt::Display;
+
+use crate::expr::{ExprType, Nullability};
+use crate::reader::{join_errors, OutputCol, SealedReader};
+
+/// A value within a row returned by a query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    /// Text, along with dates, times and timestamps in their ISO 8601 form
+    Text(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "NULL"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Int(i) => write!(f, "{i}"),
+            Value::Float(x) => write!(f, "{x}"),
+            Value::Text(s) => write!(f, "'{}'", s.replace('\'', "''")),
+        }
+    }
+}
+
+/// A row returned by a query, whose values are in the order of its columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    columns: Vec<String>,
+    values: Vec<Value>,
+}
+
+impl Row {
+    /// Creates a row, or returns an error unless there is a value for each column.
+    pub fn new(columns: Vec<String>, values: Vec<Value>) -> Result<Self, String> {
+        if columns.len() != values.len() {
+            return Err(format!(
+                "a row of {} columns with {} values",
+                columns.len(),
+                values.len()
+            ));
+        }
+        Ok(Self { columns, values })
+    }
+
+    /// The names of the columns of the row.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// The values of the row.
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// The value of the given column, if the row has it.
+    pub fn value(&self, col: &str) -> Option<&Value> {
+        let i = self.columns.iter().position(|c| c == col)?;
+        Some(&self.values[i])
+    }
+
+    /// Decodes the value of the given column.
+    pub fn get<T: FromValue>(&self, col: &str) -> Result<T, String> {
+        let value = self
+            .value(col)
+            .ok_or_else(|| format!("the row has no column `{col}`"))?;
+        T::from_value(value).map_err(|e| format!("column `{col}`: {e}"))
+    }
+}
+
+/// Types that a single value can be decoded into.
+pub trait FromValue: Sized {
+    /// The type of the columns that are decoded into `Self`.
+    const KIND: ExprType;
+    /// Whether `NULL` can be decoded into `Self`.
+    const NULLABLE: bool = false;
+
+    fn from_value(value: &Value) -> Result<Self, String>;
+}
+
+fn mismatch<T>(expected: &str, value: &Value) -> Result<T, String> {
+    Err(format!("expected {expected}, found {value}"))
+}
+
+impl FromValue for bool {
+    const KIND: ExprType = ExprType::Bool;
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Bool(b) => Ok(*b),
+            // booleans are stored as 0 and 1 by SQLite and MySql
+            Value::Int(0) => Ok(false),
+            Value::Int(1) => Ok(true),
+            value => mismatch("a boolean", value),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl FromValue for $t {
+            const KIND: ExprType = ExprType::Num;
+
+            fn from_value(value: &Value) -> Result<Self, String> {
+                let int = match value {
+                    Value::Int(i) => *i,
+                    Value::Float(x) if x.fract() == 0.0 => *x as i64,
+                    value => return mismatch("an integer", value),
+                };
+                <$t>::try_from(int).map_err(|_| {
+                    format!("{int} is out of range for {}", stringify!($t))
+                })
+            }
+        })*
+    };
+}
+
+from_int!(i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+impl FromValue for f64 {
+    const KIND: ExprType = ExprType::Num;
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Int(i) => Ok(*i as f64),
+            Value::Float(x) => Ok(*x),
+            value => mismatch("a number", value),
+        }
+    }
+}
+
+impl FromValue for f32 {
+    const KIND: ExprType = ExprType::Num;
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        f64::from_value(value).map(|x| x as f32)
+    }
+}
+
+impl FromValue for String {
+    const KIND: ExprType = ExprType::Text;
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Text(s) => Ok(s.clone()),
+            value => mismatch("text", value),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    const KIND: ExprType = T::KIND;
+    const NULLABLE: bool = true;
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Null => Ok(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+}
+
+/// A column that a `FromRow` type decodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowColumn {
+    name: &'static str,
+    kind: ExprType,
+    nullable: bool,
+}
+
+impl RowColumn {
+    pub fn new(name: &'static str, kind: ExprType, nullable: bool) -> Self {
+        Self {
+            name,
+            kind,
+            nullable,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn kind(&self) -> ExprType {
+        self.kind
+    }
+
+    /// Whether `NULL`s can be decoded.
+    pub fn nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Checks that the values of the output column can be decoded.
+    fn check(&self, col: &OutputCol) -> Result<(), String> {
+        let name = self.name;
+        if col.kind() != self.kind && col.kind() != ExprType::Any {
+            return Err(format!(
+                "field `{name}` decodes {:?}, but the column is {:?}",
+                self.kind,
+                col.kind()
+            ));
+        }
+        if col.nullability() != Nullability::NotNull && !self.nullable {
+            return Err(format!(
+                "column `{name}` may be NULL, but its field is not an `Option`"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Types that rows can be decoded into, usually through `#[derive(FromRow)]`.
+pub trait FromRow: Sized {
+    /// The columns that are decoded, one for each field.
+    fn columns() -> Vec<RowColumn>;
+
+    fn from_row(row: &Row) -> Result<Self, String>;
+}
+
+impl SealedReader {
+    /// Checks that the rows of the query can be decoded into `T`, so that a query which changes
+    /// without its consumer fails early rather than when its rows are read.
+    ///
+    /// Every field of `T` needs a column of the same name and type, which isn't nullable unless
+    /// the field is an `Option`. Columns without a field are ignored.
+    pub fn check_row<T: FromRow>(&self) -> Result<(), String> {
+        let Some(cols) = self.columns() else {
+            return Err(format!(
+                "the columns of the query are unknown, hence can't be decoded into `{}`",
+                std::any::type_name::<T>()
+            ));
+        };
+
+        let errors: Vec<_> = T::columns()
+            .iter()
+            .filter_map(
+                |field| match cols.iter().find(|col| col.name() == Some(field.name())) {
+                    Some(col) => field.check(col).err(),
+                    None => Some(format!("field `{}` has no column", field.name())),
+                },
+            )
+            .collect();
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(join_errors(errors)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::CompiledChecker;
+    use crate::expr::{any::col, common::Common, text::Textual};
+    use crate::multi;
+    use crate::reader::Reader;
+    use crate::schema::{Column, CompiledSchema, Table};
+
+    #[derive(crate::FromRow, Debug, PartialEq)]
+    struct User {
+        id: i64,
+        #[column(name = "username")]
+        name: String,
+        age: Option<u8>,
+        #[column(timestamp)]
+        joined: String,
+    }
+
+    fn schema() -> CompiledSchema {
+        CompiledSchema::new().with_table(
+            Table::new("users")
+                .with(Column::new("id", ExprType::Num).not_null())
+                .with(Column::new("name", ExprType::Text).not_null())
+                .with_column("age", ExprType::Num)
+                .with(Column::new("joined", ExprType::Timestamp).not_null()),
+        )
+    }
+
+    #[test]
+    fn from_row() {
+        let row = Row::new(
+            vec![
+                "id".into(),
+                "username".into(),
+                "age".into(),
+                "joined".into(),
+            ],
+            vec![
+                Value::Int(1),
+                Value::Text("ann".into()),
+                Value::Null,
+                Value::Text("2024-01-01 00:00:00".into()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            User::from_row(&row),
+            Ok(User {
+                id: 1,
+                name: "ann".into(),
+                age: None,
+                joined: "2024-01-01 00:00:00".into(),
+            })
+        );
+
+        assert_eq!(
+            Row::new(vec!["id".into()], Vec::new()),
+            Err("a row of 1 columns with 0 values".to_string())
+        );
+        let row = Row::new(vec!["id".into()], vec![Value::Text("1".into())]).unwrap();
+        assert_eq!(
+            User::from_row(&row),
+            Err("column `id`: expected an integer, found '1'".to_string())
+        );
+        assert_eq!(
+            u8::from_value(&Value::Int(300)),
+            Err("300 is out of range for u8".to_string())
+        );
+    }
+
+    #[test]
+    fn check_row() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+
+        let reader = Reader::new(&mut checker)
+            .table("users")
+            .select_into::<User>(multi![
+                col("id"),
+                col("name").alias("username"),
+                col("age"),
+                col("joined")
+            ]);
+        assert!(reader.is_ok());
+
+        let reader = Reader::new(&mut checker)
+            .table("users")
+            .select_into::<User>(multi![
+                col("id").alias("joined"),
+                col("name").upper().alias("username"),
+                col("age").alias("id")
+            ]);
+        assert_eq!(
+            reader.map(|_| ()),
+            Err(
+                "column `id` may be NULL, but its field is not an `Option`; \
+                 field `age` has no column; \
+                 field `joined` decodes Timestamp, but the column is Num"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -1,12 +1,298 @@
+use std::collections::HashMap;
+
+use crate::expr::{Dialect, ExprType, Nullability};
+use crate::writer::CreateTableStatement;
+
 /// Compiled schema
-pub struct CompiledSchema;
+///
+/// A known copy of the database's schema, used to validate every table and column access.
+#[derive(Debug, Default, Clone)]
+pub struct CompiledSchema {
+    tables: Vec<Table>,
+}
+
+impl CompiledSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a table into the schema, replacing any previous table with the same name.
+    pub fn with_table(mut self, table: Table) -> Self {
+        self.insert(table);
+        self
+    }
+
+    /// Adds the table of a [`TableDef`] into the schema, replacing any previous table with the
+    /// same name.
+    pub fn with_def<T: TableDef>(self) -> Self {
+        self.with_table(T::table())
+    }
+
+    /// Adds a table into the schema, replacing any previous table with the same name.
+    pub fn insert(&mut self, table: Table) {
+        match self.tables.iter_mut().find(|t| t.name == table.name) {
+            Some(existing) => *existing = table,
+            None => self.tables.push(table),
+        }
+    }
+
+    /// Returns the table with the given name.
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables.iter().find(|t| t.name == name)
+    }
+
+    pub fn tables(&self) -> &[Table] {
+        &self.tables
+    }
+
+    /// The `CREATE TABLE` statements that create the schema within an empty database, such as
+    /// one for tests.
+    ///
+    /// The schema doesn't know the default values of its columns, hence columns with a default
+    /// are created as nullable ones.
+    pub fn to_sql(&self, dialect: Dialect) -> Result<Vec<String>, String> {
+        self.tables
+            .iter()
+            .map(|table| CreateTableStatement::from_table(table).display(dialect))
+            .collect()
+    }
+}
+
+/// Types that describe a table, usually through `#[derive(Table)]`.
+pub trait TableDef {
+    /// Returns the name of the table.
+    /// Unlike a constant, it can't clash with the column constants of a derived table.
+    fn table_name() -> &'static str;
+
+    /// Returns the table, to be added into a [`CompiledSchema`].
+    fn table() -> Table;
+}
+
+/// A table within a [`CompiledSchema`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    name: String,
+    columns: Vec<Column>,
+    /// The sets of columns with a `UNIQUE` or `PRIMARY KEY` constraint
+    uniques: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            columns: Vec::new(),
+            uniques: Vec::new(),
+        }
+    }
+
+    /// Adds a nullable column of the given type into the table.
+    pub fn with_column(self, name: impl Into<String>, kind: ExprType) -> Self {
+        self.with(Column::new(name, kind))
+    }
+
+    /// Adds a column into the table.
+    pub fn with(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Removes a column from the table, along with the unique constraints that include it.
+    pub fn without(mut self, name: &str) -> Self {
+        self.columns.retain(|c| c.name != name);
+        self.uniques
+            .retain(|unique| !unique.iter().any(|c| c == name));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the column with the given name.
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Adds a `UNIQUE` constraint over the given columns, primary keys included.
+    pub fn with_unique<S: Into<String>>(mut self, cols: impl IntoIterator<Item = S>) -> Self {
+        self.uniques
+            .push(cols.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn uniques(&self) -> &[Vec<String>] {
+        &self.uniques
+    }
+
+    /// Whether the columns exactly match a unique constraint, in any order.
+    pub fn is_unique(&self, cols: &[String]) -> bool {
+        self.uniques
+            .iter()
+            .any(|unique| unique.len() == cols.len() && unique.iter().all(|col| cols.contains(col)))
+    }
+}
+
+/// A column within a [`Table`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    name: String,
+    kind: ExprType,
+    not_null: bool,
+    has_default: bool,
+}
+
+impl Column {
+    /// Creates a nullable column.
+    pub fn new(name: impl Into<String>, kind: ExprType) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            not_null: false,
+            has_default: false,
+        }
+    }
+
+    /// Adds a `NOT NULL` constraint to the column.
+    pub fn not_null(mut self) -> Self {
+        self.not_null = true;
+        self
+    }
+
+    /// Marks the column as having a default value, so that inserts may omit it.
+    pub fn with_default(mut self) -> Self {
+        self.has_default = true;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.has_default
+    }
+
+    /// Whether inserts must supply a value for the column.
+    pub fn is_required(&self) -> bool {
+        self.not_null && !self.has_default
+    }
+
+    pub fn kind(&self) -> ExprType {
+        self.kind
+    }
+
+    pub fn nullability(&self) -> Nullability {
+        match self.not_null {
+            true => Nullability::NotNull,
+            false => Nullability::Nullable,
+        }
+    }
+}
 
 /// Derived Schema
-#[derive(Default)]
-pub struct DerivedSchema;
+///
+/// A schema that builds itself upon use. Columns start off with an unknown type
+/// (`ExprType::Any`) until their use within an expression determines it.
+#[derive(Debug, Default)]
+pub struct DerivedSchema {
+    tables: HashMap<String, HashMap<String, ExprType>>,
+}
 
 impl DerivedSchema {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            tables: HashMap::new(),
+        }
+    }
+
+    /// Returns the currently known type of a column.
+    pub fn column(&self, table: &str, col: &str) -> Option<ExprType> {
+        self.tables.get(table)?.get(col).copied()
+    }
+
+    /// Records the use of a column with the given type.
+    ///
+    /// Returns the `(Expected, Found)` types if the column was previously used with a
+    /// different type.
+    pub fn record(
+        &mut self,
+        table: &str,
+        col: &str,
+        kind: ExprType,
+    ) -> Result<(), (ExprType, ExprType)> {
+        let known = self
+            .tables
+            .entry(table.to_string())
+            .or_default()
+            .entry(col.to_string())
+            .or_insert(ExprType::Any);
+
+        *known = ExprType::try_fold(*known, kind)?;
+        Ok(())
+    }
+
+    /// Forgets a column, such as one that was dropped.
+    pub fn remove(&mut self, table: &str, col: &str) {
+        if let Some(cols) = self.tables.get_mut(table) {
+            cols.remove(col);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::CompiledChecker;
+    use crate::expr::{num::Numeric, text::Textual, Dialect};
+    use crate::multi;
+    use crate::reader::Reader;
+
+    #[derive(crate::Table)]
+    #[allow(dead_code)]
+    struct UserAccount {
+        #[column(default, unique)]
+        id: i64,
+        name: String,
+        age: Option<u8>,
+        #[column(name = "joined_at", timestamp)]
+        joined: String,
+    }
+
+    #[test]
+    fn derive_table() {
+        assert_eq!(UserAccount::table_name(), "user_account");
+        assert_eq!(
+            UserAccount::table(),
+            Table::new("user_account")
+                .with(Column::new("id", ExprType::Num).not_null().with_default())
+                .with_unique(["id"])
+                .with(Column::new("name", ExprType::Text).not_null())
+                .with_column("age", ExprType::Num)
+                .with(Column::new("joined_at", ExprType::Timestamp).not_null())
+        );
+
+        let schema = CompiledSchema::new().with_def::<UserAccount>();
+        let mut checker = CompiledChecker::new(&schema);
+        let reader = Reader::new(&mut checker)
+            .table(UserAccount::table_name())
+            .filter(UserAccount::AGE.gt(18))
+            .select(multi![UserAccount::NAME.upper(), UserAccount::JOINED])
+            .unwrap();
+        assert_eq!(
+            reader.to_sql(Dialect::Postgres).unwrap(),
+            "SELECT UPPER(name), joined_at FROM user_account WHERE age > 18"
+        );
+        assert_eq!(
+            schema.to_sql(Dialect::Sqlite).unwrap(),
+            [
+                "CREATE TABLE user_account (id NUMERIC, name TEXT NOT NULL, age NUMERIC, \
+              joined_at TIMESTAMP NOT NULL, UNIQUE (id))"
+            ]
+        );
     }
 }
@@ -0,0 +1,220 @@
+//! Snapshot tests of generated SQL.
+//!
+//! A snapshot is a file holding the expected output of a test, stored as
+//! `snapshots/<name>.snap` within the crate under test. Statements are rendered in every
+//! [`Dialect`] and their whitespace is normalized, so that only meaningful changes fail.
+//!
+//! Running the tests with `CRABQL_UPDATE_SNAPSHOTS=1` writes the current outputs into the
+//! snapshots instead of comparing against them, to create new snapshots or accept changes.
+
+use std::path::PathBuf;
+
+use crate::batch::SealedBatch;
+use crate::expr::{render, Dialect, Expression};
+use crate::reader::SealedReader;
+use crate::transaction::SealedTransaction;
+use crate::writer::SealedWriter;
+
+/// The environment variable that turns on the update mode.
+pub const UPDATE_VAR: &str = "CRABQL_UPDATE_SNAPSHOTS";
+
+/// Statements and expressions that can be rendered into SQL.
+pub trait Render {
+    fn render(&self, dialect: Dialect) -> Result<String, String>;
+}
+
+impl Render for SealedReader {
+    fn render(&self, dialect: Dialect) -> Result<String, String> {
+        self.to_sql(dialect)
+    }
+}
+
+impl Render for SealedWriter {
+    fn render(&self, dialect: Dialect) -> Result<String, String> {
+        self.to_sql(dialect)
+    }
+}
+
+impl Render for SealedBatch {
+    fn render(&self, dialect: Dialect) -> Result<String, String> {
+        self.to_sql(dialect)
+    }
+}
+
+impl Render for SealedTransaction {
+    fn render(&self, dialect: Dialect) -> Result<String, String> {
+        self.to_sql(dialect)
+    }
+}
+
+impl<T: Expression> Render for T {
+    fn render(&self, dialect: Dialect) -> Result<String, String> {
+        render::check(dialect, || Ok(self.display(dialect)))
+    }
+}
+
+/// Renders the item in every dialect, each under a `-- <Dialect>` header. Dialects that can't
+/// render the item show their error instead.
+pub fn render_all(item: &impl Render) -> String {
+    Dialect::ALL
+        .iter()
+        .map(|&dialect| {
+            let sql = match item.render(dialect) {
+                Ok(sql) => normalize(&sql),
+                Err(e) => format!("error: {e}"),
+            };
+            format!("-- {dialect:?}\n{sql}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collapses whitespace outside of quotes into single spaces, or into line breaks if it spans
+/// lines, so that statements stay on separate lines.
+pub fn normalize(sql: &str) -> String {
+    let mut out = String::new();
+    let mut quote = None;
+    let mut chars = sql.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, c) if c.is_whitespace() => {
+                let mut newline = c == '\n';
+                while let Some(c) = chars.next_if(|c| c.is_whitespace()) {
+                    newline |= c == '\n';
+                }
+                out.push(if newline { '\n' } else { ' ' });
+                continue;
+            }
+            _ => {}
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Renders the item in every dialect and compares it against the snapshot with the given name.
+#[track_caller]
+pub fn assert_sql_snapshot(name: &str, item: &impl Render) {
+    assert_snapshot(name, &render_all(item));
+}
+
+/// Compares the content against the snapshot with the given name, panicking with a diff if they
+/// differ. In the update mode the snapshot is written instead.
+#[track_caller]
+pub fn assert_snapshot(name: &str, content: &str) {
+    let path = snapshot_path(name);
+    let content = content.trim_end();
+
+    if std::env::var(UPDATE_VAR).is_ok_and(|v| v != "0") {
+        std::fs::create_dir_all(path.parent().expect("snapshots are within a directory"))
+            .and_then(|_| std::fs::write(&path, format!("{content}\n")))
+            .unwrap_or_else(|e| panic!("can't write snapshot `{}`: {e}", path.display()));
+        return;
+    }
+
+    let Ok(expected) = std::fs::read_to_string(&path) else {
+        panic!(
+            "snapshot `{}` doesn't exist, run with {UPDATE_VAR}=1 to create it with:\n{content}",
+            path.display()
+        );
+    };
+    let expected = expected.replace("\r\n", "\n");
+    let expected = expected.trim_end();
+    if expected != content {
+        panic!(
+            "snapshot `{}` doesn't match, run with {UPDATE_VAR}=1 to accept the changes:\n{}",
+            path.display(),
+            diff(expected, content)
+        );
+    }
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    let root = std::env::var_os("CARGO_MANIFEST_DIR").map_or_else(PathBuf::new, PathBuf::from);
+    root.join("snapshots").join(format!("{name}.snap"))
+}
+
+/// A line diff, prefixing removed lines with `-` and added lines with `+`.
+fn diff(old: &str, new: &str) -> String {
+    let (old, new): (Vec<_>, Vec<_>) = (old.lines().collect(), new.lines().collect());
+
+    // lengths of the longest common subsequences of the suffixes
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = match old[i] == new[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out.push(format!("  {}", old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == new.len() || (i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            out.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::CompiledChecker;
+    use crate::expr::ExprType;
+    use crate::expr::{any::col, num::Numeric};
+    use crate::schema::{Column, CompiledSchema, Table};
+    use crate::writer::Writer;
+
+    #[test]
+    fn normalize_whitespace() {
+        assert_eq!(
+            normalize("  SELECT a,\n\t b  FROM t\n\n  WHERE s = 'x  \n y';\n BEGIN "),
+            "SELECT a,\nb FROM t\nWHERE s = 'x  \n y';\nBEGIN"
+        );
+        assert_eq!(
+            normalize("SELECT \"a  b\",  `c  d`"),
+            "SELECT \"a  b\", `c  d`"
+        );
+    }
+
+    #[test]
+    fn line_diff() {
+        assert_eq!(
+            diff(
+                "-- Postgres\nSELECT a\n-- Sqlite",
+                "-- Postgres\nSELECT b\n-- Sqlite"
+            ),
+            "  -- Postgres\n- SELECT a\n+ SELECT b\n  -- Sqlite"
+        );
+        assert_eq!(diff("a", "a\nb"), "  a\n+ b");
+    }
+
+    #[test]
+    fn sql_snapshot() {
+        let schema = CompiledSchema::new().with_table(
+            Table::new("users")
+                .with(Column::new("id", ExprType::Num).not_null())
+                .with_column("name", ExprType::Text),
+        );
+        let mut checker = CompiledChecker::new(&schema);
+        let delete = Writer::new(&mut checker)
+            .delete_from("users")
+            .filter(Numeric::eq(col("id"), 1))
+            .returning(col("name"))
+            .seal()
+            .unwrap();
+        assert_sql_snapshot("delete_returning", &delete);
+    }
+}
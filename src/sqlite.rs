@@ -0,0 +1,255 @@
+use std::path::Path;
+
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{params_from_iter, Connection};
+
+use crate::expr::Dialect;
+use crate::reader::SealedReader;
+use crate::row::{FromRow, Row, Value};
+use crate::schema::CompiledSchema;
+use crate::writer::SealedWriter;
+
+impl From<&Value> for SqlValue {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Null => SqlValue::Null,
+            Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+            Value::Int(i) => SqlValue::Integer(*i),
+            Value::Float(x) => SqlValue::Real(*x),
+            Value::Text(s) => SqlValue::Text(s.clone()),
+        }
+    }
+}
+
+fn decode(value: ValueRef<'_>) -> Result<Value, String> {
+    match value {
+        ValueRef::Null => Ok(Value::Null),
+        ValueRef::Integer(i) => Ok(Value::Int(i)),
+        ValueRef::Real(x) => Ok(Value::Float(x)),
+        ValueRef::Text(s) => String::from_utf8(s.to_vec())
+            .map(Value::Text)
+            .map_err(|e| e.to_string()),
+        ValueRef::Blob(_) => Err("blobs are not supported".to_string()),
+    }
+}
+
+/// Executes sealed statements against an embedded SQLite database, such as an in-memory one for
+/// tests.
+///
+/// Statements are rendered in the SQLite dialect, and the given values are bound to their
+/// `param`s by index.
+pub struct SqliteExecutor {
+    conn: Connection,
+}
+
+impl SqliteExecutor {
+    /// Opens a fresh in-memory database.
+    pub fn open_in_memory() -> Result<Self, String> {
+        Connection::open_in_memory()
+            .map(Self::new)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Opens the database at the given path, creating it if it doesn't exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        Connection::open(path)
+            .map(Self::new)
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn new(conn: Connection) -> Self {
+        Self { conn }
+    }
+
+    /// The underlying connection, for anything that CrabQL doesn't cover.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Creates the tables of the schema, so that a fresh database matches it.
+    pub fn apply_schema(&self, schema: &CompiledSchema) -> Result<(), String> {
+        for sql in schema.to_sql(Dialect::Sqlite)? {
+            self.conn.execute(&sql, []).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Runs the query, returning its rows decoded into `T`.
+    pub fn query<T: FromRow>(
+        &self,
+        reader: &SealedReader,
+        params: &[Value],
+    ) -> Result<Vec<T>, String> {
+        reader.check_row::<T>()?;
+        self.query_rows(reader, params)?
+            .iter()
+            .map(T::from_row)
+            .collect()
+    }
+
+    /// Runs the query, returning its rows as they are.
+    pub fn query_rows(&self, reader: &SealedReader, params: &[Value]) -> Result<Vec<Row>, String> {
+        self.query_sql(&reader.to_sql(Dialect::Sqlite)?, params)
+    }
+
+    /// Runs the write statement, returning the number of rows that it changed.
+    pub fn execute(&self, writer: &SealedWriter, params: &[Value]) -> Result<usize, String> {
+        let mut changed = 0;
+        for sql in writer.to_sql_statements(Dialect::Sqlite)? {
+            changed += self
+                .conn
+                .execute(&sql, params_from_iter(params.iter().map(SqlValue::from)))
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(changed)
+    }
+
+    /// Runs the write statement, returning the rows of its `RETURNING` clause.
+    pub fn execute_returning(
+        &self,
+        writer: &SealedWriter,
+        params: &[Value],
+    ) -> Result<Vec<Row>, String> {
+        if writer.columns().is_none() {
+            return Err("the statement has no RETURNING clause".to_string());
+        }
+        self.query_sql(&writer.to_sql(Dialect::Sqlite)?, params)
+    }
+
+    /// Runs the SQL as it is, returning its rows, for statements that CrabQL can't build.
+    pub fn query_sql(&self, sql: &str, params: &[Value]) -> Result<Vec<Row>, String> {
+        let mut stmt = self.conn.prepare(sql).map_err(|e| e.to_string())?;
+        let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+        let mut rows = stmt
+            .query(params_from_iter(params.iter().map(SqlValue::from)))
+            .map_err(|e| e.to_string())?;
+        let mut out = Vec::new();
+        while let Some(row) = rows.next().map_err(|e| e.to_string())? {
+            let values = (0..columns.len())
+                .map(|i| decode(row.get_ref(i).map_err(|e| e.to_string())?))
+                .collect::<Result<_, _>>()?;
+            out.push(Row::new(columns.clone(), values)?);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::checker::CompiledChecker;
+    use crate::expr::ExprType;
+    use crate::expr::{
+        any::{col, param},
+        num::Numeric,
+    };
+    use crate::multi;
+    use crate::reader::Reader;
+    use crate::schema::Column;
+    use crate::writer::Writer;
+
+    #[derive(crate::Table, crate::FromRow, Debug, PartialEq)]
+    #[table(name = "users")]
+    struct User {
+        #[column(unique)]
+        id: i64,
+        name: String,
+        age: Option<u8>,
+        admin: bool,
+    }
+
+    #[test]
+    fn round_trip() {
+        let schema = CompiledSchema::new().with_def::<User>();
+        let db = SqliteExecutor::open_in_memory().unwrap();
+        db.apply_schema(&schema).unwrap();
+
+        let mut checker = CompiledChecker::new(&schema);
+        let insert = Writer::new(&mut checker)
+            .insert_into("users", ["id", "name", "age", "admin"])
+            .values(multi![
+                param(NonZeroUsize::MIN),
+                param(NonZeroUsize::new(2).unwrap()),
+                param(NonZeroUsize::new(3).unwrap()),
+                param(NonZeroUsize::new(4).unwrap())
+            ])
+            .seal()
+            .unwrap();
+        for (id, name, age, admin) in [
+            (1, "ann", Value::Int(30), true),
+            (2, "bob", Value::Null, false),
+        ] {
+            let params = [
+                Value::Int(id),
+                Value::Text(name.into()),
+                age,
+                Value::Bool(admin),
+            ];
+            assert_eq!(db.execute(&insert, &params), Ok(1));
+        }
+
+        let reader = Reader::new(&mut checker)
+            .table("users")
+            .filter(Numeric::ge(col("id"), param(NonZeroUsize::MIN)))
+            .select_into::<User>(multi![col("id"), col("name"), col("age"), col("admin")])
+            .unwrap();
+        assert_eq!(
+            db.query::<User>(&reader, &[Value::Int(1)]),
+            Ok(vec![
+                User {
+                    id: 1,
+                    name: "ann".into(),
+                    age: Some(30),
+                    admin: true,
+                },
+                User {
+                    id: 2,
+                    name: "bob".into(),
+                    age: None,
+                    admin: false,
+                },
+            ])
+        );
+
+        let delete = Writer::new(&mut checker)
+            .delete_from("users")
+            .filter(Numeric::eq(col("id"), param(NonZeroUsize::MIN)))
+            .returning(col("name"))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            db.execute_returning(&delete, &[Value::Int(2)]),
+            Ok(vec![Row::new(
+                vec!["name".into()],
+                vec![Value::Text("bob".into())]
+            )
+            .unwrap()])
+        );
+    }
+
+    #[test]
+    fn alter_table() {
+        let schema = CompiledSchema::new().with_def::<User>();
+        let db = SqliteExecutor::open_in_memory().unwrap();
+        db.apply_schema(&schema).unwrap();
+
+        // SQLite takes one statement per action
+        let mut checker = CompiledChecker::new(&schema);
+        let alter = Writer::new(&mut checker)
+            .alter_table("users")
+            .add_column(Column::new("email", ExprType::Text))
+            .drop_column("admin")
+            .seal()
+            .unwrap();
+        assert_eq!(db.execute(&alter, &[]), Ok(0));
+
+        let reader = Reader::new(&mut checker)
+            .table("users")
+            .select(multi![col("id"), col("email")])
+            .unwrap();
+        assert_eq!(db.query_rows(&reader, &[]), Ok(Vec::new()));
+    }
+}
@@ -0,0 +1,356 @@
+use crate::batch::{check_order, BatchItem, SealedBatch};
+use crate::expr::Dialect;
+
+/// How a transaction is isolated from concurrent ones.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    fn keyword(self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+enum Step {
+    Statement(BatchItem),
+    Savepoint(String),
+    Release(String),
+    RollbackTo(String),
+}
+
+/// Statements that are run in order as a single atomic script.
+///
+/// Like a [`crate::batch::Batch`], statements must come after the schema changes they depend on,
+/// statements that are rolled back to a savepoint don't apply their changes.
+#[derive(Default)]
+pub struct Transaction {
+    isolation: Option<IsolationLevel>,
+    read_only: bool,
+    steps: Vec<Step>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the isolation level of the transaction.
+    /// SQLite transactions are always serializable, which satisfies every level.
+    pub fn isolation(mut self, level: IsolationLevel) -> Self {
+        self.isolation = Some(level);
+        self
+    }
+
+    /// Only allows statements that read.
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /// Adds a statement after the previous ones.
+    pub fn then(mut self, item: impl Into<BatchItem>) -> Self {
+        self.steps.push(Step::Statement(item.into()));
+        self
+    }
+
+    /// Adds the statements of a batch after the previous ones.
+    pub fn batch(mut self, batch: SealedBatch) -> Self {
+        let statements = batch.into_statements().into_iter().map(Step::Statement);
+        self.steps.extend(statements);
+        self
+    }
+
+    /// Marks a point that the transaction can be rolled back to.
+    pub fn savepoint(mut self, name: &str) -> Self {
+        self.steps.push(Step::Savepoint(name.to_string()));
+        self
+    }
+
+    /// Forgets a savepoint, along with the savepoints created after it.
+    pub fn release(mut self, name: &str) -> Self {
+        self.steps.push(Step::Release(name.to_string()));
+        self
+    }
+
+    /// Undoes the statements run after a savepoint, the savepoint itself is kept.
+    pub fn rollback_to(mut self, name: &str) -> Self {
+        self.steps.push(Step::RollbackTo(name.to_string()));
+        self
+    }
+
+    /// Validates the savepoints and the ordering of the statements, returns a
+    /// `SealedTransaction` that cannot be internally modified further.
+    pub fn seal(self) -> Result<SealedTransaction, String> {
+        let mut errors = Vec::new();
+        // the active savepoints, with the index of the first statement after them
+        let mut savepoints: Vec<(&str, usize)> = Vec::new();
+        let mut statements: Vec<(&BatchItem, bool)> = Vec::new();
+
+        for step in &self.steps {
+            match step {
+                Step::Statement(item) => {
+                    if self.read_only && matches!(item, BatchItem::Write(_)) {
+                        errors.push(format!(
+                            "statement {} writes within a read only transaction",
+                            statements.len() + 1
+                        ));
+                    }
+                    statements.push((item, true));
+                }
+                Step::Savepoint(name) => {
+                    if savepoints.iter().any(|(active, _)| active == name) {
+                        errors.push(format!("savepoint `{name}` is already active"));
+                    }
+                    savepoints.push((name, statements.len()));
+                }
+                Step::Release(name) | Step::RollbackTo(name) => {
+                    let Some(pos) = savepoints.iter().rposition(|(active, _)| active == name)
+                    else {
+                        errors.push(format!("unknown savepoint `{name}`"));
+                        continue;
+                    };
+                    if let Step::RollbackTo(_) = step {
+                        for (_, kept) in &mut statements[savepoints[pos].1..] {
+                            *kept = false;
+                        }
+                        savepoints.truncate(pos + 1);
+                    } else {
+                        savepoints.truncate(pos);
+                    }
+                }
+            }
+        }
+        errors.extend(check_order(&statements));
+
+        match errors.is_empty() {
+            true => Ok(SealedTransaction {
+                isolation: self.isolation,
+                read_only: self.read_only,
+                steps: self.steps,
+            }),
+            false => Err(errors.join("; ")),
+        }
+    }
+}
+
+/// A finished transaction.
+pub struct SealedTransaction {
+    isolation: Option<IsolationLevel>,
+    read_only: bool,
+    steps: Vec<Step>,
+}
+
+impl SealedTransaction {
+    /// Returns the statements that start the transaction.
+    fn begin(&self, dialect: Dialect) -> Vec<String> {
+        match dialect {
+            Dialect::Postgres => {
+                let mut modes = Vec::new();
+                if let Some(level) = self.isolation {
+                    modes.push(format!("ISOLATION LEVEL {}", level.keyword()));
+                }
+                if self.read_only {
+                    modes.push("READ ONLY".to_string());
+                }
+                match modes.is_empty() {
+                    true => vec!["BEGIN".to_string()],
+                    false => vec![format!("BEGIN {}", modes.join(", "))],
+                }
+            }
+            // the isolation level applies to the next transaction
+            Dialect::MySql => {
+                let mut out = Vec::new();
+                if let Some(level) = self.isolation {
+                    out.push(format!(
+                        "SET TRANSACTION ISOLATION LEVEL {}",
+                        level.keyword()
+                    ));
+                }
+                out.push(match self.read_only {
+                    true => "START TRANSACTION READ ONLY".to_string(),
+                    false => "START TRANSACTION".to_string(),
+                });
+                out
+            }
+            // writers take the lock upfront, rather than failing to upgrade it later on
+            Dialect::Sqlite => {
+                let writes = self
+                    .steps
+                    .iter()
+                    .any(|step| matches!(step, Step::Statement(BatchItem::Write(_))));
+                match writes {
+                    true => vec!["BEGIN IMMEDIATE".to_string()],
+                    false => vec!["BEGIN".to_string()],
+                }
+            }
+        }
+    }
+
+    /// Returns the transaction as a single script in the given dialect, one statement per line.
+    pub fn to_sql(&self, dialect: Dialect) -> Result<String, String> {
+        let mut statements = self.begin(dialect);
+        for step in &self.steps {
+            statements.push(match step {
+                Step::Statement(item) => {
+                    let changes_schema = !item.dependencies().writes.is_empty();
+                    if dialect == Dialect::MySql && changes_schema {
+                        return Err(
+                            "MySql commits schema changes implicitly, hence they can't be part of a transaction"
+                                .to_string(),
+                        );
+                    }
+                    item.to_sql(dialect)?
+                }
+                Step::Savepoint(name) => format!("SAVEPOINT {name}"),
+                Step::Release(name) => format!("RELEASE SAVEPOINT {name}"),
+                Step::RollbackTo(name) => format!("ROLLBACK TO SAVEPOINT {name}"),
+            });
+        }
+        statements.push("COMMIT".to_string());
+
+        let statements: Vec<_> = statements.iter().map(|sql| format!("{sql};")).collect();
+        Ok(statements.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IsolationLevel, Transaction};
+    use crate::checker::{CompiledChecker, DerivedChecker};
+    use crate::expr::any::{col, lit};
+    use crate::expr::num::Numeric;
+    use crate::expr::{Dialect, ExprType};
+    use crate::reader::Reader;
+    use crate::schema::{Column, CompiledSchema};
+    use crate::writer::Writer;
+
+    #[test]
+    fn savepoints() {
+        let mut checker = DerivedChecker::new();
+        let insert = Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("ann"))
+            .seal()
+            .unwrap();
+        let delete = Writer::new(&mut checker)
+            .delete_from("users")
+            .filter(col("age").lt(18))
+            .seal()
+            .unwrap();
+
+        let transaction = Transaction::new()
+            .isolation(IsolationLevel::Serializable)
+            .then(insert)
+            .savepoint("cleanup")
+            .then(delete)
+            .rollback_to("cleanup")
+            .release("cleanup")
+            .seal()
+            .unwrap();
+        assert_eq!(
+            transaction.to_sql(Dialect::Postgres).unwrap(),
+            "BEGIN ISOLATION LEVEL SERIALIZABLE;\n\
+             INSERT INTO users (name) VALUES ('ann');\n\
+             SAVEPOINT cleanup;\n\
+             DELETE FROM users WHERE age < 18;\n\
+             ROLLBACK TO SAVEPOINT cleanup;\n\
+             RELEASE SAVEPOINT cleanup;\n\
+             COMMIT;"
+        );
+        assert!(transaction
+            .to_sql(Dialect::MySql)
+            .unwrap()
+            .starts_with("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE;\nSTART TRANSACTION;\n"));
+        assert!(transaction
+            .to_sql(Dialect::Sqlite)
+            .unwrap()
+            .starts_with("BEGIN IMMEDIATE;\n"));
+
+        let unknown = Transaction::new()
+            .savepoint("a")
+            .savepoint("b")
+            .release("a")
+            .rollback_to("b")
+            .seal();
+        assert_eq!(unknown.err().unwrap(), "unknown savepoint `b`");
+    }
+
+    #[test]
+    fn read_only() {
+        let mut checker = DerivedChecker::new();
+        let select = Reader::new(&mut checker)
+            .table("users")
+            .select(col("name"))
+            .unwrap();
+        let transaction = Transaction::new().read_only().then(select).seal().unwrap();
+        assert_eq!(
+            transaction.to_sql(Dialect::Postgres).unwrap(),
+            "BEGIN READ ONLY;\nSELECT name FROM users;\nCOMMIT;"
+        );
+        assert_eq!(
+            transaction.to_sql(Dialect::MySql).unwrap(),
+            "START TRANSACTION READ ONLY;\nSELECT name FROM users;\nCOMMIT;"
+        );
+
+        let delete = Writer::new(&mut checker)
+            .delete_from("users")
+            .all_rows()
+            .seal()
+            .unwrap();
+        assert_eq!(
+            Transaction::new()
+                .read_only()
+                .then(delete)
+                .seal()
+                .err()
+                .unwrap(),
+            "statement 1 writes within a read only transaction"
+        );
+    }
+
+    #[test]
+    fn schema_changes() {
+        let schema = CompiledSchema::new();
+        let mut checker = CompiledChecker::new(&schema);
+        let create = Writer::new(&mut checker)
+            .create_table("users")
+            .column(Column::new("name", ExprType::Text))
+            .seal()
+            .unwrap();
+        let insert = Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("ann"))
+            .seal()
+            .unwrap();
+
+        let rolled_back = Transaction::new()
+            .savepoint("a")
+            .then(create)
+            .rollback_to("a")
+            .then(insert)
+            .seal();
+        assert_eq!(
+            rolled_back.err().unwrap(),
+            "statement 2 depends on a change to `users` that is not earlier in the batch"
+        );
+
+        let create = Writer::new(&mut checker)
+            .create_table("teams")
+            .column(Column::new("name", ExprType::Text))
+            .seal()
+            .unwrap();
+        let transaction = Transaction::new().then(create).seal().unwrap();
+        assert!(transaction.to_sql(Dialect::Postgres).is_ok());
+        assert!(transaction.to_sql(Dialect::MySql).is_err());
+    }
+}
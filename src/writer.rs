@@ -0,0 +1,255 @@
+/// `INSERT` statements
+mod insert;
+pub use insert::Insert;
+
+/// `UPDATE` statements
+mod update;
+pub use update::Update;
+
+/// `DELETE` statements
+mod delete;
+pub use delete::Delete;
+
+/// Schema changing statements, which are applied to the checker's schema
+mod ddl;
+pub(crate) use ddl::CreateTableStatement;
+pub use ddl::{AlterTable, CreateIndex, CreateTable, CreateView};
+
+use crate::checker::{CheckError, Checker, Clause, Condition, Dependencies, Signal};
+use crate::expr::{
+    bool::Boolean, render, CommonExpr, Dialect, ExprType, Expression, Nullability, Projection,
+};
+use crate::protocol::Client;
+use crate::reader::{OutputCol, SealedReader};
+
+pub(crate) use crate::reader::{display_filters, join_errors, output_cols};
+
+/// Builds write statements, validating them with the checker as they are built.
+pub struct Writer<'c, C> {
+    checker: &'c mut C,
+}
+
+impl<'c, C: Checker> Writer<'c, C> {
+    pub fn new(checker: &'c mut C) -> Self {
+        checker.reset();
+        Writer { checker }
+    }
+
+    /// Inserts rows into the given columns of a table.
+    pub fn insert_into<S: Into<String>>(
+        self,
+        table: &str,
+        cols: impl IntoIterator<Item = S>,
+    ) -> Insert<'c, C> {
+        self.checker
+            .accept(Condition::TableExists(table.to_string()).into());
+        Insert::new(
+            self.checker,
+            table.to_string(),
+            cols.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Updates the rows of a table.
+    pub fn update(self, table: &str) -> Update<'c, C> {
+        self.checker
+            .accept(Condition::TableExists(table.to_string()).into());
+        Update::new(self.checker, table.to_string())
+    }
+
+    /// Deletes the rows of a table.
+    pub fn delete_from(self, table: &str) -> Delete<'c, C> {
+        self.checker
+            .accept(Condition::TableExists(table.to_string()).into());
+        Delete::new(self.checker, table.to_string())
+    }
+
+    /// Creates a table.
+    pub fn create_table(self, table: &str) -> CreateTable<'c, C> {
+        CreateTable::new(self.checker, table.to_string())
+    }
+
+    /// Alters the columns of a table.
+    pub fn alter_table(self, table: &str) -> AlterTable<'c, C> {
+        self.checker
+            .accept(Condition::TableExists(table.to_string()).into());
+        AlterTable::new(self.checker, table.to_string())
+    }
+
+    /// Creates an index over the given columns of a table.
+    pub fn create_index<S: Into<String>>(
+        self,
+        name: &str,
+        table: &str,
+        cols: impl IntoIterator<Item = S>,
+    ) -> CreateIndex<'c, C> {
+        self.checker
+            .accept(Condition::TableExists(table.to_string()).into());
+        CreateIndex::new(
+            self.checker,
+            name.to_string(),
+            table.to_string(),
+            cols.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Creates a view of the rows returned by a query.
+    pub fn create_view(self, name: &str, reader: SealedReader) -> CreateView<'c, C> {
+        CreateView::new(self.checker, name.to_string(), reader)
+    }
+}
+
+/// Checks a value that is written into a column of the current table.
+fn send_value<C: Checker>(checker: &mut C, col: &str, value: &dyn Expression) {
+    let kind = value.eval_type();
+    let nullability = value.nullability(&|col| checker.col_nullability(col));
+    checker.accept(Condition::ColAcceptsType(col.to_string(), kind, nullability).into());
+    // a value of the wrong type has already been reported against the column
+    let ctx = match checker.col_type(col) {
+        Some(expected) if ExprType::try_fold(expected, kind).is_ok() => expected,
+        _ => kind,
+    };
+    value.send_all(ctx, checker);
+}
+
+/// The `WHERE` clause of a statement that would otherwise affect every row of its table.
+#[derive(Default)]
+pub(crate) struct Filters {
+    exprs: Vec<Box<dyn Expression>>,
+    /// Has the user opted into affecting every row when there are no filters
+    all_rows: bool,
+}
+
+impl Filters {
+    fn push<C: Checker, T: Boolean + 'static>(&mut self, checker: &mut C, expr: CommonExpr<T>) {
+        checker.accept(Signal::StartClause(Clause::Filter).into());
+        expr.send_all(ExprType::Bool, checker);
+        checker.accept(Signal::EndClause.into());
+        self.exprs.push(Box::new(expr));
+    }
+
+    /// Returns an error if the statement would unintentionally affect every row.
+    fn check(&self, statement: &str) -> Option<String> {
+        match self.exprs.is_empty() && !self.all_rows {
+            true => Some(format!(
+                "{statement} without a filter affects every row, use `all_rows` if this is intended"
+            )),
+            false => None,
+        }
+    }
+
+    fn display(&self, dialect: Dialect) -> String {
+        display_filters(&self.exprs, dialect)
+    }
+}
+
+/// The `RETURNING` projection of a write statement.
+pub(crate) struct Returning {
+    projection: Box<dyn Projection>,
+    columns: Vec<OutputCol>,
+}
+
+impl Returning {
+    /// Checks the projection against the target table.
+    fn new<C: Checker>(checker: &mut C, projection: impl Projection + 'static) -> Self {
+        projection.send_all(ExprType::Any, checker);
+        let columns = output_cols(&projection, checker);
+        Self {
+            projection: Box::new(projection),
+            columns,
+        }
+    }
+
+    fn display(&self, dialect: Dialect) -> Result<String, String> {
+        match dialect {
+            Dialect::MySql => Err("RETURNING is not supported by MySql".to_string()),
+            _ => Ok(format!(" RETURNING {}", self.projection.display(dialect))),
+        }
+    }
+}
+
+pub(crate) enum Statement {
+    Insert(insert::InsertStatement),
+    Update(update::UpdateStatement),
+    Delete(delete::DeleteStatement),
+    CreateTable(ddl::CreateTableStatement),
+    AlterTable(ddl::AlterTableStatement),
+    CreateIndex(ddl::CreateIndexStatement),
+    CreateView(ddl::CreateViewStatement),
+}
+
+/// A finished write statement.
+pub struct SealedWriter {
+    statement: Statement,
+    returning: Option<Returning>,
+    deps: Dependencies,
+    warnings: Vec<CheckError>,
+}
+
+impl SealedWriter {
+    /// The schema objects that the statement reads and changes.
+    pub fn dependencies(&self) -> &Dependencies {
+        &self.deps
+    }
+
+    /// The suspicious, but valid, uses that the checker found within the statement.
+    pub fn warnings(&self) -> &[CheckError] {
+        &self.warnings
+    }
+
+    /// Returns the columns of the rows returned by the statement, if it has a `returning`
+    /// projection.
+    pub fn columns(&self) -> Option<&[OutputCol]> {
+        self.returning.as_ref().map(|r| r.columns.as_slice())
+    }
+
+    /// Returns the SQL statement in the given dialect, or an error if the dialect does not
+    /// support it.
+    pub fn to_sql(&self, dialect: Dialect) -> Result<String, String> {
+        render::check(dialect, || self.display(dialect))
+    }
+
+    fn display(&self, dialect: Dialect) -> Result<String, String> {
+        let out = match &self.statement {
+            Statement::Insert(insert) => insert.display(dialect),
+            Statement::Update(update) => update.display(dialect),
+            Statement::Delete(delete) => delete.display(dialect),
+            Statement::CreateTable(create) => create.display(dialect)?,
+            Statement::AlterTable(alter) => alter.display(dialect)?,
+            Statement::CreateIndex(create) => create.display(dialect),
+            Statement::CreateView(create) => create.display(dialect),
+        };
+        self.with_returning(out, dialect)
+    }
+
+    /// Returns the SQL statements in the given dialect, which are several where the dialect can't
+    /// express the statement as a single one.
+    pub(crate) fn to_sql_statements(&self, dialect: Dialect) -> Result<Vec<String>, String> {
+        match &self.statement {
+            Statement::AlterTable(alter) => alter
+                .statements(dialect)?
+                .into_iter()
+                .map(|sql| render::check(dialect, || Ok(sql)))
+                .collect(),
+            _ => Ok(vec![self.to_sql(dialect)?]),
+        }
+    }
+
+    /// Returns an `INSERT` with a conflict clause as an SQL standard `MERGE` statement.
+    pub fn to_merge_sql(&self, dialect: Dialect) -> Result<String, String> {
+        render::check(dialect, || {
+            let out = match &self.statement {
+                Statement::Insert(insert) => insert.display_merge(dialect)?,
+                _ => return Err("MERGE requires an INSERT with a conflict target".to_string()),
+            };
+            self.with_returning(out, dialect)
+        })
+    }
+
+    fn with_returning(&self, out: String, dialect: Dialect) -> Result<String, String> {
+        match &self.returning {
+            Some(returning) => Ok(format!("{out}{}", returning.display(dialect)?)),
+            None => Ok(out),
+        }
+    }
+}
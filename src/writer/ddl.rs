@@ -0,0 +1,722 @@
+use super::{join_errors, SealedWriter, Statement};
+use crate::checker::{CheckError, Checker, Condition, Message, Signal};
+use crate::expr::{common::Common, Dialect, ExprType, Expression, Nullability};
+use crate::reader::SealedReader;
+use crate::schema::{Column, Table};
+
+/// The type of a column within a `CREATE TABLE` or `ADD COLUMN`.
+fn column_type(kind: ExprType, dialect: Dialect) -> Result<&'static str, String> {
+    match (kind, dialect) {
+        (ExprType::Bool, _) => Ok("BOOLEAN"),
+        (ExprType::Num, Dialect::MySql) => Ok("DOUBLE"),
+        (ExprType::Num, _) => Ok("NUMERIC"),
+        // MySQL can't index `TEXT` columns without a prefix length
+        (ExprType::Text, Dialect::MySql) => Ok("VARCHAR(255)"),
+        (ExprType::Text, _) => Ok("TEXT"),
+        (ExprType::Date, _) => Ok("DATE"),
+        (ExprType::Time, _) => Ok("TIME"),
+        (ExprType::Timestamp, Dialect::MySql) => Ok("DATETIME"),
+        (ExprType::Timestamp, _) => Ok("TIMESTAMP"),
+        (ExprType::Interval, Dialect::Postgres) => Ok("INTERVAL"),
+        (kind, dialect) => Err(format!("{kind:?} columns are not supported by {dialect:?}")),
+    }
+}
+
+/// A column and its default value.
+struct ColumnDef {
+    column: Column,
+    default: Option<Box<dyn Expression>>,
+}
+
+impl ColumnDef {
+    fn display(&self, dialect: Dialect) -> Result<String, String> {
+        let mut out = format!(
+            "{} {}",
+            self.column.name(),
+            column_type(self.column.kind(), dialect)?
+        );
+        if self.column.nullability() == Nullability::NotNull {
+            out += " NOT NULL";
+        }
+        if let Some(default) = &self.default {
+            out += &format!(" DEFAULT {}", default.display(dialect));
+        }
+        Ok(out)
+    }
+}
+
+/// Checks a column definition, returning the errors that were found.
+fn check_column<C: Checker>(
+    checker: &mut C,
+    column: &Column,
+    default: Option<&dyn Expression>,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    if matches!(
+        column.kind(),
+        ExprType::Any | ExprType::Temporal | ExprType::Unique
+    ) {
+        errors.push(format!("column `{}` needs a known type", column.name()));
+    }
+    match default {
+        Some(value) => {
+            value.send_all(column.kind(), checker);
+            let null = value.nullability(&|_| Nullability::Nullable) == Nullability::Null;
+            if null && column.nullability() == Nullability::NotNull {
+                errors.push(CheckError::NullValue(column.name().to_string()).to_string());
+            }
+        }
+        None if column.has_default() => errors.push(format!(
+            "column `{}` is marked as having a default, but no default value is given",
+            column.name()
+        )),
+        None => {}
+    }
+    errors
+}
+
+/// Applies the schema changes of a statement to the checker, if the statement is valid.
+fn apply<C: Checker>(
+    checker: &mut C,
+    mut errors: Vec<String>,
+    changes: Vec<Message>,
+) -> Result<(), String> {
+    if let Err(check_errors) = checker.state() {
+        errors.extend(check_errors.iter().map(ToString::to_string));
+    }
+    if !errors.is_empty() {
+        return Err(join_errors(errors));
+    }
+    for change in changes {
+        checker.accept(change);
+    }
+    checker.state().map_err(join_errors)
+}
+
+pub(crate) struct CreateTableStatement {
+    table: Table,
+    columns: Vec<ColumnDef>,
+}
+
+impl CreateTableStatement {
+    /// The statement that creates an existing table of a schema.
+    ///
+    /// Schemas don't know the default values of their columns, hence columns with a default are
+    /// created as nullable ones, whose omitted values are `NULL`.
+    pub(crate) fn from_table(table: &Table) -> Self {
+        let columns = table
+            .columns()
+            .iter()
+            .map(|col| {
+                let mut column = Column::new(col.name(), col.kind());
+                if col.is_required() {
+                    column = column.not_null();
+                }
+                ColumnDef {
+                    column,
+                    default: None,
+                }
+            })
+            .collect();
+        Self {
+            table: table.clone(),
+            columns,
+        }
+    }
+
+    pub(crate) fn display(&self, dialect: Dialect) -> Result<String, String> {
+        let mut defs = self
+            .columns
+            .iter()
+            .map(|def| def.display(dialect))
+            .collect::<Result<Vec<_>, _>>()?;
+        defs.extend(
+            self.table
+                .uniques()
+                .iter()
+                .map(|unique| format!("UNIQUE ({})", unique.join(", "))),
+        );
+        Ok(format!(
+            "CREATE TABLE {} ({})",
+            self.table.name(),
+            defs.join(", ")
+        ))
+    }
+}
+
+/// A `CREATE TABLE` statement that is being built.
+pub struct CreateTable<'c, C> {
+    checker: &'c mut C,
+    statement: CreateTableStatement,
+    /// Errors that are found outside of the checker
+    errors: Vec<String>,
+}
+
+impl<'c, C: Checker> CreateTable<'c, C> {
+    pub(super) fn new(checker: &'c mut C, table: String) -> Self {
+        Self {
+            checker,
+            statement: CreateTableStatement {
+                table: Table::new(table),
+                columns: Vec::new(),
+            },
+            errors: Vec::new(),
+        }
+    }
+
+    /// Adds a column without a default value.
+    pub fn column(self, column: Column) -> Self {
+        self.push(column, None)
+    }
+
+    /// Adds a column with a default value, which inserts may then omit.
+    pub fn column_with_default(self, column: Column, value: impl Common + 'static) -> Self {
+        self.push(column.with_default(), Some(Box::new(value)))
+    }
+
+    fn push(mut self, column: Column, default: Option<Box<dyn Expression>>) -> Self {
+        let table = &self.statement.table;
+        if table.column(column.name()).is_some() {
+            let duplicate =
+                CheckError::DuplicateColumn(table.name().to_string(), column.name().to_string());
+            self.errors.push(duplicate.to_string());
+        }
+        let errors = check_column(self.checker, &column, default.as_deref());
+        self.errors.extend(errors);
+
+        self.statement.table = self.statement.table.clone().with(column.clone());
+        self.statement.columns.push(ColumnDef { column, default });
+        self
+    }
+
+    /// Adds a unique constraint over the given columns.
+    pub fn unique<S: Into<String>>(mut self, cols: impl IntoIterator<Item = S>) -> Self {
+        let cols: Vec<String> = cols.into_iter().map(Into::into).collect();
+        let table = &self.statement.table;
+        for col in &cols {
+            if table.column(col).is_none() {
+                let unknown = CheckError::UnknownColumn(table.name().to_string(), col.clone());
+                self.errors.push(unknown.to_string());
+            }
+        }
+        self.statement.table = self.statement.table.clone().with_unique(cols);
+        self
+    }
+
+    /// Validates the statement and adds the table into the checker's schema, returns a
+    /// `SealedWriter` that cannot be internally modified further.
+    pub fn seal(self) -> Result<SealedWriter, String> {
+        let mut errors = self.errors;
+        if self.statement.columns.is_empty() {
+            errors.push("a table needs at least one column".to_string());
+        }
+        let table = self.statement.table.clone();
+        apply(
+            self.checker,
+            errors,
+            vec![Signal::CreateTable(table).into()],
+        )?;
+
+        let deps = self.checker.dependencies();
+        Ok(SealedWriter {
+            statement: Statement::CreateTable(self.statement),
+            returning: None,
+            deps,
+            warnings: self.checker.warnings(),
+        })
+    }
+}
+
+enum AlterAction {
+    Add(ColumnDef),
+    Drop(String),
+}
+
+pub(crate) struct AlterTableStatement {
+    table: String,
+    actions: Vec<AlterAction>,
+}
+
+impl AlterTableStatement {
+    pub(crate) fn display(&self, dialect: Dialect) -> Result<String, String> {
+        Ok(self.statements(dialect)?.join("; "))
+    }
+
+    /// Returns the statement, split into one per action in dialects that only allow a single
+    /// action per statement.
+    pub(crate) fn statements(&self, dialect: Dialect) -> Result<Vec<String>, String> {
+        let actions = self
+            .actions
+            .iter()
+            .map(|action| match action {
+                AlterAction::Add(def) => Ok(format!("ADD COLUMN {}", def.display(dialect)?)),
+                AlterAction::Drop(col) => Ok(format!("DROP COLUMN {col}")),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(match dialect {
+            // SQLite only allows a single action per statement
+            Dialect::Sqlite => actions
+                .iter()
+                .map(|action| format!("ALTER TABLE {} {action}", self.table))
+                .collect(),
+            _ => vec![format!("ALTER TABLE {} {}", self.table, actions.join(", "))],
+        })
+    }
+}
+
+/// An `ALTER TABLE` statement that is being built.
+pub struct AlterTable<'c, C> {
+    checker: &'c mut C,
+    statement: AlterTableStatement,
+    /// Errors that are found outside of the checker
+    errors: Vec<String>,
+}
+
+impl<'c, C: Checker> AlterTable<'c, C> {
+    pub(super) fn new(checker: &'c mut C, table: String) -> Self {
+        Self {
+            checker,
+            statement: AlterTableStatement {
+                table,
+                actions: Vec::new(),
+            },
+            errors: Vec::new(),
+        }
+    }
+
+    /// Adds a column without a default value.
+    ///
+    /// The column can't be `NOT NULL`, as it would have no value for existing rows, see
+    /// [`AlterTable::add_column_with_default`].
+    pub fn add_column(self, column: Column) -> Self {
+        self.push(column, None)
+    }
+
+    /// Adds a column with a default value, which also fills the column for existing rows.
+    pub fn add_column_with_default(self, column: Column, value: impl Common + 'static) -> Self {
+        self.push(column.with_default(), Some(Box::new(value)))
+    }
+
+    fn push(mut self, column: Column, default: Option<Box<dyn Expression>>) -> Self {
+        if self.checker.col_type(column.name()).is_some() {
+            let duplicate =
+                CheckError::DuplicateColumn(self.statement.table.clone(), column.name().into());
+            self.errors.push(duplicate.to_string());
+        }
+        if default.is_none()
+            && !column.has_default()
+            && column.nullability() == Nullability::NotNull
+        {
+            self.errors.push(format!(
+                "added column `{}` is NOT NULL, hence needs a default for existing rows",
+                column.name()
+            ));
+        }
+        let errors = check_column(self.checker, &column, default.as_deref());
+        self.errors.extend(errors);
+
+        let def = ColumnDef { column, default };
+        self.statement.actions.push(AlterAction::Add(def));
+        self
+    }
+
+    /// Drops a column.
+    pub fn drop_column(mut self, col: &str) -> Self {
+        self.checker
+            .accept(Condition::ColExists(col.to_string()).into());
+        self.statement
+            .actions
+            .push(AlterAction::Drop(col.to_string()));
+        self
+    }
+
+    /// Validates the statement and applies it to the checker's schema, returns a `SealedWriter`
+    /// that cannot be internally modified further.
+    pub fn seal(self) -> Result<SealedWriter, String> {
+        let mut errors = self.errors;
+        if self.statement.actions.is_empty() {
+            errors.push("an ALTER TABLE needs at least one action".to_string());
+        }
+        let changes = self
+            .statement
+            .actions
+            .iter()
+            .map(|action| match action {
+                AlterAction::Add(def) => Signal::AddColumn(def.column.clone()).into(),
+                AlterAction::Drop(col) => Signal::DropColumn(col.clone()).into(),
+            })
+            .collect();
+        apply(self.checker, errors, changes)?;
+
+        let deps = self.checker.dependencies();
+        Ok(SealedWriter {
+            statement: Statement::AlterTable(self.statement),
+            returning: None,
+            deps,
+            warnings: self.checker.warnings(),
+        })
+    }
+}
+
+pub(crate) struct CreateIndexStatement {
+    name: String,
+    table: String,
+    cols: Vec<String>,
+    unique: bool,
+}
+
+impl CreateIndexStatement {
+    pub(crate) fn display(&self, dialect: Dialect) -> String {
+        let unique = if self.unique { "UNIQUE " } else { "" };
+        format!(
+            "CREATE {unique}INDEX {} ON {} ({})",
+            self.name,
+            self.table,
+            self.cols.join(", ")
+        )
+    }
+}
+
+/// A `CREATE INDEX` statement that is being built.
+pub struct CreateIndex<'c, C> {
+    checker: &'c mut C,
+    statement: CreateIndexStatement,
+}
+
+impl<'c, C: Checker> CreateIndex<'c, C> {
+    pub(super) fn new(checker: &'c mut C, name: String, table: String, cols: Vec<String>) -> Self {
+        for col in &cols {
+            checker.accept(Condition::ColExists(col.clone()).into());
+        }
+        Self {
+            checker,
+            statement: CreateIndexStatement {
+                name,
+                table,
+                cols,
+                unique: false,
+            },
+        }
+    }
+
+    /// Makes the index a unique constraint.
+    pub fn unique(mut self) -> Self {
+        self.statement.unique = true;
+        self
+    }
+
+    /// Validates the statement and applies it to the checker's schema, returns a `SealedWriter`
+    /// that cannot be internally modified further.
+    pub fn seal(self) -> Result<SealedWriter, String> {
+        let mut errors = Vec::new();
+        if self.statement.cols.is_empty() {
+            errors.push("an index needs at least one column".to_string());
+        }
+        let changes = match self.statement.unique {
+            true => vec![Signal::AddUnique(self.statement.cols.clone()).into()],
+            false => Vec::new(),
+        };
+        apply(self.checker, errors, changes)?;
+
+        let deps = self.checker.dependencies();
+        Ok(SealedWriter {
+            statement: Statement::CreateIndex(self.statement),
+            returning: None,
+            deps,
+            warnings: self.checker.warnings(),
+        })
+    }
+}
+
+pub(crate) struct CreateViewStatement {
+    name: String,
+    reader: Box<SealedReader>,
+}
+
+impl CreateViewStatement {
+    pub(crate) fn display(&self, dialect: Dialect) -> String {
+        format!(
+            "CREATE VIEW {} AS {}",
+            self.name,
+            self.reader.display(dialect)
+        )
+    }
+}
+
+/// A `CREATE VIEW` statement that is being built.
+pub struct CreateView<'c, C> {
+    checker: &'c mut C,
+    statement: CreateViewStatement,
+}
+
+impl<'c, C: Checker> CreateView<'c, C> {
+    pub(super) fn new(checker: &'c mut C, name: String, reader: SealedReader) -> Self {
+        Self {
+            checker,
+            statement: CreateViewStatement {
+                name,
+                reader: Box::new(reader),
+            },
+        }
+    }
+
+    /// Validates the statement and adds the view into the checker's schema, returns a
+    /// `SealedWriter` that cannot be internally modified further.
+    ///
+    /// The columns of the query must be known, so that later statements can be checked against
+    /// the view.
+    pub fn seal(self) -> Result<SealedWriter, String> {
+        let Some(columns) = self.statement.reader.columns() else {
+            return Err(format!(
+                "the columns of view `{}` are unknown, select them explicitly",
+                self.statement.name
+            ));
+        };
+
+        let mut errors = Vec::new();
+        let mut view = Table::new(&self.statement.name);
+        for (i, col) in columns.iter().enumerate() {
+            let Some(name) = col.name() else {
+                errors.push(format!(
+                    "column {} of view `{}` needs a name, use `alias`",
+                    i + 1,
+                    self.statement.name
+                ));
+                continue;
+            };
+            let column = Column::new(name, col.kind());
+            view = view.with(match col.nullability() {
+                Nullability::NotNull => column.not_null(),
+                _ => column,
+            });
+        }
+        apply(self.checker, errors, vec![Signal::CreateTable(view).into()])?;
+
+        let deps = self
+            .checker
+            .dependencies()
+            .merge(self.statement.reader.dependencies().clone());
+        let mut warnings = self.checker.warnings();
+        warnings.extend_from_slice(self.statement.reader.warnings());
+        Ok(SealedWriter {
+            statement: Statement::CreateView(self.statement),
+            returning: None,
+            deps,
+            warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checker::{CompiledChecker, DerivedChecker};
+    use crate::expr::any::{col, lit};
+    use crate::expr::num::Numeric;
+    use crate::expr::{Dialect, ExprType, Nullability};
+    use crate::multi;
+    use crate::reader::{OutputCol, Reader};
+    use crate::schema::{Column, CompiledSchema};
+    use crate::writer::Writer;
+
+    #[test]
+    fn create_table() {
+        let schema = CompiledSchema::new();
+        let mut checker = CompiledChecker::new(&schema);
+        assert!(Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("ann"))
+            .seal()
+            .is_err());
+
+        let create = Writer::new(&mut checker)
+            .create_table("users")
+            .column(Column::new("id", ExprType::Num).not_null())
+            .column(Column::new("name", ExprType::Text).not_null())
+            .column_with_default(Column::new("active", ExprType::Bool), true)
+            .unique(["id"])
+            .seal()
+            .unwrap();
+        assert_eq!(
+            create.to_sql(Dialect::Postgres).unwrap(),
+            "CREATE TABLE users (id NUMERIC NOT NULL, name TEXT NOT NULL, \
+             active BOOLEAN DEFAULT true, UNIQUE (id))"
+        );
+        assert_eq!(
+            create.to_sql(Dialect::MySql).unwrap(),
+            "CREATE TABLE users (id DOUBLE NOT NULL, name VARCHAR(255) NOT NULL, \
+             active BOOLEAN DEFAULT true, UNIQUE (id))"
+        );
+
+        let missing = Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("ann"))
+            .seal();
+        assert_eq!(
+            missing.err().unwrap(),
+            "column `id` in table `users` is NOT NULL without a default, but has no value"
+        );
+        assert!(Writer::new(&mut checker)
+            .insert_into("users", ["id", "name"])
+            .values(multi![lit(1), "ann"])
+            .on_conflict_do_nothing(["id"])
+            .seal()
+            .is_ok());
+
+        let mismatch = Writer::new(&mut checker)
+            .create_table("users")
+            .column(Column::new("id", ExprType::Num))
+            .column_with_default(Column::new("age", ExprType::Num), "old")
+            .seal();
+        assert_eq!(
+            mismatch.err().unwrap(),
+            "type mismatch: expected Num, found Text"
+        );
+        let duplicate = Writer::new(&mut checker)
+            .create_table("users")
+            .column(Column::new("id", ExprType::Num))
+            .seal();
+        assert_eq!(duplicate.err().unwrap(), "table `users` already exists");
+    }
+
+    #[test]
+    fn alter_table() {
+        let schema = CompiledSchema::new().with_table(
+            crate::schema::Table::new("users")
+                .with_column("name", ExprType::Text)
+                .with_column("nickname", ExprType::Text),
+        );
+        let mut checker = CompiledChecker::new(&schema);
+        assert!(Writer::new(&mut checker)
+            .update("users")
+            .set("age", 30)
+            .all_rows()
+            .seal()
+            .is_err());
+
+        let alter = Writer::new(&mut checker)
+            .alter_table("users")
+            .add_column_with_default(Column::new("age", ExprType::Num).not_null(), 0)
+            .drop_column("nickname")
+            .seal()
+            .unwrap();
+        assert_eq!(
+            alter.to_sql(Dialect::Postgres).unwrap(),
+            "ALTER TABLE users ADD COLUMN age NUMERIC NOT NULL DEFAULT 0, DROP COLUMN nickname"
+        );
+        assert_eq!(
+            alter.to_sql(Dialect::Sqlite).unwrap(),
+            "ALTER TABLE users ADD COLUMN age NUMERIC NOT NULL DEFAULT 0; \
+             ALTER TABLE users DROP COLUMN nickname"
+        );
+
+        assert!(Writer::new(&mut checker)
+            .update("users")
+            .set("age", 30)
+            .all_rows()
+            .seal()
+            .is_ok());
+        let dropped = Reader::new(&mut checker)
+            .table("users")
+            .select(col("nickname"));
+        assert_eq!(
+            dropped.err().unwrap(),
+            "unknown column `nickname` in table `users`"
+        );
+
+        let invalid = Writer::new(&mut checker)
+            .alter_table("users")
+            .add_column(Column::new("name", ExprType::Text))
+            .add_column(Column::new("rank", ExprType::Interval))
+            .seal();
+        assert_eq!(
+            invalid.err().unwrap(),
+            "column `name` already exists in table `users`"
+        );
+        let required = Writer::new(&mut checker)
+            .alter_table("users")
+            .add_column(Column::new("email", ExprType::Text).not_null())
+            .seal();
+        assert_eq!(
+            required.err().unwrap(),
+            "added column `email` is NOT NULL, hence needs a default for existing rows"
+        );
+        let interval = Writer::new(&mut checker)
+            .alter_table("users")
+            .add_column(Column::new("idle", ExprType::Interval))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            interval.to_sql(Dialect::MySql).err().unwrap(),
+            "Interval columns are not supported by MySql"
+        );
+    }
+
+    #[test]
+    fn create_index_and_view() {
+        let mut checker = DerivedChecker::new();
+        let index = Writer::new(&mut checker)
+            .create_index("users_email", "users", ["email"])
+            .unique()
+            .seal()
+            .unwrap();
+        assert_eq!(
+            index.to_sql(Dialect::Sqlite).unwrap(),
+            "CREATE UNIQUE INDEX users_email ON users (email)"
+        );
+
+        let adults = Reader::new(&mut checker)
+            .table("users")
+            .filter(col("age").ge(18))
+            .select(multi![col("email"), col("age")])
+            .unwrap();
+        let view = Writer::new(&mut checker)
+            .create_view("adults", adults)
+            .seal()
+            .unwrap();
+        assert_eq!(
+            view.to_sql(Dialect::Postgres).unwrap(),
+            "CREATE VIEW adults AS SELECT email, age FROM users WHERE age >= 18"
+        );
+
+        let reader = Reader::new(&mut checker)
+            .table("adults")
+            .select(col("age"))
+            .unwrap();
+        assert_eq!(
+            reader.columns().unwrap(),
+            [OutputCol::new(
+                Some("age".into()),
+                ExprType::Num,
+                Nullability::Nullable
+            )]
+        );
+
+        let unnamed = Reader::new(&mut checker)
+            .table("users")
+            .select(col("age").add(1))
+            .unwrap();
+        assert_eq!(
+            Writer::new(&mut checker)
+                .create_view("older", unnamed)
+                .seal()
+                .err()
+                .unwrap(),
+            "column 1 of view `older` needs a name, use `alias`"
+        );
+
+        // the derived checker only knows the columns that are used
+        let all = Reader::new(&mut checker)
+            .table("users")
+            .select_all()
+            .unwrap();
+        assert_eq!(
+            Writer::new(&mut checker)
+                .create_view("everyone", all)
+                .seal()
+                .err()
+                .unwrap(),
+            "the columns of view `everyone` are unknown, select them explicitly"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use super::{join_errors, Filters, Returning, SealedWriter, Statement};
+use crate::checker::Checker;
+use crate::expr::{bool::Boolean, CommonExpr, Dialect, Projection};
+
+pub(crate) struct DeleteStatement {
+    table: String,
+    filters: Filters,
+}
+
+impl DeleteStatement {
+    pub(crate) fn display(&self, dialect: Dialect) -> String {
+        format!(
+            "DELETE FROM {}{}",
+            self.table,
+            self.filters.display(dialect)
+        )
+    }
+}
+
+/// A `DELETE` statement that is being built.
+pub struct Delete<'c, C> {
+    checker: &'c mut C,
+    statement: DeleteStatement,
+    returning: Option<Returning>,
+}
+
+impl<'c, C: Checker> Delete<'c, C> {
+    pub(super) fn new(checker: &'c mut C, table: String) -> Self {
+        Self {
+            checker,
+            statement: DeleteStatement {
+                table,
+                filters: Filters::default(),
+            },
+            returning: None,
+        }
+    }
+
+    /// Filters the rows that are deleted
+    pub fn filter<T: Boolean + 'static>(mut self, bool_expr: CommonExpr<T>) -> Self {
+        self.statement.filters.push(self.checker, bool_expr);
+        self
+    }
+
+    /// Allows the delete to affect every row of the table when there are no filters.
+    pub fn all_rows(mut self) -> Self {
+        self.statement.filters.all_rows = true;
+        self
+    }
+
+    /// Returns the given projection of the deleted rows.
+    pub fn returning(mut self, projection: impl Projection + 'static) -> Self {
+        self.returning = Some(Returning::new(self.checker, projection));
+        self
+    }
+
+    /// Validates the statement, returns a `SealedWriter` that cannot be internally modified
+    /// further.
+    pub fn seal(self) -> Result<SealedWriter, String> {
+        let mut errors: Vec<String> = match self.checker.state() {
+            Ok(()) => Vec::new(),
+            Err(check_errors) => check_errors.iter().map(ToString::to_string).collect(),
+        };
+        errors.extend(self.statement.filters.check("DELETE"));
+        if !errors.is_empty() {
+            return Err(join_errors(errors));
+        }
+
+        let deps = self.checker.dependencies();
+        Ok(SealedWriter {
+            statement: Statement::Delete(self.statement),
+            returning: self.returning,
+            deps,
+            warnings: self.checker.warnings(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checker::DerivedChecker;
+    use crate::expr::any::col;
+    use crate::expr::num::Numeric;
+    use crate::expr::text::Textual;
+    use crate::expr::Dialect;
+    use crate::writer::Writer;
+
+    #[test]
+    fn delete() {
+        let mut checker = DerivedChecker::new();
+        let delete = Writer::new(&mut checker)
+            .delete_from("sessions")
+            .filter(Numeric::lt(col("expires"), 100))
+            .filter(col("user").like("bot%"))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            delete.to_sql(Dialect::Sqlite).unwrap(),
+            "DELETE FROM sessions WHERE (expires < 100) AND (user LIKE 'bot%')"
+        );
+
+        let mismatch = Writer::new(&mut checker)
+            .delete_from("sessions")
+            .filter(col("expires").like("x"))
+            .seal();
+        assert!(mismatch.is_err());
+
+        assert!(Writer::new(&mut checker)
+            .delete_from("sessions")
+            .seal()
+            .is_err());
+        let all = Writer::new(&mut checker)
+            .delete_from("sessions")
+            .all_rows()
+            .seal()
+            .unwrap();
+        assert_eq!(all.to_sql(Dialect::MySql).unwrap(), "DELETE FROM sessions");
+
+        let returning = Writer::new(&mut checker)
+            .delete_from("sessions")
+            .filter(Numeric::lt(col("expires"), 100))
+            .returning(col("user"))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            returning.to_sql(Dialect::Sqlite).unwrap(),
+            "DELETE FROM sessions WHERE expires < 100 RETURNING user"
+        );
+    }
+}
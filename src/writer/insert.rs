@@ -0,0 +1,495 @@
+use super::{join_errors, send_value, Returning, SealedWriter, Statement};
+use crate::checker::{Checker, Condition};
+use crate::expr::{Dialect, Nullability, Projection};
+use crate::reader::SealedReader;
+
+/// The rows written by an `INSERT`.
+pub(crate) enum Source {
+    Values(Vec<Box<dyn Projection>>),
+    Select(Box<SealedReader>),
+}
+
+impl Source {
+    fn display(&self, dialect: Dialect) -> String {
+        match self {
+            Source::Values(rows) => {
+                let rows: Vec<_> = rows
+                    .iter()
+                    .map(|row| format!("({})", row.display(dialect)))
+                    .collect();
+                format!("VALUES {}", rows.join(", "))
+            }
+            Source::Select(reader) => reader.display(dialect),
+        }
+    }
+}
+
+/// What an `INSERT` does with rows that conflict with a unique constraint.
+pub(crate) struct Conflict {
+    /// The columns of the unique constraint
+    target: Vec<String>,
+    action: ConflictAction,
+}
+
+pub(crate) enum ConflictAction {
+    /// Skips the conflicting rows
+    Nothing,
+    /// Updates the existing rows with the inserted values of these columns
+    Update(Vec<String>),
+}
+
+pub(crate) struct InsertStatement {
+    table: String,
+    cols: Vec<String>,
+    source: Source,
+    conflict: Option<Conflict>,
+}
+
+impl InsertStatement {
+    pub(crate) fn display(&self, dialect: Dialect) -> String {
+        let mut out = format!("INSERT INTO {} ({})", self.table, self.cols.join(", "));
+        match (&self.source, &self.conflict, dialect) {
+            // SQLite would parse `ON CONFLICT` as the join constraint of the select
+            (Source::Select(reader), Some(_), Dialect::Sqlite) => {
+                out += &format!(" SELECT * FROM ({}) WHERE true", reader.display(dialect))
+            }
+            (source, _, _) => out += &format!(" {}", source.display(dialect)),
+        }
+
+        let Some(conflict) = &self.conflict else {
+            return out;
+        };
+        match (&conflict.action, dialect) {
+            // MySQL has no target, any unique constraint can conflict
+            (ConflictAction::Nothing, Dialect::MySql) => {
+                let col = &conflict.target[0];
+                out += &format!(" ON DUPLICATE KEY UPDATE {col} = {col}");
+            }
+            (ConflictAction::Update(cols), Dialect::MySql) => {
+                let sets: Vec<_> = cols.iter().map(|c| format!("{c} = VALUES({c})")).collect();
+                out += &format!(" ON DUPLICATE KEY UPDATE {}", sets.join(", "));
+            }
+            (action, _) => {
+                out += &format!(" ON CONFLICT ({}) DO ", conflict.target.join(", "));
+                match action {
+                    ConflictAction::Nothing => out += "NOTHING",
+                    ConflictAction::Update(cols) => {
+                        let sets: Vec<_> =
+                            cols.iter().map(|c| format!("{c} = EXCLUDED.{c}")).collect();
+                        out += &format!("UPDATE SET {}", sets.join(", "));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Returns the upsert as an SQL standard `MERGE` statement.
+    pub(crate) fn display_merge(&self, dialect: Dialect) -> Result<String, String> {
+        if dialect != Dialect::Postgres {
+            return Err(format!("MERGE is not supported by {dialect:?}"));
+        }
+        let Some(conflict) = &self.conflict else {
+            return Err("MERGE requires an INSERT with a conflict target".to_string());
+        };
+
+        let cols = self.cols.join(", ");
+        let on: Vec<_> = conflict
+            .target
+            .iter()
+            .map(|c| format!("target.{c} = source.{c}"))
+            .collect();
+        let mut out = format!(
+            "MERGE INTO {} AS target USING ({}) AS source ({cols}) ON {}",
+            self.table,
+            self.source.display(dialect),
+            on.join(" AND ")
+        );
+        if let ConflictAction::Update(update) = &conflict.action {
+            let sets: Vec<_> = update.iter().map(|c| format!("{c} = source.{c}")).collect();
+            out += &format!(" WHEN MATCHED THEN UPDATE SET {}", sets.join(", "));
+        }
+        let values: Vec<_> = self.cols.iter().map(|c| format!("source.{c}")).collect();
+        out += &format!(
+            " WHEN NOT MATCHED THEN INSERT ({cols}) VALUES ({})",
+            values.join(", ")
+        );
+        Ok(out)
+    }
+}
+
+/// An `INSERT` statement that is being built.
+pub struct Insert<'c, C> {
+    checker: &'c mut C,
+    table: String,
+    cols: Vec<String>,
+    source: Option<Source>,
+    conflict: Option<Conflict>,
+    returning: Option<Returning>,
+    /// Errors that are found outside of the checker
+    errors: Vec<String>,
+}
+
+impl<'c, C: Checker> Insert<'c, C> {
+    pub(super) fn new(checker: &'c mut C, table: String, cols: Vec<String>) -> Self {
+        checker.accept(Condition::ColsSupplied(cols.clone()).into());
+        Self {
+            checker,
+            table,
+            cols,
+            source: None,
+            conflict: None,
+            returning: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Adds a row of values, one for each of the insert's columns.
+    pub fn values(mut self, row: impl Projection + 'static) -> Self {
+        let items = row.items();
+        if items.len() != self.cols.len() {
+            self.errors.push(format!(
+                "row of {} values for {} columns",
+                items.len(),
+                self.cols.len()
+            ));
+        }
+        for (col, item) in self.cols.iter().zip(&items) {
+            send_value(self.checker, col, *item);
+        }
+        drop(items);
+
+        match &mut self.source {
+            Some(Source::Values(rows)) => rows.push(Box::new(row)),
+            Some(Source::Select(_)) => self
+                .errors
+                .push("an INSERT can't have both VALUES and a SELECT".to_string()),
+            None => self.source = Some(Source::Values(vec![Box::new(row)])),
+        }
+        self
+    }
+
+    /// Inserts the rows returned by a query, `INSERT ... SELECT`.
+    ///
+    /// The columns of the query must be known, so that they can be checked against the target
+    /// columns.
+    pub fn select(mut self, reader: SealedReader) -> Self {
+        match reader.columns() {
+            Some(columns) => {
+                if columns.len() != self.cols.len() {
+                    self.errors.push(format!(
+                        "SELECT of {} columns for {} columns",
+                        columns.len(),
+                        self.cols.len()
+                    ));
+                }
+                for (col, column) in self.cols.iter().zip(columns) {
+                    let (kind, nullability) = (column.kind(), column.nullability());
+                    self.checker
+                        .accept(Condition::ColAcceptsType(col.clone(), kind, nullability).into());
+                }
+            }
+            None => self.errors.push(
+                "the columns of the inserted query are unknown, select them explicitly".to_string(),
+            ),
+        }
+
+        match self.source {
+            Some(_) => self
+                .errors
+                .push("an INSERT can't have both VALUES and a SELECT".to_string()),
+            None => self.source = Some(Source::Select(Box::new(reader))),
+        }
+        self
+    }
+
+    /// Skips rows that conflict with the unique constraint over the target columns.
+    /// MySQL skips rows that conflict with any unique constraint.
+    pub fn on_conflict_do_nothing<S: Into<String>>(
+        self,
+        target: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.on_conflict(target, ConflictAction::Nothing)
+    }
+
+    /// Updates the given columns of rows that conflict with the unique constraint over the target
+    /// columns, using the values that were to be inserted.
+    /// MySQL updates rows that conflict with any unique constraint.
+    pub fn on_conflict_do_update<S: Into<String>, U: Into<String>>(
+        mut self,
+        target: impl IntoIterator<Item = S>,
+        cols: impl IntoIterator<Item = U>,
+    ) -> Self {
+        let cols: Vec<String> = cols.into_iter().map(Into::into).collect();
+        for col in &cols {
+            if !self.cols.contains(col) {
+                self.errors.push(format!(
+                    "column `{col}` is updated on conflict, but not inserted"
+                ));
+            }
+        }
+        self.on_conflict(target, ConflictAction::Update(cols))
+    }
+
+    fn on_conflict<S: Into<String>>(
+        mut self,
+        target: impl IntoIterator<Item = S>,
+        action: ConflictAction,
+    ) -> Self {
+        let target: Vec<String> = target.into_iter().map(Into::into).collect();
+        if target.is_empty() {
+            self.errors
+                .push("a conflict target needs at least one column".to_string());
+        } else {
+            self.checker
+                .accept(Condition::UniqueKey(target.clone()).into());
+        }
+        match self.conflict {
+            Some(_) => self
+                .errors
+                .push("an INSERT can only have a single conflict clause".to_string()),
+            None => self.conflict = Some(Conflict { target, action }),
+        }
+        self
+    }
+
+    /// Returns the given projection of the inserted rows.
+    pub fn returning(mut self, projection: impl Projection + 'static) -> Self {
+        self.returning = Some(Returning::new(self.checker, projection));
+        self
+    }
+
+    /// Validates the statement, returns a `SealedWriter` that cannot be internally modified
+    /// further.
+    pub fn seal(self) -> Result<SealedWriter, String> {
+        let mut errors = self.errors;
+        if let Err(check_errors) = self.checker.state() {
+            errors.extend(check_errors.iter().map(ToString::to_string));
+        }
+        let Some(source) = self.source else {
+            errors.push("an INSERT needs VALUES or a SELECT".to_string());
+            return Err(join_errors(errors));
+        };
+        if !errors.is_empty() {
+            return Err(join_errors(errors));
+        }
+
+        let mut warnings = self.checker.warnings();
+        let deps = match &source {
+            Source::Select(reader) => {
+                warnings.extend_from_slice(reader.warnings());
+                self.checker
+                    .dependencies()
+                    .merge(reader.dependencies().clone())
+            }
+            Source::Values(_) => self.checker.dependencies(),
+        };
+        Ok(SealedWriter {
+            statement: Statement::Insert(InsertStatement {
+                table: self.table,
+                cols: self.cols,
+                source,
+                conflict: self.conflict,
+            }),
+            returning: self.returning,
+            deps,
+            warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checker::{CompiledChecker, DerivedChecker};
+    use crate::expr::any::{col, lit, NULL};
+    use crate::expr::num::Numeric;
+    use crate::expr::{Dialect, ExprType, Nullability};
+    use crate::multi;
+    use crate::reader::OutputCol;
+    use crate::reader::Reader;
+    use crate::schema::{Column, CompiledSchema, Table};
+    use crate::writer::Writer;
+
+    fn schema() -> CompiledSchema {
+        CompiledSchema::new().with_table(
+            Table::new("users")
+                .with(Column::new("id", ExprType::Num).not_null().with_default())
+                .with(Column::new("name", ExprType::Text).not_null())
+                .with_column("age", ExprType::Num)
+                .with_unique(["id"])
+                .with_unique(["name"]),
+        )
+    }
+
+    #[test]
+    fn values() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let insert = Writer::new(&mut checker)
+            .insert_into("users", ["name", "age"])
+            .values(multi![lit("ann"), 31])
+            .values(multi![lit("o'brien"), NULL])
+            .seal()
+            .unwrap();
+        assert_eq!(
+            insert.to_sql(Dialect::Postgres).unwrap(),
+            "INSERT INTO users (name, age) VALUES ('ann', 31), ('o''brien', NULL)"
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+
+        let missing = Writer::new(&mut checker)
+            .insert_into("users", ["age"])
+            .values(lit(31))
+            .seal();
+        assert_eq!(
+            missing.err().unwrap(),
+            "column `name` in table `users` is NOT NULL without a default, but has no value"
+        );
+
+        let mismatch = Writer::new(&mut checker)
+            .insert_into("users", ["name", "age"])
+            .values(multi![lit(NULL), "old"])
+            .seal();
+        assert_eq!(
+            mismatch.err().unwrap(),
+            "NULL value for NOT NULL column `name`; \
+             type mismatch for column `age`: expected Num, found Text"
+        );
+
+        let count = Writer::new(&mut checker)
+            .insert_into("users", ["name", "age"])
+            .values(lit("ann"))
+            .seal();
+        assert!(count.is_err());
+    }
+
+    #[test]
+    fn select() {
+        let mut checker = DerivedChecker::new();
+        let adults = Reader::new(&mut checker)
+            .table("users")
+            .filter(crate::expr::num::Numeric::ge(col("age"), 18))
+            .select(multi![col("name"), col("age")])
+            .unwrap();
+        let insert = Writer::new(&mut checker)
+            .insert_into("adults", ["name", "age"])
+            .select(adults)
+            .seal()
+            .unwrap();
+        assert_eq!(
+            insert.to_sql(Dialect::Sqlite).unwrap(),
+            "INSERT INTO adults (name, age) SELECT name, age FROM users WHERE age >= 18"
+        );
+
+        let everyone = Reader::new(&mut checker)
+            .table("users")
+            .select_all()
+            .unwrap();
+        let insert = Writer::new(&mut checker)
+            .insert_into("adults", ["name", "age"])
+            .select(everyone)
+            .seal();
+        assert_eq!(
+            insert.err().unwrap(),
+            "the columns of the inserted query are unknown, select them explicitly"
+        );
+    }
+
+    #[test]
+    fn upsert() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let upsert = Writer::new(&mut checker)
+            .insert_into("users", ["name", "age"])
+            .values(multi![lit("ann"), 31])
+            .on_conflict_do_update(["name"], ["age"])
+            .seal()
+            .unwrap();
+        assert_eq!(
+            upsert.to_sql(Dialect::Sqlite).unwrap(),
+            "INSERT INTO users (name, age) VALUES ('ann', 31) \
+             ON CONFLICT (name) DO UPDATE SET age = EXCLUDED.age"
+        );
+        assert_eq!(
+            upsert.to_sql(Dialect::MySql).unwrap(),
+            "INSERT INTO users (name, age) VALUES ('ann', 31) \
+             ON DUPLICATE KEY UPDATE age = VALUES(age)"
+        );
+        assert_eq!(
+            upsert.to_merge_sql(Dialect::Postgres).unwrap(),
+            "MERGE INTO users AS target USING (VALUES ('ann', 31)) AS source (name, age) \
+             ON target.name = source.name \
+             WHEN MATCHED THEN UPDATE SET age = source.age \
+             WHEN NOT MATCHED THEN INSERT (name, age) VALUES (source.name, source.age)"
+        );
+        assert!(upsert.to_merge_sql(Dialect::MySql).is_err());
+
+        let ignore = Writer::new(&mut checker)
+            .insert_into("users", ["id", "name"])
+            .values(multi![lit(1), "ann"])
+            .on_conflict_do_nothing(["id"])
+            .seal()
+            .unwrap();
+        assert_eq!(
+            ignore.to_sql(Dialect::Postgres).unwrap(),
+            "INSERT INTO users (id, name) VALUES (1, 'ann') ON CONFLICT (id) DO NOTHING"
+        );
+        assert_eq!(
+            ignore.to_sql(Dialect::MySql).unwrap(),
+            "INSERT INTO users (id, name) VALUES (1, 'ann') ON DUPLICATE KEY UPDATE id = id"
+        );
+
+        let invalid = Writer::new(&mut checker)
+            .insert_into("users", ["name", "age"])
+            .values(multi![lit("ann"), 31])
+            .on_conflict_do_update(["age"], ["id"])
+            .seal();
+        assert_eq!(
+            invalid.err().unwrap(),
+            "column `id` is updated on conflict, but not inserted; \
+             columns (age) don't match a unique constraint of table `users`"
+        );
+    }
+
+    #[test]
+    fn returning() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let insert = Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("ann"))
+            .returning(multi![col("id"), col("age")])
+            .seal()
+            .unwrap();
+        assert_eq!(
+            insert.to_sql(Dialect::Postgres).unwrap(),
+            "INSERT INTO users (name) VALUES ('ann') RETURNING id, age"
+        );
+        assert_eq!(
+            insert.to_sql(Dialect::MySql).err().unwrap(),
+            "RETURNING is not supported by MySql"
+        );
+        assert_eq!(
+            insert.columns().unwrap(),
+            [
+                OutputCol::new(Some("id".into()), ExprType::Num, Nullability::NotNull),
+                OutputCol::new(Some("age".into()), ExprType::Num, Nullability::Nullable),
+            ]
+        );
+
+        let unknown = Writer::new(&mut checker)
+            .insert_into("users", ["name"])
+            .values(lit("ann"))
+            .returning(col("created"))
+            .seal();
+        assert_eq!(
+            unknown.err().unwrap(),
+            "unknown column `created` in table `users`"
+        );
+    }
+}
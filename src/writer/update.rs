@@ -0,0 +1,265 @@
+use super::{join_errors, send_value, Filters, Returning, SealedWriter, Statement};
+use crate::checker::{Checker, Condition};
+use crate::expr::{
+    bool::Boolean, common::Common, CommonExpr, Dialect, Expression, Nullability, Projection,
+};
+
+pub(crate) struct UpdateStatement {
+    table: String,
+    sets: Vec<(String, Box<dyn Expression>)>,
+    from: Option<String>,
+    filters: Filters,
+}
+
+impl UpdateStatement {
+    pub(crate) fn display(&self, dialect: Dialect) -> String {
+        let (tables, prefix) = match (&self.from, dialect) {
+            // MySQL joins within the table list, hence its targets must be qualified
+            (Some(from), Dialect::MySql) => (format!("{}, {from}", self.table), &*self.table),
+            _ => (self.table.clone(), ""),
+        };
+        let sets: Vec<_> = self
+            .sets
+            .iter()
+            .map(|(col, value)| match prefix {
+                "" => format!("{col} = {}", value.display(dialect)),
+                table => format!("{table}.{col} = {}", value.display(dialect)),
+            })
+            .collect();
+
+        let mut out = format!("UPDATE {tables} SET {}", sets.join(", "));
+        match (&self.from, dialect) {
+            (Some(_), Dialect::MySql) | (None, _) => {}
+            (Some(from), _) => out += &format!(" FROM {from}"),
+        }
+        out += &self.filters.display(dialect);
+        out
+    }
+}
+
+/// An `UPDATE` statement that is being built.
+pub struct Update<'c, C> {
+    checker: &'c mut C,
+    statement: UpdateStatement,
+    returning: Option<Returning>,
+    /// Errors that are found outside of the checker
+    errors: Vec<String>,
+}
+
+impl<'c, C: Checker> Update<'c, C> {
+    pub(super) fn new(checker: &'c mut C, table: String) -> Self {
+        Self {
+            checker,
+            statement: UpdateStatement {
+                table,
+                sets: Vec::new(),
+                from: None,
+                filters: Filters::default(),
+            },
+            returning: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Sets a column to the given value.
+    pub fn set(mut self, col: &str, value: impl Common + 'static) -> Self {
+        send_value(self.checker, col, &value);
+        self.statement.sets.push((col.to_string(), Box::new(value)));
+        self
+    }
+
+    /// Joins another table into the update, `UPDATE ... FROM`.
+    /// Its columns are referred to as `table.col`, and must be used after this call.
+    pub fn from(mut self, table: &str) -> Self {
+        self.checker
+            .accept(Condition::TableJoined(table.to_string(), Nullability::NotNull).into());
+        match self.statement.from {
+            Some(_) => self
+                .errors
+                .push("an UPDATE can only join a single table".to_string()),
+            None => self.statement.from = Some(table.to_string()),
+        }
+        self
+    }
+
+    /// Filters the rows that are updated
+    pub fn filter<T: Boolean + 'static>(mut self, bool_expr: CommonExpr<T>) -> Self {
+        self.statement.filters.push(self.checker, bool_expr);
+        self
+    }
+
+    /// Allows the update to affect every row of the table when there are no filters.
+    pub fn all_rows(mut self) -> Self {
+        self.statement.filters.all_rows = true;
+        self
+    }
+
+    /// Returns the given projection of the updated rows.
+    pub fn returning(mut self, projection: impl Projection + 'static) -> Self {
+        self.returning = Some(Returning::new(self.checker, projection));
+        self
+    }
+
+    /// Validates the statement, returns a `SealedWriter` that cannot be internally modified
+    /// further.
+    pub fn seal(self) -> Result<SealedWriter, String> {
+        let mut errors = self.errors;
+        if let Err(check_errors) = self.checker.state() {
+            errors.extend(check_errors.iter().map(ToString::to_string));
+        }
+        if self.statement.sets.is_empty() {
+            errors.push("an UPDATE needs at least one `set`".to_string());
+        }
+        errors.extend(self.statement.filters.check("UPDATE"));
+        if !errors.is_empty() {
+            return Err(join_errors(errors));
+        }
+
+        let deps = self.checker.dependencies();
+        Ok(SealedWriter {
+            statement: Statement::Update(self.statement),
+            returning: self.returning,
+            deps,
+            warnings: self.checker.warnings(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checker::{CheckError, CompiledChecker};
+    use crate::expr::any::{col, NULL};
+    use crate::expr::num::Numeric;
+    use crate::expr::{Dialect, ExprType};
+    use crate::schema::{Column, CompiledSchema, Table};
+    use crate::writer::Writer;
+
+    fn schema() -> CompiledSchema {
+        CompiledSchema::new()
+            .with_table(
+                Table::new("users")
+                    .with(Column::new("id", ExprType::Num).not_null())
+                    .with(Column::new("name", ExprType::Text).not_null())
+                    .with_column("team", ExprType::Text)
+                    .with_column("team_id", ExprType::Num)
+                    .with_column("age", ExprType::Num),
+            )
+            .with_table(
+                Table::new("teams")
+                    .with(Column::new("id", ExprType::Num).not_null())
+                    .with_column("name", ExprType::Text),
+            )
+    }
+
+    #[test]
+    fn update() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let update = Writer::new(&mut checker)
+            .update("users")
+            .set("age", col("age").add(1))
+            .set("team", NULL)
+            .filter(col("id").eq(3))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            update.to_sql(Dialect::Postgres).unwrap(),
+            "UPDATE users SET age = (age + 1), team = NULL WHERE id = 3"
+        );
+        assert_eq!(update.warnings(), []);
+
+        // values that may be `NULL` are only suspicious
+        let nullable = Writer::new(&mut checker)
+            .update("users")
+            .set("name", col("team"))
+            .filter(col("id").eq(3))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            nullable.warnings(),
+            [CheckError::NullableValue("name".into())]
+        );
+
+        let invalid = Writer::new(&mut checker)
+            .update("users")
+            .set("name", NULL)
+            .set("age", "old")
+            .filter(col("id").eq(3))
+            .seal();
+        assert_eq!(
+            invalid.err().unwrap(),
+            "NULL value for NOT NULL column `name`; \
+             type mismatch for column `age`: expected Num, found Text"
+        );
+
+        // a column of the wrong type is reported once, against the column that is read
+        let invalid = Writer::new(&mut checker)
+            .update("users")
+            .set("age", col("name"))
+            .filter(col("id").eq(3))
+            .seal();
+        assert_eq!(
+            invalid.err().unwrap(),
+            "type mismatch for column `name`: expected Num, found Text"
+        );
+    }
+
+    #[test]
+    fn update_from() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let update = Writer::new(&mut checker)
+            .update("users")
+            .from("teams")
+            .set("team", col("teams.name"))
+            .filter(col("team_id").eq(col("teams.id")))
+            .seal()
+            .unwrap();
+        assert_eq!(
+            update.to_sql(Dialect::Postgres).unwrap(),
+            "UPDATE users SET team = teams.name FROM teams WHERE team_id = teams.id"
+        );
+        assert_eq!(
+            update.to_sql(Dialect::MySql).unwrap(),
+            "UPDATE users, teams SET users.team = teams.name WHERE team_id = teams.id"
+        );
+
+        let invalid = Writer::new(&mut checker)
+            .update("users")
+            .from("teams")
+            .set("age", col("teams.name"))
+            .set("team", col("places.name"))
+            .filter(col("team_id").eq(col("teams.id")))
+            .seal();
+        assert_eq!(
+            invalid.err().unwrap(),
+            "type mismatch for column `teams.name`: expected Num, found Text; \
+             unknown table `places`"
+        );
+    }
+
+    #[test]
+    fn requires_filter() {
+        let schema = schema();
+        let mut checker = CompiledChecker::new(&schema);
+        let unfiltered = Writer::new(&mut checker)
+            .update("users")
+            .set("age", 0)
+            .seal();
+        assert_eq!(
+            unfiltered.err().unwrap(),
+            "UPDATE without a filter affects every row, use `all_rows` if this is intended"
+        );
+
+        let all = Writer::new(&mut checker)
+            .update("users")
+            .set("age", 0)
+            .all_rows()
+            .seal()
+            .unwrap();
+        assert_eq!(
+            all.to_sql(Dialect::Sqlite).unwrap(),
+            "UPDATE users SET age = 0"
+        );
+    }
+}